@@ -0,0 +1,189 @@
+use crate::{state::RoyaltyEntry, ContractError};
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Api, Decimal, QuerierWrapper, Uint128};
+use cw721::{ContractInfoResponse, Cw721QueryMsg, TokensResponse};
+use sg721_base::msg::{CollectionInfoResponse, QueryMsg as Sg721QueryMsg};
+
+/// The sale price used to derive a royalty share from a CW2981 royalty amount. At 10^18,
+/// the derived share keeps the full 18 decimal places of `Decimal`, provided that the
+/// collection computes the royalty amount without rounding.
+const CW2981_SALE_PRICE: u128 = 1_000_000_000_000_000_000;
+
+#[cw_serde]
+pub enum Cw2981QueryMsg {
+    RoyaltyInfo {
+        token_id: String,
+        sale_price: Uint128,
+    },
+    CheckRoyalties {},
+}
+
+/// CW2981 queries are nested under the cw721 extension query.
+#[cw_serde]
+enum Cw2981ExtensionQueryMsg {
+    Extension { msg: Cw2981QueryMsg },
+}
+
+#[cw_serde]
+pub struct RoyaltiesInfoResponse {
+    pub address: String,
+    pub royalty_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct CheckRoyaltiesResponse {
+    pub royalty_payments: bool,
+}
+
+/// The interface through which the registry reads a collection contract.
+#[cw_serde]
+pub enum CollectionAdapter {
+    /// A Stargaze sg721 collection, administered by its creator
+    Sg721,
+    /// A cw721 collection implementing the CW2981 royalty extension, administered by
+    /// its wasm admin
+    Cw2981,
+    /// A plain cw721 collection without royalties, administered by its wasm admin
+    Cw721,
+}
+
+impl CollectionAdapter {
+    /// Detects the adapter for a collection by probing the queries it supports,
+    /// in order of preference.
+    pub fn detect(querier: &QuerierWrapper, collection: &Addr) -> Result<Self, ContractError> {
+        if querier
+            .query_wasm_smart::<CollectionInfoResponse>(
+                collection,
+                &Sg721QueryMsg::CollectionInfo {},
+            )
+            .is_ok()
+        {
+            return Ok(CollectionAdapter::Sg721);
+        }
+
+        if querier
+            .query_wasm_smart::<CheckRoyaltiesResponse>(
+                collection,
+                &Cw2981ExtensionQueryMsg::Extension {
+                    msg: Cw2981QueryMsg::CheckRoyalties {},
+                },
+            )
+            .is_ok()
+        {
+            return Ok(CollectionAdapter::Cw2981);
+        }
+
+        if querier
+            .query_wasm_smart::<ContractInfoResponse>(collection, &Cw721QueryMsg::ContractInfo {})
+            .is_ok()
+        {
+            return Ok(CollectionAdapter::Cw721);
+        }
+
+        Err(ContractError::UnsupportedCollection {
+            collection: collection.clone(),
+        })
+    }
+
+    /// Queries the address that administers the collection's royalties.
+    pub fn query_admin(
+        &self,
+        querier: &QuerierWrapper,
+        collection: &Addr,
+    ) -> Result<Addr, ContractError> {
+        match self {
+            CollectionAdapter::Sg721 => {
+                let collection_info: CollectionInfoResponse =
+                    querier.query_wasm_smart(collection, &Sg721QueryMsg::CollectionInfo {})?;
+                Ok(Addr::unchecked(collection_info.creator))
+            }
+            CollectionAdapter::Cw2981 | CollectionAdapter::Cw721 => {
+                let contract_info = querier.query_wasm_contract_info(collection)?;
+                contract_info.admin.map(Addr::unchecked).ok_or_else(|| {
+                    ContractError::CollectionAdminNotFound {
+                        collection: collection.clone(),
+                    }
+                })
+            }
+        }
+    }
+
+    /// Queries the royalty set on the collection contract itself, if any. CW2981
+    /// collections that report royalties via `CheckRoyalties` but have no tokens to query
+    /// the royalty of return `CollectionHasNoTokens`.
+    pub fn query_royalty_entry(
+        &self,
+        querier: &QuerierWrapper,
+        api: &dyn Api,
+        collection: &Addr,
+    ) -> Result<Option<RoyaltyEntry>, ContractError> {
+        match self {
+            CollectionAdapter::Sg721 => {
+                let collection_info: CollectionInfoResponse =
+                    querier.query_wasm_smart(collection, &Sg721QueryMsg::CollectionInfo {})?;
+
+                let royalty_entry = collection_info
+                    .royalty_info
+                    .map(|royalty_info| {
+                        api.addr_validate(&royalty_info.payment_address)
+                            .map(|recipient| RoyaltyEntry {
+                                recipient,
+                                share: royalty_info.share,
+                                updated: None,
+                                denom_shares: vec![],
+                            })
+                    })
+                    .transpose()?;
+                Ok(royalty_entry)
+            }
+            CollectionAdapter::Cw2981 => {
+                let check_royalties: CheckRoyaltiesResponse = querier.query_wasm_smart(
+                    collection,
+                    &Cw2981ExtensionQueryMsg::Extension {
+                        msg: Cw2981QueryMsg::CheckRoyalties {},
+                    },
+                )?;
+                if !check_royalties.royalty_payments {
+                    return Ok(None);
+                }
+
+                // CW2981 royalties are set per token, so the first token is taken to be
+                // representative of the collection
+                let tokens: TokensResponse = querier.query_wasm_smart(
+                    collection,
+                    &Cw721QueryMsg::AllTokens {
+                        start_after: None,
+                        limit: Some(1),
+                    },
+                )?;
+                let token_id = tokens.tokens.into_iter().next().ok_or_else(|| {
+                    ContractError::CollectionHasNoTokens {
+                        collection: collection.clone(),
+                    }
+                })?;
+
+                let royalties_info: RoyaltiesInfoResponse = querier.query_wasm_smart(
+                    collection,
+                    &Cw2981ExtensionQueryMsg::Extension {
+                        msg: Cw2981QueryMsg::RoyaltyInfo {
+                            token_id,
+                            sale_price: Uint128::new(CW2981_SALE_PRICE),
+                        },
+                    },
+                )?;
+                if royalties_info.address.is_empty() {
+                    return Ok(None);
+                }
+
+                Ok(Some(RoyaltyEntry {
+                    recipient: api.addr_validate(&royalties_info.address)?,
+                    share: Decimal::from_ratio(royalties_info.royalty_amount, CW2981_SALE_PRICE),
+                    updated: None,
+                    denom_shares: vec![],
+                }))
+            }
+            CollectionAdapter::Cw721 => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use cosmwasm_std::{from_json, to_json_string, Addr, Timestamp};
+use std::{env, fs, process};
+
+use stargaze_royalty_registry::resolve::RoyaltySnapshot;
+
+const USAGE: &str = "Usage: resolve_royalty <snapshot.json> <collection> \
+    [--protocol <protocol>] [--denom <denom>] [--time <unix seconds>]";
+
+/// Prints the effective royalty for a sale of a collection, optionally on a protocol,
+/// resolved from a JSON snapshot of the royalty registry state.
+fn main() {
+    if let Err(err) = run(env::args().skip(1).collect()) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn run(args: Vec<String>) -> Result<(), String> {
+    let mut positional = vec![];
+    let mut protocol = None;
+    let mut denom = None;
+    let mut time = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--protocol" => protocol = Some(args.next().ok_or(USAGE)?),
+            "--denom" => denom = Some(args.next().ok_or(USAGE)?),
+            "--time" => {
+                let seconds = args.next().ok_or(USAGE)?;
+                time = Some(Timestamp::from_seconds(
+                    seconds.parse().map_err(|_| USAGE.to_string())?,
+                ));
+            }
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ => positional.push(arg),
+        }
+    }
+
+    let (snapshot_path, collection) = match positional.as_slice() {
+        [snapshot_path, collection] => (snapshot_path, Addr::unchecked(collection)),
+        _ => return Err(USAGE.to_string()),
+    };
+    let protocol = protocol.map(Addr::unchecked);
+
+    let snapshot_json = fs::read(snapshot_path)
+        .map_err(|err| format!("Failed to read {}: {}", snapshot_path, err))?;
+    let snapshot: RoyaltySnapshot = from_json(snapshot_json)
+        .map_err(|err| format!("Failed to parse {}: {}", snapshot_path, err))?;
+
+    // Promotions are resolved at the given time, or else at the time of the snapshot
+    let block_time = time
+        .or(snapshot.block_time)
+        .ok_or("No --time given and the snapshot has no block_time")?;
+
+    let resolved_royalty_entry =
+        snapshot.resolve(&collection, protocol.as_ref(), denom.as_deref(), block_time);
+
+    println!(
+        "{}",
+        to_json_string(&resolved_royalty_entry).map_err(|err| err.to_string())?
+    );
+
+    Ok(())
+}
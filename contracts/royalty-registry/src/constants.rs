@@ -0,0 +1,31 @@
+use cosmwasm_std::Decimal;
+
+/// The maximum number of operations or collections in a single batch.
+pub const MAX_BATCH_SIZE: usize = 50;
+
+/// The maximum number of royalty entries re-saved by a single index rebuild.
+pub const MAX_REBUILD_INDICES_LIMIT: u32 = 100;
+
+/// The maximum number of denom share overrides on a royalty entry.
+pub const MAX_DENOM_SHARES: usize = 10;
+
+/// The length in seconds of the periods that royalty payments are bucketed into.
+pub const ROYALTY_STATS_PERIOD: u64 = 86_400;
+
+/// The maximum length of a royalty metadata label.
+pub const MAX_METADATA_LABEL_LENGTH: usize = 64;
+
+/// The maximum length of a royalty metadata URL.
+pub const MAX_METADATA_URL_LENGTH: usize = 256;
+
+/// The minimum number of seconds that `update_wait_period` can be set to.
+pub const MIN_UPDATE_WAIT_PERIOD: u64 = 60;
+
+/// The maximum number of seconds that `update_wait_period` can be set to.
+pub const MAX_UPDATE_WAIT_PERIOD: u64 = 365 * 86_400;
+
+/// The minimum value that `max_share_delta` can be set to.
+pub const MIN_MAX_SHARE_DELTA: Decimal = Decimal::permille(1);
+
+/// The maximum value that `max_share_delta` can be set to.
+pub const MAX_MAX_SHARE_DELTA: Decimal = Decimal::percent(50);
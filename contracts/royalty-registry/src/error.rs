@@ -1,5 +1,7 @@
-use cosmwasm_std::StdError;
-use cw_utils::PaymentError;
+use crate::state::RoyaltyLock;
+
+use cosmwasm_std::{Addr, Decimal, StdError, Timestamp, Uint128};
+use cw_utils::{Expiration, PaymentError};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -10,15 +12,210 @@ pub enum ContractError {
     #[error("{0}")]
     PaymentError(#[from] PaymentError),
 
-    #[error("InvalidConfig: {0}")]
-    InvalidConfig(String),
+    #[error("InvalidShareBounds: min_share {min_share} and max_share {max_share} must satisfy min_share <= max_share <= 1")]
+    InvalidShareBounds {
+        min_share: Decimal,
+        max_share: Decimal,
+    },
+
+    #[error("NotCollectionAdmin: {sender} is not the collection admin {admin}")]
+    NotCollectionAdmin { sender: Addr, admin: Addr },
+
+    #[error("UnsupportedCollection: {collection} is not an sg721, CW2981 or cw721 collection")]
+    UnsupportedCollection { collection: Addr },
+
+    #[error("CollectionAdminNotFound: collection {collection} has no admin")]
+    CollectionAdminNotFound { collection: Addr },
+
+    #[error("CollectionAdminUnchanged: admin of collection {collection} has not changed")]
+    CollectionAdminUnchanged { collection: Addr },
+
+    #[error("CollectionAdminNotRecorded: no admin was recorded for collection {collection}")]
+    CollectionAdminNotRecorded { collection: Addr },
+
+    #[error("CollectionHasNoTokens: royalty of collection {collection} cannot be queried without tokens")]
+    CollectionHasNoTokens { collection: Addr },
+
+    #[error("CollectionRoyaltyNotFound: collection {collection} does not report a royalty")]
+    CollectionRoyaltyNotFound { collection: Addr },
+
+    #[error("CollectionFrozen: royalties of collection {collection} are frozen by governance")]
+    CollectionFrozen { collection: Addr },
+
+    #[error("CollectionNotFrozen: royalties of collection {collection} are not frozen")]
+    CollectionNotFrozen { collection: Addr },
+
+    #[error("RoyaltyInSync: royalty of collection {collection} is already in sync")]
+    RoyaltyInSync { collection: Addr },
+
+    #[error("RoyaltyLocked: royalty is locked ({lock})")]
+    RoyaltyLocked { lock: RoyaltyLock },
+
+    #[error("RoyaltyLockLoosened: royalty lock cannot be loosened to {lock}")]
+    RoyaltyLockLoosened { lock: RoyaltyLock },
+
+    #[error("NoRoyaltyLock: no royalty lock specified")]
+    NoRoyaltyLock {},
+
+    #[error("NoUpdateParams: no update params specified")]
+    NoUpdateParams {},
+
+    #[error(
+        "UpdateWaitPeriodOutOfRange: update_wait_period {update_wait_period} must be between {min} and {max} seconds"
+    )]
+    UpdateWaitPeriodOutOfRange {
+        update_wait_period: u64,
+        min: u64,
+        max: u64,
+    },
+
+    #[error("MaxShareDeltaOutOfRange: max_share_delta {max_share_delta} must be between {min} and {max}")]
+    MaxShareDeltaOutOfRange {
+        max_share_delta: Decimal,
+        min: Decimal,
+        max: Decimal,
+    },
+
+    #[error(
+        "TooManyDenomShares: royalty entry cannot have more than {max_denom_shares} denom shares"
+    )]
+    TooManyDenomShares { max_denom_shares: usize },
+
+    #[error("ConflictingShareUpdate: only one of share and share_delta can be set")]
+    ConflictingShareUpdate {},
+
+    #[error("ShareDeltaExceeded: share delta {delta} exceeds max share delta {max_share_delta}")]
+    ShareDeltaExceeded {
+        delta: Decimal,
+        max_share_delta: Decimal,
+    },
+
+    #[error("ShareUnderflow: cannot decrement share {share} by {delta}")]
+    ShareUnderflow { share: Decimal, delta: Decimal },
+
+    #[error("ShareOutOfRange: share {share} must be between {min_share} and {max_share}")]
+    ShareOutOfRange {
+        share: Decimal,
+        min_share: Decimal,
+        max_share: Decimal,
+    },
 
-    #[error("Unauthorized: {0}")]
-    Unauthorized(String),
+    #[error("ProtocolNotRegistered: protocol {protocol} is not registered")]
+    ProtocolNotRegistered { protocol: Addr },
 
-    #[error("InvalidCollectionRoyalty: {0}")]
-    InvalidCollectionRoyalty(String),
+    #[error("ProtocolInactive: protocol {protocol} is not active")]
+    ProtocolInactive { protocol: Addr },
+
+    #[error("InvalidProtocolName: protocol name must not be empty")]
+    InvalidProtocolName {},
+
+    #[error("InvalidProtocolMaxShare: protocol max share {max_share} exceeds 1")]
+    InvalidProtocolMaxShare { max_share: Decimal },
+
+    #[error("ManagerExpired: collection manager expiration {expires} has already passed")]
+    ManagerExpired { expires: Expiration },
+
+    #[error("ManagerNotFound: {manager} is not a manager of collection {collection}")]
+    ManagerNotFound { collection: Addr, manager: Addr },
+
+    #[error(
+        "RoyaltyAlreadyInitialized: royalty for collection {collection}{} is already initialized",
+        protocol_suffix(.protocol)
+    )]
+    RoyaltyAlreadyInitialized {
+        collection: Addr,
+        protocol: Option<Addr>,
+    },
+
+    #[error(
+        "RoyaltyNotFound: royalty for collection {collection}{} does not exist",
+        protocol_suffix(.protocol)
+    )]
+    RoyaltyNotFound {
+        collection: Addr,
+        protocol: Option<Addr>,
+    },
+
+    #[error("CooldownActive: royalty entry cannot be updated until {until}")]
+    CooldownActive { until: Timestamp },
+
+    #[error(
+        "ProposalPending: royalty proposal for collection {collection}{} is already pending",
+        protocol_suffix(.protocol)
+    )]
+    ProposalPending {
+        collection: Addr,
+        protocol: Option<Addr>,
+    },
+
+    #[error(
+        "ProposalNotFound: royalty proposal for collection {collection}{} does not exist",
+        protocol_suffix(.protocol)
+    )]
+    ProposalNotFound {
+        collection: Addr,
+        protocol: Option<Addr>,
+    },
+
+    #[error("ProposalNotReady: royalty proposal cannot be applied until {activation}")]
+    ProposalNotReady { activation: Timestamp },
+
+    #[error(
+        "PromotionNotFound: royalty promotion for collection {collection}{} does not exist",
+        protocol_suffix(.protocol)
+    )]
+    PromotionNotFound {
+        collection: Addr,
+        protocol: Option<Addr>,
+    },
+
+    #[error("InvalidPromotionPeriod: promotion start {start} must be before its end {end}")]
+    InvalidPromotionPeriod { start: Timestamp, end: Timestamp },
+
+    #[error("PromotionExpired: promotion ended at {end}")]
+    PromotionExpired { end: Timestamp },
+
+    #[error("PromotionIncreasesShare: promotion share {share} exceeds the royalty share {baseline_share}")]
+    PromotionIncreasesShare {
+        share: Decimal,
+        baseline_share: Decimal,
+    },
+
+    #[error("InvalidRoyaltyLabel: royalty label must be between 1 and {max_length} characters")]
+    InvalidRoyaltyLabel { max_length: usize },
+
+    #[error(
+        "InvalidRoyaltyUrl: royalty URL must start with https:// and be at most {max_length} characters"
+    )]
+    InvalidRoyaltyUrl { max_length: usize },
+
+    #[error(
+        "InvalidBatchSize: batch of {size} operations must contain between 1 and {max_batch_size}"
+    )]
+    InvalidBatchSize { size: usize, max_batch_size: usize },
+
+    #[error("InvalidProtocolFee: protocol fee percent {fee_percent} exceeds 1")]
+    InvalidProtocolFee { fee_percent: Decimal },
+
+    #[error(
+        "SalePriceExceeded: royalty {royalty_amount} and protocol fee {protocol_fee_amount} exceed the sale price {price}"
+    )]
+    SalePriceExceeded {
+        price: Uint128,
+        royalty_amount: Uint128,
+        protocol_fee_amount: Uint128,
+    },
+
+    #[error("ZeroRoyaltyPayment: royalty payment cannot be zero")]
+    ZeroRoyaltyPayment {},
+
+    #[error("NothingToClaim: {recipient} has no royalties to claim")]
+    NothingToClaim { recipient: Addr },
+}
 
-    #[error("RoyaltyNotFound: {0}")]
-    RoyaltyNotFound(String),
+fn protocol_suffix(protocol: &Option<Addr>) -> String {
+    protocol
+        .as_ref()
+        .map(|protocol| format!(" and protocol {}", protocol))
+        .unwrap_or_default()
 }
@@ -1,17 +1,35 @@
 use crate::{
+    constants::{MAX_BATCH_SIZE, MAX_REBUILD_INDICES_LIMIT},
     error::ContractError,
-    external::only_collection_creator,
-    msg::ExecuteMsg,
+    external::{
+        only_collection_creator, only_collection_manager, query_collection_admin,
+        query_collection_royalty_entry,
+    },
+    msg::{DistributionReceipt, ExecuteMsg, ProtocolFee, RoyaltyOperation},
+    query::query_royalty_payment,
+    resolve::select_royalty_entry,
     state::{
-        RoyaltyDefault, RoyaltyEntry, RoyaltyProtocol, RoyaltyProtocolKey, CONFIG,
-        ROYALTY_DEFAULTS, ROYALTY_PROTOCOLS,
+        append_royalty_history, ensure_collection_not_frozen, ensure_no_pending_proposal,
+        load_effective_config, record_royalty_payment, royalty_defaults, royalty_protocols,
+        CollectionManager, Config, RegisteredProtocol, RoyaltyAction, RoyaltyDefault, RoyaltyEntry,
+        RoyaltyLock, RoyaltyLocks, RoyaltyMetadata, RoyaltyPromotion, RoyaltyProposal,
+        RoyaltyProtocol, RoyaltyProtocolKey, ShareUpdate, UpdateParams, COLLECTION_CREATORS,
+        COLLECTION_MANAGERS, CONFIG, FROZEN_COLLECTIONS, ROYALTY_BALANCES,
+        ROYALTY_DEFAULT_METADATA, ROYALTY_DEFAULT_PROMOTIONS, ROYALTY_DEFAULT_PROPOSALS,
+        ROYALTY_LOCKS, ROYALTY_PROTOCOL_METADATA, ROYALTY_PROTOCOL_PROMOTIONS,
+        ROYALTY_PROTOCOL_PROPOSALS,
     },
 };
 
-use cosmwasm_std::{attr, ensure, Addr, Decimal, DepsMut, Env, Event, MessageInfo};
-use cw_utils::{maybe_addr, nonpayable};
-use sg721_base::msg::{CollectionInfoResponse, QueryMsg as Sg721QueryMsg};
+use cosmwasm_std::{
+    attr, coin, ensure, to_json_binary, Addr, BankMsg, Coin, Decimal, DepsMut, Env, Event,
+    MessageInfo, Order, StdResult, Timestamp, Uint128,
+};
+use cw_storage_plus::Bound;
+use cw_utils::{maybe_addr, nonpayable, one_coin, Expiration};
 use sg_std::Response;
+use stargaze_fair_burn::append_fair_burn_msg;
+use std::cmp::{min, Ordering};
 
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
@@ -29,6 +47,9 @@ pub fn execute(
         ExecuteMsg::InitializeCollectionRoyalty { collection } => {
             execute_initialize_collection_royalty(deps, info, env, api.addr_validate(&collection)?)
         }
+        ExecuteMsg::SyncCollectionRoyalty { collection } => {
+            execute_sync_collection_royalty(deps, info, env, api.addr_validate(&collection)?)
+        }
         ExecuteMsg::SetCollectionRoyaltyDefault {
             collection,
             recipient,
@@ -44,6 +65,7 @@ pub fn execute(
         ExecuteMsg::UpdateCollectionRoyaltyDefault {
             collection,
             recipient,
+            share,
             share_delta,
             decrement,
         } => execute_update_collection_royalty_default(
@@ -52,8 +74,7 @@ pub fn execute(
             env,
             api.addr_validate(&collection)?,
             maybe_addr(api, recipient)?,
-            share_delta,
-            decrement,
+            ShareUpdate::from_msg(share, share_delta, decrement)?,
         ),
         ExecuteMsg::SetCollectionRoyaltyProtocol {
             collection,
@@ -73,6 +94,7 @@ pub fn execute(
             collection,
             protocol,
             recipient,
+            share,
             share_delta,
             decrement,
         } => execute_update_collection_royalty_protocol(
@@ -82,14 +104,242 @@ pub fn execute(
             api.addr_validate(&collection)?,
             api.addr_validate(&protocol)?,
             maybe_addr(api, recipient)?,
+            ShareUpdate::from_msg(share, share_delta, decrement)?,
+        ),
+        ExecuteMsg::ProposeCollectionRoyaltyDefault {
+            collection,
+            recipient,
+            share,
+            share_delta,
+            decrement,
+        } => execute_propose_collection_royalty_default(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            maybe_addr(api, recipient)?,
+            ShareUpdate::from_msg(share, share_delta, decrement)?,
+        ),
+        ExecuteMsg::ApplyCollectionRoyaltyDefault { collection } => {
+            execute_apply_collection_royalty_default(
+                deps,
+                info,
+                env,
+                api.addr_validate(&collection)?,
+            )
+        }
+        ExecuteMsg::CancelCollectionRoyaltyDefault { collection } => {
+            execute_cancel_collection_royalty_default(
+                deps,
+                info,
+                env,
+                api.addr_validate(&collection)?,
+            )
+        }
+        ExecuteMsg::ProposeCollectionRoyaltyProtocol {
+            collection,
+            protocol,
+            recipient,
+            share,
             share_delta,
             decrement,
+        } => execute_propose_collection_royalty_protocol(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            api.addr_validate(&protocol)?,
+            maybe_addr(api, recipient)?,
+            ShareUpdate::from_msg(share, share_delta, decrement)?,
+        ),
+        ExecuteMsg::ApplyCollectionRoyaltyProtocol {
+            collection,
+            protocol,
+        } => execute_apply_collection_royalty_protocol(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            api.addr_validate(&protocol)?,
+        ),
+        ExecuteMsg::CancelCollectionRoyaltyProtocol {
+            collection,
+            protocol,
+        } => execute_cancel_collection_royalty_protocol(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            api.addr_validate(&protocol)?,
+        ),
+        ExecuteMsg::SetCollectionRoyaltyPromotion {
+            collection,
+            protocol,
+            share,
+            start,
+            end,
+        } => execute_set_collection_royalty_promotion(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            maybe_addr(api, protocol)?,
+            share,
+            start,
+            end,
+        ),
+        ExecuteMsg::RemoveCollectionRoyaltyPromotion {
+            collection,
+            protocol,
+        } => execute_remove_collection_royalty_promotion(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            maybe_addr(api, protocol)?,
+        ),
+        ExecuteMsg::LockCollectionRoyalty {
+            collection,
+            default,
+            protocols,
+        } => execute_lock_collection_royalty(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            default,
+            protocols,
+        ),
+        ExecuteMsg::SetCollectionRoyaltyUpdateParams {
+            collection,
+            update_params,
+        } => execute_set_collection_royalty_update_params(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            update_params,
+        ),
+        ExecuteMsg::SetCollectionRoyaltyMetadata {
+            collection,
+            protocol,
+            metadata,
+        } => execute_set_collection_royalty_metadata(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            maybe_addr(api, protocol)?,
+            metadata,
+        ),
+        ExecuteMsg::SetCollectionRoyaltyDenomShare {
+            collection,
+            protocol,
+            denom,
+            share,
+        } => execute_set_collection_royalty_denom_share(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            maybe_addr(api, protocol)?,
+            denom,
+            share,
+        ),
+        ExecuteMsg::BatchCollectionRoyalty { operations } => {
+            execute_batch_collection_royalty(deps, info, env, operations)
+        }
+        ExecuteMsg::DistributeSale {
+            collection,
+            protocol,
+            seller,
+            protocol_fee,
+            escrow_royalty,
+        } => execute_distribute_sale(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            maybe_addr(api, protocol)?,
+            api.addr_validate(&seller)?,
+            protocol_fee,
+            escrow_royalty.unwrap_or(false),
+        ),
+        ExecuteMsg::RecordRoyaltyPayment {
+            collection,
+            recipient,
+            amount,
+        } => execute_record_royalty_payment(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            api.addr_validate(&recipient)?,
+            amount,
+        ),
+        ExecuteMsg::ClaimRoyalties { denoms } => execute_claim_royalties(deps, info, env, denoms),
+        ExecuteMsg::ClaimCollectionRoyalty {
+            collection,
+            recipient,
+        } => execute_claim_collection_royalty(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            maybe_addr(api, recipient)?,
+        ),
+        ExecuteMsg::GrantCollectionManager {
+            collection,
+            manager,
+            expires,
+        } => execute_grant_collection_manager(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            api.addr_validate(&manager)?,
+            expires.unwrap_or_default(),
+        ),
+        ExecuteMsg::RevokeCollectionManager {
+            collection,
+            manager,
+        } => execute_revoke_collection_manager(
+            deps,
+            info,
+            env,
+            api.addr_validate(&collection)?,
+            api.addr_validate(&manager)?,
         ),
+        ExecuteMsg::RebuildRoyaltyDefaultIndices { start_after, limit } => {
+            execute_rebuild_royalty_default_indices(
+                deps,
+                info,
+                env,
+                maybe_addr(api, start_after)?,
+                limit,
+            )
+        }
+        ExecuteMsg::RebuildRoyaltyProtocolIndices { start_after, limit } => {
+            execute_rebuild_royalty_protocol_indices(
+                deps,
+                info,
+                env,
+                start_after
+                    .map(|(collection, protocol)| {
+                        StdResult::Ok((
+                            api.addr_validate(&collection)?,
+                            api.addr_validate(&protocol)?,
+                        ))
+                    })
+                    .transpose()?,
+                limit,
+            )
+        }
     }
 }
 
 pub fn execute_initialize_collection_royalty(
-    deps: DepsMut,
+    mut deps: DepsMut,
     info: MessageInfo,
     env: Env,
     collection: Addr,
@@ -98,55 +348,156 @@ pub fn execute_initialize_collection_royalty(
 
     let mut response = Response::new();
 
-    let royalty_default = ROYALTY_DEFAULTS.may_load(deps.storage, collection.clone())?;
+    // Initialization is idempotent, so that several sales of an uninitialized collection
+    // can each initialize it
+    if royalty_defaults().has(deps.storage, collection.clone()) {
+        return Ok(response);
+    }
+
+    ensure_collection_not_frozen(deps.storage, &collection)?;
+
+    let royalty_entry = query_collection_royalty_entry(&deps.querier, deps.api, &collection)?
+        .ok_or_else(|| ContractError::CollectionRoyaltyNotFound {
+            collection: collection.clone(),
+        })?;
+
+    response = response.add_event(initialize_collection_royalty(
+        &mut deps,
+        &env,
+        &info.sender,
+        &collection,
+        royalty_entry,
+    )?);
+
+    Ok(response)
+}
+
+/// Saves the royalty set on the collection contract as the collection's royalty default.
+fn initialize_collection_royalty(
+    deps: &mut DepsMut,
+    env: &Env,
+    sender: &Addr,
+    collection: &Addr,
+    mut royalty_entry: RoyaltyEntry,
+) -> Result<Event, ContractError> {
+    // Collection royalties outside of the registry bounds are clamped,
+    // so that initialization triggered by a sale cannot fail
+    royalty_entry.share = CONFIG.load(deps.storage)?.clamp_share(royalty_entry.share);
+    royalty_entry.validate()?;
+
+    append_royalty_history(
+        deps.storage,
+        collection,
+        None,
+        RoyaltyAction::Initialize,
+        None,
+        Some(&royalty_entry),
+        sender,
+        env.block.time,
+    )?;
+
+    royalty_defaults().save(
+        deps.storage,
+        collection.clone(),
+        &RoyaltyDefault {
+            collection: collection.clone(),
+            royalty_entry: royalty_entry.clone(),
+            update_params: None,
+        },
+    )?;
+    record_collection_creator(deps, collection)?;
+
+    Ok(
+        Event::new("initialize-collection-royalty").add_attributes(vec![
+            attr("collection", collection.to_string()),
+            attr("recipient", royalty_entry.recipient.to_string()),
+            attr("share", royalty_entry.share.to_string()),
+            attr("updated", env.block.time.to_string()),
+        ]),
+    )
+}
+
+pub fn execute_sync_collection_royalty(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure_collection_not_frozen(deps.storage, &collection)?;
+    // Syncing only follows the collection contract, so anyone can trigger it
+    ensure_no_pending_proposal(deps.storage, &collection, None)?;
+
+    let config = load_effective_config(deps.storage, &collection)?;
+
+    let mut royalty_default = royalty_defaults()
+        .load(deps.storage, collection.clone())
+        .map_err(|_| ContractError::RoyaltyNotFound {
+            collection: collection.clone(),
+            protocol: None,
+        })?;
+    let previous = royalty_default.royalty_entry.clone();
+
+    let collection_royalty = query_collection_royalty_entry(&deps.querier, deps.api, &collection)?
+        .ok_or_else(|| ContractError::CollectionRoyaltyNotFound {
+            collection: collection.clone(),
+        })?;
+    let target_share = config.clamp_share(collection_royalty.share);
+
     ensure!(
-        royalty_default.is_none(),
-        ContractError::InvalidCollectionRoyalty(
-            "Collection royalty already initialized".to_string()
-        )
+        previous.recipient != collection_royalty.recipient || previous.share != target_share,
+        ContractError::RoyaltyInSync {
+            collection: collection.clone(),
+        }
     );
 
-    let collection_info: CollectionInfoResponse = deps
-        .querier
-        .query_wasm_smart(collection.clone(), &Sg721QueryMsg::CollectionInfo {})?;
+    let recipient = (previous.recipient != collection_royalty.recipient)
+        .then_some(collection_royalty.recipient);
 
-    if let Some(royalty_info) = collection_info.royalty_info {
-        let royalty_entry = RoyaltyEntry {
-            recipient: deps.api.addr_validate(&royalty_info.payment_address)?,
-            share: royalty_info.share,
-            updated: None,
-        };
+    // The share moves towards the collection royalty by at most `max_share_delta` per sync
+    let share_update = match target_share.cmp(&previous.share) {
+        Ordering::Greater => Some(ShareUpdate::Increment(min(
+            target_share - previous.share,
+            config.max_share_delta,
+        ))),
+        Ordering::Less => Some(ShareUpdate::Decrement(min(
+            previous.share - target_share,
+            config.max_share_delta,
+        ))),
+        Ordering::Equal => None,
+    };
 
-        royalty_entry.validate()?;
+    let royalty_locks = RoyaltyLocks::load(deps.storage, &collection)?;
+    let event = update_royalty_entry(
+        &mut royalty_default.royalty_entry,
+        &config,
+        royalty_locks.default.as_ref(),
+        env.block.time,
+        recipient,
+        share_update,
+        Event::new("sync-collection-royalty").add_attribute("collection", collection.to_string()),
+    )?;
 
-        ROYALTY_DEFAULTS.save(
-            deps.storage,
-            collection.clone(),
-            &RoyaltyDefault {
-                collection: collection.clone(),
-                royalty_entry,
-            },
-        )?;
+    royalty_defaults().save(deps.storage, collection.clone(), &royalty_default)?;
 
-        response = response.add_event(Event::new("initialize-collection-royalty").add_attributes(
-            vec![
-                attr("collection", collection.to_string()),
-                attr("recipient", royalty_info.payment_address.to_string()),
-                attr("share", royalty_info.share.to_string()),
-                attr("updated", env.block.time.to_string()),
-            ],
-        ));
-    } else {
-        return Err(ContractError::InvalidCollectionRoyalty(
-            "Collection contract royalties not found".to_string(),
-        ));
-    }
+    append_royalty_history(
+        deps.storage,
+        &collection,
+        None,
+        RoyaltyAction::Sync,
+        Some(&previous),
+        Some(&royalty_default.royalty_entry),
+        &info.sender,
+        env.block.time,
+    )?;
+
+    let response = Response::new().add_event(event);
 
     Ok(response)
 }
 
 pub fn execute_set_collection_royalty_default(
-    deps: DepsMut,
+    mut deps: DepsMut,
     info: MessageInfo,
     env: Env,
     collection: Addr,
@@ -154,16 +505,18 @@ pub fn execute_set_collection_royalty_default(
     share: Decimal,
 ) -> Result<Response, ContractError> {
     nonpayable(&info)?;
-    only_collection_creator(deps.as_ref(), &info, &collection)?;
+    ensure_collection_not_frozen(deps.storage, &collection)?;
+    only_collection_manager(deps.as_ref(), &env, &info, &collection)?;
 
     let mut response = Response::new();
 
-    let royalty_default = ROYALTY_DEFAULTS.may_load(deps.storage, collection.clone())?;
+    let royalty_default = royalty_defaults().may_load(deps.storage, collection.clone())?;
     ensure!(
         royalty_default.is_none(),
-        ContractError::InvalidCollectionRoyalty(
-            "Collection royalty already initialized".to_string()
-        )
+        ContractError::RoyaltyAlreadyInitialized {
+            collection: collection.clone(),
+            protocol: None,
+        }
     );
 
     let royalty_default = RoyaltyDefault {
@@ -172,12 +525,33 @@ pub fn execute_set_collection_royalty_default(
             recipient: recipient.clone(),
             share,
             updated: Some(env.block.time),
+            denom_shares: vec![],
         },
+        update_params: None,
     };
 
     royalty_default.royalty_entry.validate()?;
+    CONFIG
+        .load(deps.storage)?
+        .ensure_share_in_bounds(royalty_default.royalty_entry.share)?;
+
+    if let Some(royalty_lock) = RoyaltyLocks::load(deps.storage, &collection)?.default {
+        royalty_lock.validate_new_entry(Decimal::zero(), &royalty_default.royalty_entry)?;
+    }
 
-    ROYALTY_DEFAULTS.save(deps.storage, collection.clone(), &royalty_default)?;
+    royalty_defaults().save(deps.storage, collection.clone(), &royalty_default)?;
+    record_collection_creator(&mut deps, &collection)?;
+
+    append_royalty_history(
+        deps.storage,
+        &collection,
+        None,
+        RoyaltyAction::Set,
+        None,
+        Some(&royalty_default.royalty_entry),
+        &info.sender,
+        env.block.time,
+    )?;
 
     response = response.add_event(Event::new("set-collection-royalty-default").add_attributes(
         vec![
@@ -197,47 +571,48 @@ pub fn execute_update_collection_royalty_default(
     env: Env,
     collection: Addr,
     recipient: Option<Addr>,
-    share_delta: Option<Decimal>,
-    decrement: Option<bool>,
+    share_update: Option<ShareUpdate>,
 ) -> Result<Response, ContractError> {
     nonpayable(&info)?;
-    only_collection_creator(deps.as_ref(), &info, &collection)?;
+    ensure_collection_not_frozen(deps.storage, &collection)?;
+    only_collection_manager(deps.as_ref(), &env, &info, &collection)?;
+    ensure_no_pending_proposal(deps.storage, &collection, None)?;
 
-    let config = CONFIG.load(deps.storage)?;
+    let config = load_effective_config(deps.storage, &collection)?;
     let mut response = Response::new();
 
-    let mut royalty_default = ROYALTY_DEFAULTS
+    let mut royalty_default = royalty_defaults()
         .load(deps.storage, collection.clone())
-        .map_err(|_| {
-            ContractError::InvalidCollectionRoyalty("Collection royalty does not exist".to_string())
+        .map_err(|_| ContractError::RoyaltyNotFound {
+            collection: collection.clone(),
+            protocol: None,
         })?;
+    let previous = royalty_default.royalty_entry.clone();
 
-    if let Some(updated) = royalty_default.royalty_entry.updated {
-        ensure!(
-            updated.plus_seconds(config.update_wait_period) <= env.block.time,
-            ContractError::Unauthorized("Royalty entry cannot be updated yet".to_string())
-        );
-    }
-
-    let mut event = Event::new("update-collection-royalty-default")
-        .add_attribute("collection", collection.to_string());
-
-    if let Some(recipient) = recipient {
-        royalty_default.royalty_entry.recipient = recipient.clone();
-        event = event.add_attribute("recipient", recipient.to_string());
-    }
-
-    if let Some(share_delta) = share_delta {
-        royalty_default
-            .royalty_entry
-            .update_share(&config, share_delta, decrement)?;
+    let royalty_locks = RoyaltyLocks::load(deps.storage, &collection)?;
+    let event = update_royalty_entry(
+        &mut royalty_default.royalty_entry,
+        &config,
+        royalty_locks.default.as_ref(),
+        env.block.time,
+        recipient,
+        share_update,
+        Event::new("update-collection-royalty-default")
+            .add_attribute("collection", collection.to_string()),
+    )?;
 
-        event = event.add_attribute("share", royalty_default.royalty_entry.share.to_string());
-    }
+    royalty_defaults().save(deps.storage, collection.clone(), &royalty_default)?;
 
-    royalty_default.royalty_entry.updated = Some(env.block.time);
-    royalty_default.royalty_entry.validate()?;
-    ROYALTY_DEFAULTS.save(deps.storage, collection, &royalty_default)?;
+    append_royalty_history(
+        deps.storage,
+        &collection,
+        None,
+        RoyaltyAction::Update,
+        Some(&previous),
+        Some(&royalty_default.royalty_entry),
+        &info.sender,
+        env.block.time,
+    )?;
 
     response = response.add_event(event);
 
@@ -254,26 +629,56 @@ pub fn execute_set_collection_royalty_protocol(
     share: Decimal,
 ) -> Result<Response, ContractError> {
     nonpayable(&info)?;
-    only_collection_creator(deps.as_ref(), &info, &collection)?;
+    ensure_collection_not_frozen(deps.storage, &collection)?;
+    only_collection_manager(deps.as_ref(), &env, &info, &collection)?;
 
     let mut response = Response::new();
 
     let royalty_protocol_key: RoyaltyProtocolKey = (collection.clone(), protocol.clone());
     let royalty_protocol =
-        ROYALTY_PROTOCOLS.may_load(deps.storage, royalty_protocol_key.clone())?;
+        royalty_protocols().may_load(deps.storage, royalty_protocol_key.clone())?;
     if royalty_protocol.is_some() {
-        return Err(ContractError::InvalidCollectionRoyalty(
-            "Collection royalty protocol already initialized".to_string(),
-        ));
+        return Err(ContractError::RoyaltyAlreadyInitialized {
+            collection,
+            protocol: Some(protocol),
+        });
     }
 
     let royalty_entry = RoyaltyEntry {
         recipient: recipient.clone(),
         share,
         updated: Some(env.block.time),
+        denom_shares: vec![],
     };
     royalty_entry.validate()?;
-    ROYALTY_PROTOCOLS.save(
+    CONFIG
+        .load(deps.storage)?
+        .ensure_share_in_bounds(royalty_entry.share)?;
+    RegisteredProtocol::load_active(deps.storage, &protocol)?
+        .validate_royalty_entry(&royalty_entry)?;
+
+    if let Some(royalty_lock) = RoyaltyLocks::load(deps.storage, &collection)?.protocols {
+        // A new protocol entry replaces the royalty default for sales on the protocol
+        let baseline_share = royalty_defaults()
+            .may_load(deps.storage, collection.clone())?
+            .map_or(Decimal::zero(), |royalty_default| {
+                royalty_default.royalty_entry.share
+            });
+        royalty_lock.validate_new_entry(baseline_share, &royalty_entry)?;
+    }
+
+    append_royalty_history(
+        deps.storage,
+        &collection,
+        Some(&protocol),
+        RoyaltyAction::Set,
+        None,
+        Some(&royalty_entry),
+        &info.sender,
+        env.block.time,
+    )?;
+
+    royalty_protocols().save(
         deps.storage,
         royalty_protocol_key,
         &RoyaltyProtocol {
@@ -304,49 +709,1466 @@ pub fn execute_update_collection_royalty_protocol(
     collection: Addr,
     protocol: Addr,
     recipient: Option<Addr>,
-    share_delta: Option<Decimal>,
-    decrement: Option<bool>,
+    share_update: Option<ShareUpdate>,
 ) -> Result<Response, ContractError> {
     nonpayable(&info)?;
-    only_collection_creator(deps.as_ref(), &info, &collection)?;
+    ensure_collection_not_frozen(deps.storage, &collection)?;
+    only_collection_manager(deps.as_ref(), &env, &info, &collection)?;
+    ensure_no_pending_proposal(deps.storage, &collection, Some(&protocol))?;
 
-    let config = CONFIG.load(deps.storage)?;
+    let config = load_effective_config(deps.storage, &collection)?;
     let mut response = Response::new();
 
-    let royalty_protocol_key: RoyaltyProtocolKey = (collection.clone(), protocol);
-    let mut royalty_protocol = ROYALTY_PROTOCOLS
+    let royalty_protocol_key: RoyaltyProtocolKey = (collection.clone(), protocol.clone());
+    let mut royalty_protocol = royalty_protocols()
         .load(deps.storage, royalty_protocol_key.clone())
-        .map_err(|_| {
-            ContractError::InvalidCollectionRoyalty("Collection royalty does not exist".to_string())
+        .map_err(|_| ContractError::RoyaltyNotFound {
+            collection: collection.clone(),
+            protocol: Some(protocol.clone()),
         })?;
+    let previous = royalty_protocol.royalty_entry.clone();
 
-    if let Some(updated) = royalty_protocol.royalty_entry.updated {
-        ensure!(
-            updated.plus_seconds(config.update_wait_period) <= env.block.time,
-            ContractError::Unauthorized("Royalty entry cannot be updated yet".to_string())
-        );
-    }
+    let royalty_locks = RoyaltyLocks::load(deps.storage, &collection)?;
+    let event = update_royalty_entry(
+        &mut royalty_protocol.royalty_entry,
+        &config,
+        royalty_locks.protocols.as_ref(),
+        env.block.time,
+        recipient,
+        share_update,
+        Event::new("update-collection-royalty-protocol")
+            .add_attribute("collection", collection.to_string()),
+    )?;
+    RegisteredProtocol::validate_update(
+        deps.storage,
+        &protocol,
+        &previous,
+        &royalty_protocol.royalty_entry,
+    )?;
 
-    let mut event = Event::new("update-collection-royalty-protocol")
-        .add_attribute("collection", collection.to_string());
+    royalty_protocols().save(deps.storage, royalty_protocol_key, &royalty_protocol)?;
+
+    append_royalty_history(
+        deps.storage,
+        &collection,
+        Some(&protocol),
+        RoyaltyAction::Update,
+        Some(&previous),
+        Some(&royalty_protocol.royalty_entry),
+        &info.sender,
+        env.block.time,
+    )?;
+
+    response = response.add_event(event);
+
+    Ok(response)
+}
+
+/// Applies an update to a royalty entry, enforcing the `update_wait_period`
+/// and `max_share_delta` rules, and appends the changes to the given event.
+#[allow(clippy::too_many_arguments)]
+fn update_royalty_entry(
+    royalty_entry: &mut RoyaltyEntry,
+    config: &Config,
+    royalty_lock: Option<&RoyaltyLock>,
+    block_time: Timestamp,
+    recipient: Option<Addr>,
+    share_update: Option<ShareUpdate>,
+    mut event: Event,
+) -> Result<Event, ContractError> {
+    royalty_entry.ensure_updatable(config, block_time)?;
+    let previous = royalty_entry.clone();
 
     if let Some(recipient) = recipient {
-        royalty_protocol.royalty_entry.recipient = recipient.clone();
+        royalty_entry.recipient = recipient.clone();
         event = event.add_attribute("recipient", recipient.to_string());
     }
 
-    if let Some(share_delta) = share_delta {
-        royalty_protocol
-            .royalty_entry
-            .update_share(&config, share_delta, decrement)?;
-        event = event.add_attribute("share", royalty_protocol.royalty_entry.share.to_string());
+    if let Some(share_update) = share_update {
+        royalty_entry.update_share(config, &share_update)?;
+        event = event.add_attribute("share", royalty_entry.share.to_string());
     }
 
-    royalty_protocol.royalty_entry.updated = Some(env.block.time);
-    royalty_protocol.royalty_entry.validate()?;
-    ROYALTY_PROTOCOLS.save(deps.storage, royalty_protocol_key, &royalty_protocol)?;
+    royalty_entry.updated = Some(block_time);
+    royalty_entry.validate()?;
 
-    response = response.add_event(event);
+    if let Some(royalty_lock) = royalty_lock {
+        royalty_lock.validate_update(&previous, royalty_entry)?;
+    }
+
+    Ok(event)
+}
+
+/// Validates a proposed update against the royalty entry it targets, and returns the
+/// proposed royalty entry and the time at which the proposal can be applied.
+fn proposal_activation(
+    royalty_entry: &RoyaltyEntry,
+    config: &Config,
+    royalty_lock: Option<&RoyaltyLock>,
+    block_time: Timestamp,
+    recipient: &Option<Addr>,
+    share_update: &Option<ShareUpdate>,
+) -> Result<(RoyaltyEntry, Timestamp), ContractError> {
+    let activation = block_time.plus_seconds(config.update_wait_period);
+
+    let mut proposed_royalty_entry = royalty_entry.clone();
+    update_royalty_entry(
+        &mut proposed_royalty_entry,
+        config,
+        royalty_lock,
+        activation,
+        recipient.clone(),
+        share_update.clone(),
+        Event::new("dry-run"),
+    )?;
+
+    Ok((proposed_royalty_entry, activation))
+}
+
+fn proposal_event(ty: &str, royalty_proposal: &RoyaltyProposal) -> Event {
+    let mut event =
+        Event::new(ty).add_attribute("collection", royalty_proposal.collection.to_string());
+    if let Some(protocol) = &royalty_proposal.protocol {
+        event = event.add_attribute("protocol", protocol.to_string());
+    }
+    event.add_attributes(vec![
+        attr(
+            "recipient",
+            royalty_proposal.royalty_entry.recipient.to_string(),
+        ),
+        attr("share", royalty_proposal.royalty_entry.share.to_string()),
+        attr("proposer", royalty_proposal.proposer.to_string()),
+        attr("activation", royalty_proposal.activation.to_string()),
+    ])
+}
+
+pub fn execute_propose_collection_royalty_default(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+    recipient: Option<Addr>,
+    share_update: Option<ShareUpdate>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure_collection_not_frozen(deps.storage, &collection)?;
+    only_collection_manager(deps.as_ref(), &env, &info, &collection)?;
+
+    ensure_no_pending_proposal(deps.storage, &collection, None)?;
+
+    let config = load_effective_config(deps.storage, &collection)?;
+
+    let royalty_default = royalty_defaults()
+        .load(deps.storage, collection.clone())
+        .map_err(|_| ContractError::RoyaltyNotFound {
+            collection: collection.clone(),
+            protocol: None,
+        })?;
+
+    let royalty_locks = RoyaltyLocks::load(deps.storage, &collection)?;
+    let (proposed_royalty_entry, activation) = proposal_activation(
+        &royalty_default.royalty_entry,
+        &config,
+        royalty_locks.default.as_ref(),
+        env.block.time,
+        &recipient,
+        &share_update,
+    )?;
+
+    let royalty_proposal = RoyaltyProposal {
+        collection: collection.clone(),
+        protocol: None,
+        royalty_entry: proposed_royalty_entry,
+        proposer: info.sender,
+        activation,
+    };
+    ROYALTY_DEFAULT_PROPOSALS.save(deps.storage, collection, &royalty_proposal)?;
+
+    let response = Response::new().add_event(proposal_event(
+        "propose-collection-royalty-default",
+        &royalty_proposal,
+    ));
+
+    Ok(response)
+}
+
+pub fn execute_apply_collection_royalty_default(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure_collection_not_frozen(deps.storage, &collection)?;
+
+    let royalty_proposal = ROYALTY_DEFAULT_PROPOSALS
+        .may_load(deps.storage, collection.clone())?
+        .ok_or_else(|| ContractError::ProposalNotFound {
+            collection: collection.clone(),
+            protocol: None,
+        })?;
+
+    ensure!(
+        royalty_proposal.activation <= env.block.time,
+        ContractError::ProposalNotReady {
+            activation: royalty_proposal.activation,
+        }
+    );
+
+    let config = load_effective_config(deps.storage, &collection)?;
+
+    let mut royalty_default = royalty_defaults()
+        .load(deps.storage, collection.clone())
+        .map_err(|_| ContractError::RoyaltyNotFound {
+            collection: collection.clone(),
+            protocol: None,
+        })?;
+    let previous = royalty_default.royalty_entry.clone();
+
+    let royalty_locks = RoyaltyLocks::load(deps.storage, &collection)?;
+    let event = update_royalty_entry(
+        &mut royalty_default.royalty_entry,
+        &config,
+        royalty_locks.default.as_ref(),
+        env.block.time,
+        Some(royalty_proposal.royalty_entry.recipient),
+        Some(ShareUpdate::Set(royalty_proposal.royalty_entry.share)),
+        Event::new("apply-collection-royalty-default")
+            .add_attribute("collection", collection.to_string()),
+    )?;
+
+    royalty_defaults().save(deps.storage, collection.clone(), &royalty_default)?;
+    ROYALTY_DEFAULT_PROPOSALS.remove(deps.storage, collection.clone());
+
+    append_royalty_history(
+        deps.storage,
+        &collection,
+        None,
+        RoyaltyAction::Update,
+        Some(&previous),
+        Some(&royalty_default.royalty_entry),
+        &info.sender,
+        env.block.time,
+    )?;
+
+    let response = Response::new().add_event(event);
+
+    Ok(response)
+}
+
+pub fn execute_cancel_collection_royalty_default(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    only_collection_manager(deps.as_ref(), &env, &info, &collection)?;
+
+    ensure!(
+        ROYALTY_DEFAULT_PROPOSALS.has(deps.storage, collection.clone()),
+        ContractError::ProposalNotFound {
+            collection: collection.clone(),
+            protocol: None,
+        }
+    );
+    ROYALTY_DEFAULT_PROPOSALS.remove(deps.storage, collection.clone());
+
+    let response = Response::new().add_event(
+        Event::new("cancel-collection-royalty-default")
+            .add_attribute("collection", collection.to_string()),
+    );
+
+    Ok(response)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_propose_collection_royalty_protocol(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+    protocol: Addr,
+    recipient: Option<Addr>,
+    share_update: Option<ShareUpdate>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure_collection_not_frozen(deps.storage, &collection)?;
+    only_collection_manager(deps.as_ref(), &env, &info, &collection)?;
+
+    ensure_no_pending_proposal(deps.storage, &collection, Some(&protocol))?;
+
+    let royalty_protocol_key: RoyaltyProtocolKey = (collection.clone(), protocol.clone());
+
+    let config = load_effective_config(deps.storage, &collection)?;
+
+    let royalty_protocol = royalty_protocols()
+        .load(deps.storage, royalty_protocol_key.clone())
+        .map_err(|_| ContractError::RoyaltyNotFound {
+            collection: collection.clone(),
+            protocol: Some(protocol.clone()),
+        })?;
+
+    let royalty_locks = RoyaltyLocks::load(deps.storage, &collection)?;
+    let (proposed_royalty_entry, activation) = proposal_activation(
+        &royalty_protocol.royalty_entry,
+        &config,
+        royalty_locks.protocols.as_ref(),
+        env.block.time,
+        &recipient,
+        &share_update,
+    )?;
+    RegisteredProtocol::validate_update(
+        deps.storage,
+        &protocol,
+        &royalty_protocol.royalty_entry,
+        &proposed_royalty_entry,
+    )?;
+
+    let royalty_proposal = RoyaltyProposal {
+        collection,
+        protocol: Some(protocol),
+        royalty_entry: proposed_royalty_entry,
+        proposer: info.sender,
+        activation,
+    };
+    ROYALTY_PROTOCOL_PROPOSALS.save(deps.storage, royalty_protocol_key, &royalty_proposal)?;
+
+    let response = Response::new().add_event(proposal_event(
+        "propose-collection-royalty-protocol",
+        &royalty_proposal,
+    ));
+
+    Ok(response)
+}
+
+pub fn execute_apply_collection_royalty_protocol(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+    protocol: Addr,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure_collection_not_frozen(deps.storage, &collection)?;
+
+    let royalty_protocol_key: RoyaltyProtocolKey = (collection.clone(), protocol.clone());
+    let royalty_proposal = ROYALTY_PROTOCOL_PROPOSALS
+        .may_load(deps.storage, royalty_protocol_key.clone())?
+        .ok_or_else(|| ContractError::ProposalNotFound {
+            collection: collection.clone(),
+            protocol: Some(protocol.clone()),
+        })?;
+
+    ensure!(
+        royalty_proposal.activation <= env.block.time,
+        ContractError::ProposalNotReady {
+            activation: royalty_proposal.activation,
+        }
+    );
+
+    let config = load_effective_config(deps.storage, &collection)?;
+
+    let mut royalty_protocol = royalty_protocols()
+        .load(deps.storage, royalty_protocol_key.clone())
+        .map_err(|_| ContractError::RoyaltyNotFound {
+            collection: collection.clone(),
+            protocol: Some(protocol.clone()),
+        })?;
+    let previous = royalty_protocol.royalty_entry.clone();
+
+    let royalty_locks = RoyaltyLocks::load(deps.storage, &collection)?;
+    let event = update_royalty_entry(
+        &mut royalty_protocol.royalty_entry,
+        &config,
+        royalty_locks.protocols.as_ref(),
+        env.block.time,
+        Some(royalty_proposal.royalty_entry.recipient),
+        Some(ShareUpdate::Set(royalty_proposal.royalty_entry.share)),
+        Event::new("apply-collection-royalty-protocol")
+            .add_attribute("collection", collection.to_string())
+            .add_attribute("protocol", protocol.to_string()),
+    )?;
+    RegisteredProtocol::validate_update(
+        deps.storage,
+        &protocol,
+        &previous,
+        &royalty_protocol.royalty_entry,
+    )?;
+
+    royalty_protocols().save(
+        deps.storage,
+        royalty_protocol_key.clone(),
+        &royalty_protocol,
+    )?;
+    ROYALTY_PROTOCOL_PROPOSALS.remove(deps.storage, royalty_protocol_key);
+
+    append_royalty_history(
+        deps.storage,
+        &collection,
+        Some(&protocol),
+        RoyaltyAction::Update,
+        Some(&previous),
+        Some(&royalty_protocol.royalty_entry),
+        &info.sender,
+        env.block.time,
+    )?;
+
+    let response = Response::new().add_event(event);
+
+    Ok(response)
+}
+
+pub fn execute_cancel_collection_royalty_protocol(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+    protocol: Addr,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    only_collection_manager(deps.as_ref(), &env, &info, &collection)?;
+
+    let royalty_protocol_key: RoyaltyProtocolKey = (collection.clone(), protocol.clone());
+    ensure!(
+        ROYALTY_PROTOCOL_PROPOSALS.has(deps.storage, royalty_protocol_key.clone()),
+        ContractError::ProposalNotFound {
+            collection: collection.clone(),
+            protocol: Some(protocol.clone()),
+        }
+    );
+    ROYALTY_PROTOCOL_PROPOSALS.remove(deps.storage, royalty_protocol_key);
+
+    let response = Response::new().add_event(
+        Event::new("cancel-collection-royalty-protocol")
+            .add_attribute("collection", collection.to_string())
+            .add_attribute("protocol", protocol.to_string()),
+    );
+
+    Ok(response)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_set_collection_royalty_promotion(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+    protocol: Option<Addr>,
+    share: Decimal,
+    start: Timestamp,
+    end: Timestamp,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure_collection_not_frozen(deps.storage, &collection)?;
+    only_collection_manager(deps.as_ref(), &env, &info, &collection)?;
+
+    let royalty_default = royalty_defaults().may_load(deps.storage, collection.clone())?;
+    let royalty_locks = RoyaltyLocks::load(deps.storage, &collection)?;
+
+    // A protocol promotion applies to the protocol entry, or to the royalty default
+    // for sales on the protocol if there is no protocol entry
+    let (royalty_entry, royalty_lock) = match &protocol {
+        Some(protocol) => {
+            let royalty_protocol = royalty_protocols()
+                .may_load(deps.storage, (collection.clone(), protocol.clone()))?;
+            (
+                royalty_protocol
+                    .map(|royalty_protocol| royalty_protocol.royalty_entry)
+                    .or(royalty_default.map(|royalty_default| royalty_default.royalty_entry)),
+                royalty_locks.protocols,
+            )
+        }
+        None => (
+            royalty_default.map(|royalty_default| royalty_default.royalty_entry),
+            royalty_locks.default,
+        ),
+    };
+
+    let royalty_entry = royalty_entry.ok_or_else(|| ContractError::RoyaltyNotFound {
+        collection: collection.clone(),
+        protocol: protocol.clone(),
+    })?;
+    ensure!(
+        royalty_lock != Some(RoyaltyLock::Full),
+        ContractError::RoyaltyLocked {
+            lock: RoyaltyLock::Full,
+        }
+    );
+
+    let royalty_promotion = RoyaltyPromotion {
+        collection: collection.clone(),
+        protocol: protocol.clone(),
+        share,
+        start,
+        end,
+    };
+    royalty_promotion.validate(
+        &CONFIG.load(deps.storage)?,
+        env.block.time,
+        royalty_entry.share,
+    )?;
+
+    match &protocol {
+        Some(protocol) => ROYALTY_PROTOCOL_PROMOTIONS.save(
+            deps.storage,
+            (collection.clone(), protocol.clone()),
+            &royalty_promotion,
+        )?,
+        None => {
+            ROYALTY_DEFAULT_PROMOTIONS.save(deps.storage, collection.clone(), &royalty_promotion)?
+        }
+    }
+
+    let mut event = Event::new("set-collection-royalty-promotion")
+        .add_attribute("collection", collection.to_string());
+    if let Some(protocol) = &protocol {
+        event = event.add_attribute("protocol", protocol.to_string());
+    }
+    event = event.add_attributes(vec![
+        attr("share", share.to_string()),
+        attr("start", start.to_string()),
+        attr("end", end.to_string()),
+    ]);
+
+    let response = Response::new().add_event(event);
+
+    Ok(response)
+}
+
+pub fn execute_remove_collection_royalty_promotion(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+    protocol: Option<Addr>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    only_collection_manager(deps.as_ref(), &env, &info, &collection)?;
+
+    let mut event = Event::new("remove-collection-royalty-promotion")
+        .add_attribute("collection", collection.to_string());
+
+    let exists = match &protocol {
+        Some(protocol) => {
+            event = event.add_attribute("protocol", protocol.to_string());
+            let royalty_protocol_key: RoyaltyProtocolKey = (collection.clone(), protocol.clone());
+            let exists =
+                ROYALTY_PROTOCOL_PROMOTIONS.has(deps.storage, royalty_protocol_key.clone());
+            ROYALTY_PROTOCOL_PROMOTIONS.remove(deps.storage, royalty_protocol_key);
+            exists
+        }
+        None => {
+            let exists = ROYALTY_DEFAULT_PROMOTIONS.has(deps.storage, collection.clone());
+            ROYALTY_DEFAULT_PROMOTIONS.remove(deps.storage, collection.clone());
+            exists
+        }
+    };
+    ensure!(
+        exists,
+        ContractError::PromotionNotFound {
+            collection,
+            protocol,
+        }
+    );
+
+    let response = Response::new().add_event(event);
+
+    Ok(response)
+}
+
+pub fn execute_lock_collection_royalty(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    _env: Env,
+    collection: Addr,
+    default: Option<RoyaltyLock>,
+    protocols: Option<RoyaltyLock>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    only_collection_creator(deps.as_ref(), &info, &collection)?;
+    record_collection_creator(&mut deps, &collection)?;
+
+    ensure!(
+        default.is_some() || protocols.is_some(),
+        ContractError::NoRoyaltyLock {}
+    );
+
+    let mut royalty_locks = RoyaltyLocks::load(deps.storage, &collection)?;
+    let mut event =
+        Event::new("lock-collection-royalty").add_attribute("collection", collection.to_string());
+
+    if let Some(default) = default {
+        ensure!(
+            royalty_defaults().has(deps.storage, collection.clone()),
+            ContractError::RoyaltyNotFound {
+                collection: collection.clone(),
+                protocol: None,
+            }
+        );
+        ensure!(
+            royalty_locks.default.as_ref() <= Some(&default),
+            ContractError::RoyaltyLockLoosened { lock: default }
+        );
+        event = event.add_attribute("default", default.to_string());
+        royalty_locks.default = Some(default);
+    }
+
+    if let Some(protocols) = protocols {
+        ensure!(
+            royalty_locks.protocols.as_ref() <= Some(&protocols),
+            ContractError::RoyaltyLockLoosened { lock: protocols }
+        );
+        event = event.add_attribute("protocols", protocols.to_string());
+        royalty_locks.protocols = Some(protocols);
+    }
+
+    ROYALTY_LOCKS.save(deps.storage, collection, &royalty_locks)?;
+
+    let response = Response::new().add_event(event);
+
+    Ok(response)
+}
+
+pub fn execute_set_collection_royalty_update_params(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    _env: Env,
+    collection: Addr,
+    update_params: UpdateParams,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    only_collection_creator(deps.as_ref(), &info, &collection)?;
+    record_collection_creator(&mut deps, &collection)?;
+
+    ensure!(
+        update_params.update_wait_period.is_some() || update_params.max_share_delta.is_some(),
+        ContractError::NoUpdateParams {}
+    );
+
+    let mut royalty_default = royalty_defaults()
+        .load(deps.storage, collection.clone())
+        .map_err(|_| ContractError::RoyaltyNotFound {
+            collection: collection.clone(),
+            protocol: None,
+        })?;
+    let previous = royalty_default.update_params.unwrap_or_default();
+
+    update_params.validate(&CONFIG.load(deps.storage)?, &previous)?;
+    royalty_default.update_params = Some(previous.merge(&update_params));
+
+    royalty_defaults().save(deps.storage, collection.clone(), &royalty_default)?;
+
+    let mut event = Event::new("set-collection-royalty-update-params")
+        .add_attribute("collection", collection.to_string());
+    if let Some(update_wait_period) = update_params.update_wait_period {
+        event = event.add_attribute("update_wait_period", update_wait_period.to_string());
+    }
+    if let Some(max_share_delta) = update_params.max_share_delta {
+        event = event.add_attribute("max_share_delta", max_share_delta.to_string());
+    }
+
+    let response = Response::new().add_event(event);
+
+    Ok(response)
+}
+
+pub fn execute_set_collection_royalty_metadata(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+    protocol: Option<Addr>,
+    metadata: Option<RoyaltyMetadata>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    only_collection_manager(deps.as_ref(), &env, &info, &collection)?;
+
+    if let Some(metadata) = &metadata {
+        metadata.validate()?;
+    }
+
+    let mut event = Event::new("set-collection-royalty-metadata")
+        .add_attribute("collection", collection.to_string());
+
+    match &protocol {
+        Some(protocol) => {
+            event = event.add_attribute("protocol", protocol.to_string());
+
+            let royalty_protocol_key: RoyaltyProtocolKey = (collection.clone(), protocol.clone());
+            ensure!(
+                royalty_protocols().has(deps.storage, royalty_protocol_key.clone()),
+                ContractError::RoyaltyNotFound {
+                    collection: collection.clone(),
+                    protocol: Some(protocol.clone()),
+                }
+            );
+            match &metadata {
+                Some(metadata) => {
+                    ROYALTY_PROTOCOL_METADATA.save(deps.storage, royalty_protocol_key, metadata)?
+                }
+                None => ROYALTY_PROTOCOL_METADATA.remove(deps.storage, royalty_protocol_key),
+            }
+        }
+        None => {
+            ensure!(
+                royalty_defaults().has(deps.storage, collection.clone()),
+                ContractError::RoyaltyNotFound {
+                    collection: collection.clone(),
+                    protocol: None,
+                }
+            );
+            match &metadata {
+                Some(metadata) => {
+                    ROYALTY_DEFAULT_METADATA.save(deps.storage, collection.clone(), metadata)?
+                }
+                None => ROYALTY_DEFAULT_METADATA.remove(deps.storage, collection.clone()),
+            }
+        }
+    }
+
+    if let Some(metadata) = &metadata {
+        if let Some(label) = &metadata.label {
+            event = event.add_attribute("label", label);
+        }
+        if let Some(role) = &metadata.role {
+            event = event.add_attribute("role", role.to_string());
+        }
+        if let Some(url) = &metadata.url {
+            event = event.add_attribute("url", url);
+        }
+    }
+
+    let response = Response::new().add_event(event);
+
+    Ok(response)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_set_collection_royalty_denom_share(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+    protocol: Option<Addr>,
+    denom: String,
+    share: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure_collection_not_frozen(deps.storage, &collection)?;
+    only_collection_manager(deps.as_ref(), &env, &info, &collection)?;
+    ensure_no_pending_proposal(deps.storage, &collection, protocol.as_ref())?;
+
+    let config = load_effective_config(deps.storage, &collection)?;
+    let royalty_locks = RoyaltyLocks::load(deps.storage, &collection)?;
+
+    let mut event = Event::new("set-collection-royalty-denom-share")
+        .add_attribute("collection", collection.to_string());
+
+    let (previous, current) = match &protocol {
+        Some(protocol) => {
+            event = event.add_attribute("protocol", protocol.to_string());
+
+            let royalty_protocol_key: RoyaltyProtocolKey = (collection.clone(), protocol.clone());
+            let mut royalty_protocol = royalty_protocols()
+                .load(deps.storage, royalty_protocol_key.clone())
+                .map_err(|_| ContractError::RoyaltyNotFound {
+                    collection: collection.clone(),
+                    protocol: Some(protocol.clone()),
+                })?;
+            let previous = royalty_protocol.royalty_entry.clone();
+
+            update_denom_share(
+                &mut royalty_protocol.royalty_entry,
+                &config,
+                royalty_locks.protocols.as_ref(),
+                env.block.time,
+                &denom,
+                share,
+            )?;
+            RegisteredProtocol::validate_update(
+                deps.storage,
+                protocol,
+                &previous,
+                &royalty_protocol.royalty_entry,
+            )?;
+
+            royalty_protocols().save(deps.storage, royalty_protocol_key, &royalty_protocol)?;
+            (previous, royalty_protocol.royalty_entry)
+        }
+        None => {
+            let mut royalty_default = royalty_defaults()
+                .load(deps.storage, collection.clone())
+                .map_err(|_| ContractError::RoyaltyNotFound {
+                    collection: collection.clone(),
+                    protocol: None,
+                })?;
+            let previous = royalty_default.royalty_entry.clone();
+
+            update_denom_share(
+                &mut royalty_default.royalty_entry,
+                &config,
+                royalty_locks.default.as_ref(),
+                env.block.time,
+                &denom,
+                share,
+            )?;
+
+            royalty_defaults().save(deps.storage, collection.clone(), &royalty_default)?;
+            (previous, royalty_default.royalty_entry)
+        }
+    };
+
+    append_royalty_history(
+        deps.storage,
+        &collection,
+        protocol.as_ref(),
+        RoyaltyAction::Update,
+        Some(&previous),
+        Some(&current),
+        &info.sender,
+        env.block.time,
+    )?;
+
+    event = event.add_attribute("denom", denom);
+    if let Some(share) = share {
+        event = event.add_attribute("share", share.to_string());
+    }
+    event = event.add_attribute("updated", env.block.time.to_string());
+
+    let response = Response::new().add_event(event);
+
+    Ok(response)
+}
+
+/// Applies a denom share override to a royalty entry, enforcing the same rules as
+/// `update_royalty_entry`.
+fn update_denom_share(
+    royalty_entry: &mut RoyaltyEntry,
+    config: &Config,
+    royalty_lock: Option<&RoyaltyLock>,
+    block_time: Timestamp,
+    denom: &str,
+    share: Option<Decimal>,
+) -> Result<(), ContractError> {
+    royalty_entry.ensure_updatable(config, block_time)?;
+    let previous = royalty_entry.clone();
+
+    royalty_entry.set_denom_share(config, denom, share)?;
+    royalty_entry.updated = Some(block_time);
+    royalty_entry.validate()?;
+
+    if let Some(royalty_lock) = royalty_lock {
+        royalty_lock.validate_update(&previous, royalty_entry)?;
+    }
+
+    Ok(())
+}
+
+pub fn execute_batch_collection_royalty(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    operations: Vec<RoyaltyOperation>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    ensure!(
+        !operations.is_empty() && operations.len() <= MAX_BATCH_SIZE,
+        ContractError::InvalidBatchSize {
+            size: operations.len(),
+            max_batch_size: MAX_BATCH_SIZE,
+        }
+    );
+
+    let api = deps.api;
+    let mut response = Response::new();
+
+    for operation in operations {
+        let operation_response = match operation {
+            RoyaltyOperation::SetDefault {
+                collection,
+                recipient,
+                share,
+            } => execute_set_collection_royalty_default(
+                deps.branch(),
+                info.clone(),
+                env.clone(),
+                api.addr_validate(&collection)?,
+                api.addr_validate(&recipient)?,
+                share,
+            )?,
+            RoyaltyOperation::UpdateDefault {
+                collection,
+                recipient,
+                share,
+                share_delta,
+                decrement,
+            } => execute_update_collection_royalty_default(
+                deps.branch(),
+                info.clone(),
+                env.clone(),
+                api.addr_validate(&collection)?,
+                maybe_addr(api, recipient)?,
+                ShareUpdate::from_msg(share, share_delta, decrement)?,
+            )?,
+            RoyaltyOperation::SetProtocol {
+                collection,
+                protocol,
+                recipient,
+                share,
+            } => execute_set_collection_royalty_protocol(
+                deps.branch(),
+                info.clone(),
+                env.clone(),
+                api.addr_validate(&collection)?,
+                api.addr_validate(&protocol)?,
+                api.addr_validate(&recipient)?,
+                share,
+            )?,
+            RoyaltyOperation::UpdateProtocol {
+                collection,
+                protocol,
+                recipient,
+                share,
+                share_delta,
+                decrement,
+            } => execute_update_collection_royalty_protocol(
+                deps.branch(),
+                info.clone(),
+                env.clone(),
+                api.addr_validate(&collection)?,
+                api.addr_validate(&protocol)?,
+                maybe_addr(api, recipient)?,
+                ShareUpdate::from_msg(share, share_delta, decrement)?,
+            )?,
+        };
+
+        response = response
+            .add_submessages(operation_response.messages)
+            .add_events(operation_response.events);
+    }
+
+    Ok(response)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_distribute_sale(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+    protocol: Option<Addr>,
+    seller: Addr,
+    protocol_fee: Option<ProtocolFee>,
+    escrow_royalty: bool,
+) -> Result<Response, ContractError> {
+    let sale = one_coin(&info)?;
+    let denom = sale.denom.clone();
+
+    let mut response = Response::new();
+
+    // Sales of collections not yet on the registry initialize them with the royalty set on
+    // the collection contract, as `fetch_or_set_royalties` does for protocols
+    if !royalty_defaults().has(deps.storage, collection.clone())
+        && !FROZEN_COLLECTIONS.has(deps.storage, collection.clone())
+    {
+        if let Some(royalty_entry) =
+            query_collection_royalty_entry(&deps.querier, deps.api, &collection)?
+        {
+            response = response.add_event(initialize_collection_royalty(
+                &mut deps,
+                &env,
+                &info.sender,
+                &collection,
+                royalty_entry,
+            )?);
+        }
+    }
+
+    let royalty_payment_response = query_royalty_payment(
+        deps.as_ref(),
+        env.clone(),
+        collection.clone(),
+        protocol.clone(),
+        Some(denom.clone()),
+    )?;
+    let royalty_entry = select_royalty_entry(royalty_payment_response)
+        .map(|resolved_royalty_entry| resolved_royalty_entry.royalty_entry);
+
+    let royalty_amount = royalty_entry
+        .as_ref()
+        .map_or(Uint128::zero(), |royalty_entry| {
+            sale.amount.mul_floor(royalty_entry.share)
+        });
+    if let Some(royalty_entry) = &royalty_entry {
+        if !royalty_amount.is_zero() {
+            // Royalty stats are only recorded for sales distributed by an active registered
+            // protocol on its own behalf, so that senders cannot credit other protocols
+            if protocol.as_ref() == Some(&info.sender)
+                && RegisteredProtocol::is_active(deps.storage, &info.sender)?
+            {
+                record_royalty_payment(
+                    deps.storage,
+                    &collection,
+                    &royalty_entry.recipient,
+                    &info.sender,
+                    &coin(royalty_amount.u128(), &denom),
+                    env.block.time,
+                )?;
+            }
+
+            if escrow_royalty {
+                ROYALTY_BALANCES.update(
+                    deps.storage,
+                    (royalty_entry.recipient.clone(), denom.clone()),
+                    |balance| -> StdResult<_> {
+                        Ok(balance.unwrap_or_default().checked_add(royalty_amount)?)
+                    },
+                )?;
+                response = response.add_event(Event::new("credit-royalty").add_attributes(vec![
+                    attr("recipient", royalty_entry.recipient.to_string()),
+                    attr("amount", coin(royalty_amount.u128(), &denom).to_string()),
+                ]));
+            } else {
+                response = response.add_message(BankMsg::Send {
+                    to_address: royalty_entry.recipient.to_string(),
+                    amount: vec![coin(royalty_amount.u128(), &denom)],
+                });
+            }
+        }
+    }
+
+    let mut protocol_fee_amount = Uint128::zero();
+    if let Some(protocol_fee) = protocol_fee {
+        ensure!(
+            protocol_fee.fee_percent <= Decimal::one(),
+            ContractError::InvalidProtocolFee {
+                fee_percent: protocol_fee.fee_percent,
+            }
+        );
+        let fair_burn = deps.api.addr_validate(&protocol_fee.fair_burn)?;
+        let fee_recipient = maybe_addr(deps.api, protocol_fee.recipient)?;
+
+        protocol_fee_amount = sale.amount.mul_ceil(protocol_fee.fee_percent);
+        if !protocol_fee_amount.is_zero() {
+            response = append_fair_burn_msg(
+                &fair_burn,
+                vec![coin(protocol_fee_amount.u128(), &denom)],
+                fee_recipient.as_ref(),
+                response,
+            );
+        }
+    }
+
+    let seller_amount = sale
+        .amount
+        .checked_sub(royalty_amount + protocol_fee_amount)
+        .map_err(|_| ContractError::SalePriceExceeded {
+            price: sale.amount,
+            royalty_amount,
+            protocol_fee_amount,
+        })?;
+    if !seller_amount.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: seller.to_string(),
+            amount: vec![coin(seller_amount.u128(), &denom)],
+        });
+    }
+
+    let receipt = DistributionReceipt {
+        collection,
+        protocol,
+        seller,
+        sale,
+        royalty_recipient: royalty_entry.map(|royalty_entry| royalty_entry.recipient),
+        royalty: coin(royalty_amount.u128(), &denom),
+        royalty_escrowed: escrow_royalty && !royalty_amount.is_zero(),
+        protocol_fee: coin(protocol_fee_amount.u128(), &denom),
+        seller_proceeds: coin(seller_amount.u128(), &denom),
+    };
+
+    let mut event =
+        Event::new("distribute-sale").add_attribute("collection", receipt.collection.to_string());
+    if let Some(protocol) = &receipt.protocol {
+        event = event.add_attribute("protocol", protocol.to_string());
+    }
+    if let Some(royalty_recipient) = &receipt.royalty_recipient {
+        event = event.add_attribute("royalty_recipient", royalty_recipient.to_string());
+    }
+    event = event.add_attributes(vec![
+        attr("seller", receipt.seller.to_string()),
+        attr("sale", receipt.sale.to_string()),
+        attr("royalty", receipt.royalty.to_string()),
+        attr("protocol_fee", receipt.protocol_fee.to_string()),
+        attr("seller_proceeds", receipt.seller_proceeds.to_string()),
+    ]);
+
+    response = response
+        .add_event(event)
+        .set_data(to_json_binary(&receipt)?);
+
+    Ok(response)
+}
+
+pub fn execute_record_royalty_payment(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+    recipient: Addr,
+    amount: Coin,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    record_royalty_payment(
+        deps.storage,
+        &collection,
+        &recipient,
+        &info.sender,
+        &amount,
+        env.block.time,
+    )?;
+
+    let response =
+        Response::new().add_event(Event::new("record-royalty-payment").add_attributes(vec![
+            attr("collection", collection.to_string()),
+            attr("recipient", recipient.to_string()),
+            attr("protocol", info.sender.to_string()),
+            attr("amount", amount.to_string()),
+        ]));
+
+    Ok(response)
+}
+
+pub fn execute_claim_royalties(
+    deps: DepsMut,
+    info: MessageInfo,
+    _env: Env,
+    denoms: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let denoms = match denoms {
+        Some(denoms) => denoms,
+        None => ROYALTY_BALANCES
+            .prefix(info.sender.clone())
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?,
+    };
+
+    let mut claimed = vec![];
+    for denom in denoms {
+        let royalty_balance_key = (info.sender.clone(), denom.clone());
+        if let Some(amount) =
+            ROYALTY_BALANCES.may_load(deps.storage, royalty_balance_key.clone())?
+        {
+            ROYALTY_BALANCES.remove(deps.storage, royalty_balance_key);
+            claimed.push(coin(amount.u128(), denom));
+        }
+    }
+
+    ensure!(
+        !claimed.is_empty(),
+        ContractError::NothingToClaim {
+            recipient: info.sender.clone(),
+        }
+    );
+    // Bank sends require coins sorted by denom
+    claimed.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+    let response = Response::new()
+        .add_event(Event::new("claim-royalties").add_attributes(vec![
+            attr("recipient", info.sender.to_string()),
+            attr(
+                "amount",
+                claimed
+                    .iter()
+                    .map(|coin| coin.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+        ]))
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: claimed,
+        });
+
+    Ok(response)
+}
+
+/// Records the collection admin when the collection royalties are registered, or when
+/// the admin of a collection registered before admins were recorded first acts on it,
+/// so that later changes of the collection admin can be detected.
+fn record_collection_creator(deps: &mut DepsMut, collection: &Addr) -> Result<(), ContractError> {
+    if COLLECTION_CREATORS.has(deps.storage, collection.clone()) {
+        return Ok(());
+    }
+
+    // Collections without an admin cannot be claimed, so are not recorded
+    if let Ok(collection_admin) = query_collection_admin(&deps.querier, collection) {
+        COLLECTION_CREATORS.save(deps.storage, collection.clone(), &collection_admin)?;
+    }
+
+    Ok(())
+}
+
+/// Lets a new collection admin claim control of the collection royalties. Claiming revokes
+/// the managers and pending proposals of the previous admin, resets the update cooldown of
+/// the collection's royalty entries, and optionally changes the recipient of the royalty
+/// default. Shares are left unchanged, and locks continue to apply.
+pub fn execute_claim_collection_royalty(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+    recipient: Option<Addr>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure_collection_not_frozen(deps.storage, &collection)?;
+    only_collection_creator(deps.as_ref(), &info, &collection)?;
+
+    // Without a recorded admin, a change of the collection admin cannot be established
+    let recorded_creator = COLLECTION_CREATORS
+        .may_load(deps.storage, collection.clone())?
+        .ok_or_else(|| ContractError::CollectionAdminNotRecorded {
+            collection: collection.clone(),
+        })?;
+    ensure!(
+        recorded_creator != info.sender,
+        ContractError::CollectionAdminUnchanged {
+            collection: collection.clone(),
+        }
+    );
+    COLLECTION_CREATORS.save(deps.storage, collection.clone(), &info.sender)?;
+
+    let mut event = Event::new("claim-collection-royalty").add_attributes(vec![
+        attr("collection", collection.to_string()),
+        attr("creator", info.sender.to_string()),
+        attr("previous_creator", recorded_creator.to_string()),
+    ]);
+
+    let managers = COLLECTION_MANAGERS
+        .prefix(collection.clone())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for manager in managers {
+        COLLECTION_MANAGERS.remove(deps.storage, (collection.clone(), manager));
+    }
+
+    ROYALTY_DEFAULT_PROPOSALS.remove(deps.storage, collection.clone());
+    let proposal_protocols = ROYALTY_PROTOCOL_PROPOSALS
+        .prefix(collection.clone())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for protocol in proposal_protocols {
+        ROYALTY_PROTOCOL_PROPOSALS.remove(deps.storage, (collection.clone(), protocol));
+    }
+
+    let collection_royalty_protocols = royalty_protocols()
+        .prefix(collection.clone())
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (protocol, mut royalty_protocol) in collection_royalty_protocols {
+        royalty_protocol.royalty_entry.updated = None;
+        royalty_protocols().save(
+            deps.storage,
+            (collection.clone(), protocol),
+            &royalty_protocol,
+        )?;
+    }
+
+    match royalty_defaults().may_load(deps.storage, collection.clone())? {
+        Some(mut royalty_default) => {
+            let previous = royalty_default.royalty_entry.clone();
+
+            royalty_default.royalty_entry.updated = None;
+            if let Some(recipient) = recipient {
+                ensure!(
+                    RoyaltyLocks::load(deps.storage, &collection)?.default
+                        != Some(RoyaltyLock::Full),
+                    ContractError::RoyaltyLocked {
+                        lock: RoyaltyLock::Full,
+                    }
+                );
+                royalty_default.royalty_entry.recipient = recipient.clone();
+                event = event.add_attribute("recipient", recipient.to_string());
+            }
+
+            royalty_defaults().save(deps.storage, collection.clone(), &royalty_default)?;
+
+            append_royalty_history(
+                deps.storage,
+                &collection,
+                None,
+                RoyaltyAction::Claim,
+                Some(&previous),
+                Some(&royalty_default.royalty_entry),
+                &info.sender,
+                env.block.time,
+            )?;
+        }
+        None => ensure!(
+            recipient.is_none(),
+            ContractError::RoyaltyNotFound {
+                collection: collection.clone(),
+                protocol: None,
+            }
+        ),
+    }
+
+    let response = Response::new().add_event(event);
+
+    Ok(response)
+}
+
+pub fn execute_grant_collection_manager(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    collection: Addr,
+    manager: Addr,
+    expires: Expiration,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    only_collection_creator(deps.as_ref(), &info, &collection)?;
+    record_collection_creator(&mut deps, &collection)?;
+
+    ensure!(
+        !expires.is_expired(&env.block),
+        ContractError::ManagerExpired { expires }
+    );
+
+    COLLECTION_MANAGERS.save(
+        deps.storage,
+        (collection.clone(), manager.clone()),
+        &CollectionManager {
+            collection: collection.clone(),
+            manager: manager.clone(),
+            expires,
+        },
+    )?;
+
+    let response =
+        Response::new().add_event(Event::new("grant-collection-manager").add_attributes(vec![
+            attr("collection", collection.to_string()),
+            attr("manager", manager.to_string()),
+            attr("expires", expires.to_string()),
+        ]));
+
+    Ok(response)
+}
+
+pub fn execute_revoke_collection_manager(
+    deps: DepsMut,
+    info: MessageInfo,
+    _env: Env,
+    collection: Addr,
+    manager: Addr,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    // Managers may renounce their own permissions
+    if info.sender != manager {
+        only_collection_creator(deps.as_ref(), &info, &collection)?;
+    }
+
+    let collection_manager_key = (collection.clone(), manager.clone());
+    ensure!(
+        COLLECTION_MANAGERS.has(deps.storage, collection_manager_key.clone()),
+        ContractError::ManagerNotFound {
+            collection: collection.clone(),
+            manager: manager.clone(),
+        }
+    );
+    COLLECTION_MANAGERS.remove(deps.storage, collection_manager_key);
+
+    let response =
+        Response::new().add_event(Event::new("revoke-collection-manager").add_attributes(vec![
+            attr("collection", collection.to_string()),
+            attr("manager", manager.to_string()),
+        ]));
+
+    Ok(response)
+}
+
+/// Re-saves a page of royalty defaults so that the secondary indices of the royalty
+/// map are populated for entries written before they existed.
+pub fn execute_rebuild_royalty_default_indices(
+    deps: DepsMut,
+    info: MessageInfo,
+    _env: Env,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let limit = limit
+        .unwrap_or(MAX_REBUILD_INDICES_LIMIT)
+        .min(MAX_REBUILD_INDICES_LIMIT) as usize;
+    let entries = royalty_defaults()
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut event = Event::new("rebuild-royalty-default-indices")
+        .add_attribute("count", entries.len().to_string());
+    if let Some((collection, _)) = entries.last() {
+        event = event.add_attribute("last", collection.to_string());
+    }
+
+    for (collection, royalty_default) in entries {
+        royalty_defaults().save(deps.storage, collection, &royalty_default)?;
+    }
+
+    let response = Response::new().add_event(event);
+
+    Ok(response)
+}
+
+/// Re-saves a page of protocol entries so that the secondary indices of the royalty
+/// map are populated for entries written before they existed.
+pub fn execute_rebuild_royalty_protocol_indices(
+    deps: DepsMut,
+    info: MessageInfo,
+    _env: Env,
+    start_after: Option<RoyaltyProtocolKey>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let limit = limit
+        .unwrap_or(MAX_REBUILD_INDICES_LIMIT)
+        .min(MAX_REBUILD_INDICES_LIMIT) as usize;
+    let entries = royalty_protocols()
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut event = Event::new("rebuild-royalty-protocol-indices")
+        .add_attribute("count", entries.len().to_string());
+    if let Some(((collection, protocol), _)) = entries.last() {
+        event = event.add_attributes(vec![
+            attr("last_collection", collection.to_string()),
+            attr("last_protocol", protocol.to_string()),
+        ]);
+    }
+
+    for (royalty_protocol_key, royalty_protocol) in entries {
+        royalty_protocols().save(deps.storage, royalty_protocol_key, &royalty_protocol)?;
+    }
+
+    let response = Response::new().add_event(event);
 
     Ok(response)
 }
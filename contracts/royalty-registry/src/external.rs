@@ -1,31 +1,76 @@
 use crate::{
-    msg::{ExecuteMsg, QueryMsg, RoyaltyPaymentResponse},
-    state::RoyaltyEntry,
+    adapter::CollectionAdapter,
+    msg::{ExecuteMsg, QueryMsg, ResolvedRoyaltyEntry, RoyaltyPaymentResponse, RoyaltySource},
+    resolve::{collection_fallback, select_royalty_entry},
+    state::{RoyaltyEntry, COLLECTION_MANAGERS},
     ContractError,
 };
 
-use cosmwasm_std::{ensure, to_json_binary, Addr, Deps, MessageInfo, QuerierWrapper, WasmMsg};
-use sg721_base::msg::{CollectionInfoResponse, QueryMsg as Sg721QueryMsg};
+use cosmwasm_std::{
+    ensure, to_json_binary, Addr, Api, Deps, Env, MessageInfo, QuerierWrapper, WasmMsg,
+};
 use sg_std::Response;
 
-/// Ensures that the sender is the collection creator.
+/// Ensures that the sender is the collection creator, or the wasm admin for
+/// collections that are not sg721 collections.
 pub fn only_collection_creator(
     deps: Deps,
     info: &MessageInfo,
     collection: &Addr,
 ) -> Result<(), ContractError> {
-    let collection_info: CollectionInfoResponse = deps
-        .querier
-        .query_wasm_smart(collection, &Sg721QueryMsg::CollectionInfo {})?;
+    let collection_admin = query_collection_admin(&deps.querier, collection)?;
 
     ensure!(
-        info.sender == collection_info.creator,
-        ContractError::Unauthorized("Only collection owner can execute this action".to_string())
+        info.sender == collection_admin,
+        ContractError::NotCollectionAdmin {
+            sender: info.sender.clone(),
+            admin: collection_admin,
+        }
     );
 
     Ok(())
 }
 
+/// Queries the current collection creator, or the wasm admin for collections that are
+/// not sg721 collections.
+pub fn query_collection_admin(
+    querier: &QuerierWrapper,
+    collection: &Addr,
+) -> Result<Addr, ContractError> {
+    CollectionAdapter::detect(querier, collection)?.query_admin(querier, collection)
+}
+
+/// Ensures that the sender is the collection creator, or a delegated manager
+/// of the collection whose grant has not expired.
+pub fn only_collection_manager(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+    collection: &Addr,
+) -> Result<(), ContractError> {
+    let collection_manager =
+        COLLECTION_MANAGERS.may_load(deps.storage, (collection.clone(), info.sender.clone()))?;
+
+    if let Some(collection_manager) = collection_manager {
+        if !collection_manager.expires.is_expired(&env.block) {
+            return Ok(());
+        }
+    }
+
+    only_collection_creator(deps, info, collection)
+}
+
+/// Queries the royalty set on the collection contract itself, if any.
+pub fn query_collection_royalty_entry(
+    querier: &QuerierWrapper,
+    api: &dyn Api,
+    collection: &Addr,
+) -> Result<Option<RoyaltyEntry>, ContractError> {
+    let royalty_entry = CollectionAdapter::detect(querier, collection)?
+        .query_royalty_entry(querier, api, collection)?;
+    Ok(royalty_entry)
+}
+
 /// Invoke `fetch_royalty_entry` to fetch the royalties for a given NFT sale
 /// with an optional protocol address.
 ///
@@ -46,23 +91,91 @@ pub fn fetch_royalty_entry(
     collection: &Addr,
     protocol: Option<&Addr>,
 ) -> Result<Option<RoyaltyEntry>, ContractError> {
+    fetch_royalty_entry_for_denom(querier, royalty_registry, collection, protocol, None)
+}
+
+/// Invoke `fetch_royalty_entry_for_denom` to fetch the royalties for a given NFT sale
+/// settled in an optional denom, with an optional protocol address.
+///
+/// # Arguments
+///
+/// * `deps` - [cosmwasm_std::Deps]
+/// * `royalty_registry` - The address of the royalty registry.
+/// * `collection` - The address of the collection contract to fetch royalties for.
+/// * `protocol` - The address of the protocol looking to pay royalties (optional).
+/// * `denom` - The denom the sale is settled in, which selects any denom share (optional).
+///
+/// # Returns
+///
+/// * `RoyaltyEntry` - The [RoyaltyEntry] for the given collection and protocol (if any).
+///
+pub fn fetch_royalty_entry_for_denom(
+    querier: &QuerierWrapper,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    protocol: Option<&Addr>,
+    denom: Option<&str>,
+) -> Result<Option<RoyaltyEntry>, ContractError> {
+    let royalty_payment_response =
+        query_royalty_payment(querier, royalty_registry, collection, protocol, denom)?;
+
+    Ok(select_royalty_entry(royalty_payment_response)
+        .map(|resolved_royalty_entry| resolved_royalty_entry.royalty_entry))
+}
+
+/// Invoke `resolve_royalty_entry` to resolve the royalties for a given NFT sale
+/// with an optional protocol address, without any side effects. If royalties are not
+/// found on the royalty registry then the collection contract's royalties are used.
+///
+/// # Arguments
+///
+/// * `deps` - [cosmwasm_std::Deps]
+/// * `royalty_registry` - The address of the royalty registry.
+/// * `collection` - The address of the collection contract to resolve royalties for.
+/// * `protocol` - The address of the protocol looking to pay royalties (optional).
+/// * `denom` - The denom the sale is settled in, which selects any denom share (optional).
+///
+/// # Returns
+///
+/// * `ResolvedRoyaltyEntry` - The effective [RoyaltyEntry] and where it was resolved from (if any).
+///
+pub fn resolve_royalty_entry(
+    deps: Deps,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    protocol: Option<&Addr>,
+    denom: Option<&str>,
+) -> Result<Option<ResolvedRoyaltyEntry>, ContractError> {
+    let royalty_payment_response =
+        query_royalty_payment(&deps.querier, royalty_registry, collection, protocol, denom)?;
+    let protocol_max_share = royalty_payment_response.protocol_max_share;
+
+    if let Some(resolved_royalty_entry) = select_royalty_entry(royalty_payment_response) {
+        return Ok(Some(resolved_royalty_entry));
+    }
+
+    let collection_royalty = query_collection_royalty_entry(&deps.querier, deps.api, collection)?;
+
+    Ok(collection_royalty
+        .map(|royalty_entry| collection_fallback(royalty_entry, protocol_max_share)))
+}
+
+fn query_royalty_payment(
+    querier: &QuerierWrapper,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    protocol: Option<&Addr>,
+    denom: Option<&str>,
+) -> Result<RoyaltyPaymentResponse, ContractError> {
     let royalty_payment_response = querier.query_wasm_smart::<RoyaltyPaymentResponse>(
         royalty_registry,
         &QueryMsg::RoyaltyPayment {
             collection: collection.to_string(),
             protocol: protocol.map(|p| p.to_string()),
+            denom: denom.map(|d| d.to_string()),
         },
     )?;
-
-    if let Some(royalty_protocol) = royalty_payment_response.royalty_protocol {
-        return Ok(Some(royalty_protocol.royalty_entry));
-    }
-
-    if let Some(royalty_default) = royalty_payment_response.royalty_default {
-        return Ok(Some(royalty_default.royalty_entry));
-    }
-
-    Ok(None)
+    Ok(royalty_payment_response)
 }
 
 /// Invoke `fetch_or_set_royalties` to fetch the royalties for a given NFT sale
@@ -88,26 +201,47 @@ pub fn fetch_or_set_royalties(
     royalty_registry: &Addr,
     collection: &Addr,
     protocol: Option<&Addr>,
-    mut response: Response,
+    response: Response,
 ) -> Result<(Option<RoyaltyEntry>, Response), ContractError> {
-    let royalty_entry = fetch_royalty_entry(&deps.querier, royalty_registry, collection, protocol)?;
-    if let Some(royalty_entry) = royalty_entry {
-        return Ok((Some(royalty_entry), response));
-    }
-
-    let collection_info: CollectionInfoResponse = deps
-        .querier
-        .query_wasm_smart(collection, &Sg721QueryMsg::CollectionInfo {})?;
+    fetch_or_set_royalties_for_denom(deps, royalty_registry, collection, protocol, None, response)
+}
 
-    if let Some(royalty_info_response) = collection_info.royalty_info {
-        let royalty_entry = RoyaltyEntry {
-            recipient: deps
-                .api
-                .addr_validate(&royalty_info_response.payment_address)?,
-            share: royalty_info_response.share,
-            updated: None,
-        };
+/// Invoke `fetch_or_set_royalties_for_denom` to fetch the royalties for a given NFT sale
+/// settled in an optional denom, with an optional protocol address. If royalties are not found on the royalty registry
+/// then the collection contract's royalties are used, and the collection contract's royalties
+/// are set on the royalty registry. Initialization is idempotent, so the same collection may
+/// be initialized by several sales in the same block.
+///
+/// # Arguments
+///
+/// * `deps` - [cosmwasm_std::Deps]
+/// * `royalty_registry` - The address of the royalty registry.
+/// * `collection` - The address of the collection contract to fetch royalties for.
+/// * `protocol` - The address of the protocol looking to pay royalties (optional).
+/// * `denom` - The denom the sale is settled in, which selects any denom share (optional).
+/// * `response` - The [cosmwasm_std::Response] object used to append the message.
+///
+/// # Returns
+///
+/// * `RoyaltyEntry` - The [RoyaltyEntry] for the given collection and protocol (if any).
+/// * `Response` - The [cosmwasm_std::Response] with the appended message.
+///
+pub fn fetch_or_set_royalties_for_denom(
+    deps: Deps,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    protocol: Option<&Addr>,
+    denom: Option<&str>,
+    mut response: Response,
+) -> Result<(Option<RoyaltyEntry>, Response), ContractError> {
+    let resolved_royalty_entry =
+        resolve_royalty_entry(deps, royalty_registry, collection, protocol, denom)?;
 
+    if let Some(ResolvedRoyaltyEntry {
+        source: RoyaltySource::CollectionFallback,
+        ..
+    }) = &resolved_royalty_entry
+    {
         response = response.add_message(WasmMsg::Execute {
             contract_addr: royalty_registry.to_string(),
             msg: to_json_binary(&ExecuteMsg::InitializeCollectionRoyalty {
@@ -116,9 +250,10 @@ pub fn fetch_or_set_royalties(
             .unwrap(),
             funds: vec![],
         });
-
-        return Ok((Some(royalty_entry), response));
     }
 
-    Ok((None, response))
+    Ok((
+        resolved_royalty_entry.map(|resolved_royalty_entry| resolved_royalty_entry.royalty_entry),
+        response,
+    ))
 }
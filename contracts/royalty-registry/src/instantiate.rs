@@ -33,7 +33,9 @@ pub fn instantiate(
     response = response.add_event(
         Event::new("initialize-config")
             .add_attribute("update_wait_period", config.update_wait_period.to_string())
-            .add_attribute("max_share_delta", config.max_share_delta.to_string()),
+            .add_attribute("max_share_delta", config.max_share_delta.to_string())
+            .add_attribute("min_share", config.min_share.to_string())
+            .add_attribute("max_share", config.max_share.to_string()),
     );
 
     Ok(response)
@@ -9,10 +9,14 @@
 //! - The collection admin can set a protocol royalty percentage for a given protocol. This protocol royalty percentage is applied when the protocol itself is calculating a royalty for the NFT sale.
 //! - Any royalty percentage set by a given collection owner can only be changed by a the maximum amount of config parameter `max_share_delta` per invocation. After changing a royalty percentage, the collection owner must wait `update_wait_period` to update the percentage again.
 //!
+//! The full set of royalty registry features is described in the [README](https://github.com/public-awesome/core/blob/main/contracts/royalty-registry/README.md).
+//!
 //! ## Additional Notes
 //!
 //! - The shares percentages set in the royalty registry are represented as [cosmwasm_std::Decimal]. The max royalty share is 1.0, which is equivalent to 100%. Consumers of the royalty registry should be aware of this when calculating the royalty amount to be paid, and can set a cap on the amount of royalties to be paid if the percentage is too high.
 
+pub mod adapter;
+pub mod constants;
 mod error;
 pub mod execute;
 mod external;
@@ -20,9 +24,16 @@ pub mod instantiate;
 pub mod migrate;
 pub mod msg;
 pub mod query;
+pub mod resolve;
 pub mod state;
 pub mod sudo;
 
+#[cfg(test)]
+mod tests;
+
 pub use crate::error::ContractError;
 pub use crate::external::fetch_or_set_royalties;
+pub use crate::external::fetch_or_set_royalties_for_denom;
 pub use crate::external::fetch_royalty_entry;
+pub use crate::external::fetch_royalty_entry_for_denom;
+pub use crate::external::resolve_royalty_entry;
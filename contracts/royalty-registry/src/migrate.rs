@@ -1,9 +1,15 @@
 use crate::{
+    constants::{
+        MAX_MAX_SHARE_DELTA, MAX_UPDATE_WAIT_PERIOD, MIN_MAX_SHARE_DELTA, MIN_UPDATE_WAIT_PERIOD,
+    },
     error::ContractError,
     instantiate::{CONTRACT_NAME, CONTRACT_VERSION},
+    state::{Config, CONFIG},
 };
 
-use cosmwasm_std::{ensure, DepsMut, Empty, Env, Event, StdError};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Decimal, DepsMut, Empty, Env, Event, StdError, Storage};
+use cw_storage_plus::Item;
 use sg_std::Response;
 
 #[cfg(not(feature = "library"))]
@@ -27,6 +33,8 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, Contra
 
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    migrate_config(deps.storage)?;
+
     let response = Response::new().add_event(
         Event::new("migrate")
             .add_attribute("from_name", prev_contract_version.contract)
@@ -37,3 +45,34 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, Contra
 
     Ok(response)
 }
+
+/// The config stored by versions of the contract prior to the introduction of share bounds.
+#[cw_serde]
+struct LegacyConfig {
+    update_wait_period: u64,
+    max_share_delta: Decimal,
+}
+
+const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+
+/// Converts a legacy config into the current config, with share bounds that
+/// allow any share between 0 and 1. Legacy update parameters outside of the
+/// config bounds are clamped to them.
+fn migrate_config(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    if CONFIG.load(storage).is_ok() {
+        return Ok(());
+    }
+
+    let legacy_config = LEGACY_CONFIG.load(storage)?;
+    Config {
+        update_wait_period: legacy_config
+            .update_wait_period
+            .clamp(MIN_UPDATE_WAIT_PERIOD, MAX_UPDATE_WAIT_PERIOD),
+        max_share_delta: legacy_config
+            .max_share_delta
+            .clamp(MIN_MAX_SHARE_DELTA, MAX_MAX_SHARE_DELTA),
+        min_share: Decimal::zero(),
+        max_share: Decimal::one(),
+    }
+    .save(storage)
+}
@@ -1,7 +1,13 @@
-use crate::state::{Config, RoyaltyDefault, RoyaltyProtocol};
+use crate::adapter::{CheckRoyaltiesResponse, RoyaltiesInfoResponse};
+use crate::state::{
+    CollectionManager, Config, RegisteredProtocol, RoyaltyDefault, RoyaltyEntry,
+    RoyaltyHistoryEntry, RoyaltyLock, RoyaltyLocks, RoyaltyMetadata, RoyaltyPeriod,
+    RoyaltyPromotion, RoyaltyProposal, RoyaltyProtocol, UpdateParams,
+};
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Decimal;
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp, Uint128};
+use cw_utils::Expiration;
 use sg_index_query::QueryOptions;
 
 #[cw_serde]
@@ -14,14 +20,21 @@ pub enum ExecuteMsg {
     InitializeCollectionRoyalty {
         collection: String,
     },
+    SyncCollectionRoyalty {
+        collection: String,
+    },
     SetCollectionRoyaltyDefault {
         collection: String,
         recipient: String,
         share: Decimal,
     },
+    /// Updates the royalty default directly. Share increases do not require a proposal,
+    /// and are subject to `update_wait_period` and `max_share_delta` like any update. The
+    /// update is rejected while a proposal for the royalty default is pending.
     UpdateCollectionRoyaltyDefault {
         collection: String,
         recipient: Option<String>,
+        share: Option<Decimal>,
         share_delta: Option<Decimal>,
         decrement: Option<bool>,
     },
@@ -31,10 +44,197 @@ pub enum ExecuteMsg {
         recipient: String,
         share: Decimal,
     },
+    /// Updates a protocol entry directly. Share increases do not require a proposal, and
+    /// are subject to `update_wait_period` and `max_share_delta` like any update. The
+    /// update is rejected while a proposal for the protocol entry is pending.
     UpdateCollectionRoyaltyProtocol {
         collection: String,
         protocol: String,
         recipient: Option<String>,
+        share: Option<Decimal>,
+        share_delta: Option<Decimal>,
+        decrement: Option<bool>,
+    },
+    /// Proposes an update of the royalty default, which anyone can apply once
+    /// `update_wait_period` has elapsed. Proposals are optional, and give marketplaces
+    /// notice of upcoming royalty changes.
+    ProposeCollectionRoyaltyDefault {
+        collection: String,
+        recipient: Option<String>,
+        share: Option<Decimal>,
+        share_delta: Option<Decimal>,
+        decrement: Option<bool>,
+    },
+    ApplyCollectionRoyaltyDefault {
+        collection: String,
+    },
+    CancelCollectionRoyaltyDefault {
+        collection: String,
+    },
+    /// Proposes an update of a protocol entry, which anyone can apply once
+    /// `update_wait_period` has elapsed.
+    ProposeCollectionRoyaltyProtocol {
+        collection: String,
+        protocol: String,
+        recipient: Option<String>,
+        share: Option<Decimal>,
+        share_delta: Option<Decimal>,
+        decrement: Option<bool>,
+    },
+    ApplyCollectionRoyaltyProtocol {
+        collection: String,
+        protocol: String,
+    },
+    CancelCollectionRoyaltyProtocol {
+        collection: String,
+        protocol: String,
+    },
+    SetCollectionRoyaltyPromotion {
+        collection: String,
+        protocol: Option<String>,
+        share: Decimal,
+        start: Timestamp,
+        end: Timestamp,
+    },
+    RemoveCollectionRoyaltyPromotion {
+        collection: String,
+        protocol: Option<String>,
+    },
+    LockCollectionRoyalty {
+        collection: String,
+        default: Option<RoyaltyLock>,
+        protocols: Option<RoyaltyLock>,
+    },
+    /// Opts the collection into a stricter `update_wait_period` and/or `max_share_delta`
+    /// than the global config. Values that are not given are left unchanged, and values
+    /// can only be made stricter.
+    SetCollectionRoyaltyUpdateParams {
+        collection: String,
+        update_params: UpdateParams,
+    },
+    /// Sets or, if `metadata` is not given, removes the display metadata of the royalty
+    /// default or a protocol entry
+    SetCollectionRoyaltyMetadata {
+        collection: String,
+        protocol: Option<String>,
+        metadata: Option<RoyaltyMetadata>,
+    },
+    /// Sets or, if `share` is not given, removes the share override for sales settled
+    /// in `denom` on the royalty default or a protocol entry
+    SetCollectionRoyaltyDenomShare {
+        collection: String,
+        protocol: Option<String>,
+        denom: String,
+        share: Option<Decimal>,
+    },
+    /// Applies a list of royalty operations atomically, with the same validation and
+    /// authorization as the individual messages.
+    BatchCollectionRoyalty {
+        operations: Vec<RoyaltyOperation>,
+    },
+    /// Distributes the sale funds sent with the message: royalties are paid according to
+    /// the registry, the protocol fee is optionally forwarded to fair burn, and the
+    /// remainder is sent to the seller. Collections not yet on the registry are first
+    /// initialized with the royalty set on the collection contract. The royalty is recorded
+    /// in the royalty stats only when the sender is an active registered protocol and is
+    /// given as `protocol`.
+    DistributeSale {
+        collection: String,
+        protocol: Option<String>,
+        seller: String,
+        protocol_fee: Option<ProtocolFee>,
+        /// Credit the royalty to the recipient's escrow balance instead of sending it
+        escrow_royalty: Option<bool>,
+    },
+    /// Records a royalty payment made by the sender, which must be a registered protocol
+    RecordRoyaltyPayment {
+        collection: String,
+        recipient: String,
+        amount: Coin,
+    },
+    /// Withdraws the sender's escrowed royalties, for all denoms if none are given
+    ClaimRoyalties {
+        denoms: Option<Vec<String>>,
+    },
+    /// Claims control of the collection royalties after the collection admin has changed
+    ClaimCollectionRoyalty {
+        collection: String,
+        recipient: Option<String>,
+    },
+    GrantCollectionManager {
+        collection: String,
+        manager: String,
+        expires: Option<Expiration>,
+    },
+    RevokeCollectionManager {
+        collection: String,
+        manager: String,
+    },
+    /// Re-saves a page of royalty defaults to populate the recipient index for entries
+    /// written before it existed. Callers continue after the `last` event attribute.
+    RebuildRoyaltyDefaultIndices {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Re-saves a page of protocol entries to populate the recipient index for entries
+    /// written before it existed. Callers continue after the `last_collection` and
+    /// `last_protocol` event attributes.
+    RebuildRoyaltyProtocolIndices {
+        start_after: Option<(String, String)>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct ProtocolFee {
+    /// The address of the fair burn contract
+    pub fair_burn: String,
+    /// The percentage of the sale price paid as a protocol fee
+    pub fee_percent: Decimal,
+    /// The recipient of the non-burned portion of the fee (optional)
+    pub recipient: Option<String>,
+}
+
+/// The breakdown of a sale distributed by the registry, set as the response data.
+#[cw_serde]
+pub struct DistributionReceipt {
+    pub collection: Addr,
+    pub protocol: Option<Addr>,
+    pub seller: Addr,
+    pub sale: Coin,
+    pub royalty_recipient: Option<Addr>,
+    pub royalty: Coin,
+    /// Whether the royalty was credited to the recipient's escrow balance
+    pub royalty_escrowed: bool,
+    pub protocol_fee: Coin,
+    pub seller_proceeds: Coin,
+}
+
+#[cw_serde]
+pub enum RoyaltyOperation {
+    SetDefault {
+        collection: String,
+        recipient: String,
+        share: Decimal,
+    },
+    UpdateDefault {
+        collection: String,
+        recipient: Option<String>,
+        share: Option<Decimal>,
+        share_delta: Option<Decimal>,
+        decrement: Option<bool>,
+    },
+    SetProtocol {
+        collection: String,
+        protocol: String,
+        recipient: String,
+        share: Decimal,
+    },
+    UpdateProtocol {
+        collection: String,
+        protocol: String,
+        recipient: Option<String>,
+        share: Option<Decimal>,
         share_delta: Option<Decimal>,
         decrement: Option<bool>,
     },
@@ -45,8 +245,17 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     #[returns(Config)]
     Config {},
+    /// The bounds that the config parameters must be within
+    #[returns(ConfigBoundsResponse)]
+    ConfigBounds {},
     #[returns(Option<RoyaltyDefault>)]
     CollectionRoyaltyDefault { collection: String },
+    /// The royalty default and, if a protocol is given, the protocol entry, with metadata
+    #[returns(RoyaltyDetailsResponse)]
+    CollectionRoyaltyDetails {
+        collection: String,
+        protocol: Option<String>,
+    },
     #[returns(Option<RoyaltyProtocol>)]
     CollectionRoyaltyProtocol {
         collection: String,
@@ -58,9 +267,134 @@ pub enum QueryMsg {
         query_options: Option<QueryOptions<String>>,
     },
     #[returns(RoyaltyPaymentResponse)]
+    /// The royalty entries for a sale, with shares resolved for the sale `denom` if given
     RoyaltyPayment {
         collection: String,
         protocol: Option<String>,
+        denom: Option<String>,
+    },
+    /// CW2981 compatible royalty info for a sale of `sale_price` on the protocol.
+    /// Royalties are set per collection, so `token_id` does not affect the result.
+    #[returns(RoyaltiesInfoResponse)]
+    RoyaltyInfo {
+        collection: String,
+        protocol: Option<String>,
+        token_id: String,
+        sale_price: Uint128,
+    },
+    /// CW2981 compatible check for whether royalties are paid for the collection on the protocol
+    #[returns(CheckRoyaltiesResponse)]
+    CheckRoyalties {
+        collection: String,
+        protocol: Option<String>,
+    },
+    #[returns(CollectionCreatorResponse)]
+    CollectionCreator { collection: String },
+    #[returns(RoyaltySyncResponse)]
+    CollectionRoyaltySync { collection: String },
+    /// Royalty payments for many collections, in the order the collections are given
+    #[returns(Vec<RoyaltyPaymentResponse>)]
+    RoyaltyPayments {
+        collections: Vec<String>,
+        protocol: Option<String>,
+        denom: Option<String>,
+    },
+    #[returns(Vec<RoyaltyDefault>)]
+    AllRoyaltyDefaults {
+        query_options: Option<QueryOptions<String>>,
+    },
+    #[returns(Vec<RoyaltyProtocol>)]
+    AllRoyaltyProtocols {
+        query_options: Option<QueryOptions<(String, String)>>,
+    },
+    #[returns(Vec<RoyaltyProtocol>)]
+    RoyaltyProtocolsByProtocol {
+        protocol: String,
+        query_options: Option<QueryOptions<String>>,
+    },
+    #[returns(Vec<RoyaltyDefault>)]
+    RoyaltyDefaultsByRecipient {
+        recipient: String,
+        query_options: Option<QueryOptions<String>>,
+    },
+    #[returns(Vec<RoyaltyProtocol>)]
+    RoyaltyProtocolsByRecipient {
+        recipient: String,
+        query_options: Option<QueryOptions<(String, String)>>,
+    },
+    #[returns(RoyaltyLocks)]
+    CollectionRoyaltyLocks { collection: String },
+    #[returns(Vec<CollectionRoyaltyLocks>)]
+    AllRoyaltyLocks {
+        query_options: Option<QueryOptions<String>>,
+    },
+    #[returns(bool)]
+    CollectionRoyaltyFrozen { collection: String },
+    #[returns(Vec<RoyaltyHistoryEntry>)]
+    RoyaltyHistory {
+        collection: String,
+        query_options: Option<QueryOptions<u64>>,
+    },
+    #[returns(Option<RoyaltyProposal>)]
+    CollectionRoyaltyDefaultProposal { collection: String },
+    #[returns(Option<RoyaltyProposal>)]
+    CollectionRoyaltyProtocolProposal {
+        collection: String,
+        protocol: String,
+    },
+    #[returns(Coin)]
+    RoyaltyBalance { recipient: String, denom: String },
+    #[returns(Vec<Coin>)]
+    RoyaltyBalances {
+        recipient: String,
+        query_options: Option<QueryOptions<String>>,
+    },
+    #[returns(Vec<Coin>)]
+    CollectionRoyaltyTotals {
+        collection: String,
+        query_options: Option<QueryOptions<String>>,
+    },
+    #[returns(Vec<Coin>)]
+    RecipientRoyaltyTotals {
+        recipient: String,
+        query_options: Option<QueryOptions<String>>,
+    },
+    #[returns(Vec<Coin>)]
+    ProtocolRoyaltyTotals {
+        protocol: String,
+        query_options: Option<QueryOptions<String>>,
+    },
+    /// Royalties paid for a collection, bucketed by period start time in seconds
+    #[returns(Vec<RoyaltyPeriod>)]
+    CollectionRoyaltyPeriods {
+        collection: String,
+        query_options: Option<QueryOptions<u64>>,
+    },
+    #[returns(Option<RoyaltyPromotion>)]
+    CollectionRoyaltyPromotion {
+        collection: String,
+        protocol: Option<String>,
+    },
+    #[returns(Vec<RoyaltyPromotion>)]
+    AllRoyaltyDefaultPromotions {
+        query_options: Option<QueryOptions<String>>,
+    },
+    #[returns(Vec<RoyaltyPromotion>)]
+    AllRoyaltyProtocolPromotions {
+        query_options: Option<QueryOptions<(String, String)>>,
+    },
+    #[returns(Option<RegisteredProtocol>)]
+    RegisteredProtocol { protocol: String },
+    #[returns(Vec<RegisteredProtocol>)]
+    RegisteredProtocols {
+        query_options: Option<QueryOptions<String>>,
+    },
+    #[returns(Option<CollectionManager>)]
+    CollectionManager { collection: String, manager: String },
+    #[returns(Vec<CollectionManager>)]
+    CollectionManagers {
+        collection: String,
+        query_options: Option<QueryOptions<String>>,
     },
 }
 
@@ -68,9 +402,116 @@ pub enum QueryMsg {
 pub struct RoyaltyPaymentResponse {
     pub royalty_default: Option<RoyaltyDefault>,
     pub royalty_protocol: Option<RoyaltyProtocol>,
+    /// The maximum royalty share honored by the protocol, if the protocol is registered
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol_max_share: Option<Decimal>,
+    /// The locks placed by the collection admin on the collection's royalties
+    #[serde(default, skip_serializing_if = "RoyaltyLocks::is_empty")]
+    pub royalty_locks: RoyaltyLocks,
+    /// The promotions active at the current block time, which have already been applied to
+    /// `royalty_default` and `royalty_protocol`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub royalty_promotions: Vec<RoyaltyPromotion>,
+}
+
+/// Where an effective royalty entry was resolved from.
+#[cw_serde]
+pub enum RoyaltySource {
+    /// The protocol entry set on the registry
+    RegistryProtocol,
+    /// The royalty default set on the registry
+    RegistryDefault,
+    /// The royalty set on the collection contract, for collections not yet initialized
+    /// on the registry
+    CollectionFallback,
+}
+
+/// Collection royalty locks, keyed by collection.
+#[cw_serde]
+pub struct CollectionRoyaltyLocks {
+    pub collection: Addr,
+    pub royalty_locks: RoyaltyLocks,
+}
+
+#[cw_serde]
+pub struct ResolvedRoyaltyEntry {
+    pub royalty_entry: RoyaltyEntry,
+    pub source: RoyaltySource,
+}
+
+#[cw_serde]
+pub struct ConfigBoundsResponse {
+    pub min_update_wait_period: u64,
+    pub max_update_wait_period: u64,
+    pub min_max_share_delta: Decimal,
+    pub max_max_share_delta: Decimal,
+}
+
+#[cw_serde]
+pub struct RoyaltyDetailsResponse {
+    pub royalty_default: Option<RoyaltyDefault>,
+    pub default_metadata: Option<RoyaltyMetadata>,
+    pub royalty_protocol: Option<RoyaltyProtocol>,
+    pub protocol_metadata: Option<RoyaltyMetadata>,
+}
+
+#[cw_serde]
+pub struct CollectionCreatorResponse {
+    /// The collection admin at the time the collection royalties were registered, or last claimed
+    pub recorded_creator: Option<Addr>,
+    /// The current collection admin
+    pub current_creator: Option<Addr>,
+    /// Whether the collection admin has changed since the royalties were registered, or
+    /// `None` if either admin is unknown
+    pub changed: Option<bool>,
+}
+
+#[cw_serde]
+pub struct RoyaltySyncResponse {
+    pub royalty_default: Option<RoyaltyDefault>,
+    /// The royalty set on the collection contract, clamped to the registry share bounds
+    pub collection_royalty: Option<RoyaltyEntry>,
+    /// Whether the royalty default matches the royalty set on the collection contract
+    pub in_sync: bool,
 }
 
 #[cw_serde]
 pub enum SudoMsg {
-    UpdateConfig { config: Config },
+    UpdateConfig {
+        config: Config,
+    },
+    RegisterProtocol {
+        protocol: String,
+        name: String,
+        url: String,
+        max_share: Decimal,
+        active: bool,
+    },
+    RemoveProtocol {
+        protocol: String,
+    },
+    ForceSetCollectionRoyaltyDefault {
+        collection: String,
+        recipient: String,
+        share: Decimal,
+    },
+    ForceSetCollectionRoyaltyProtocol {
+        collection: String,
+        protocol: String,
+        recipient: String,
+        share: Decimal,
+    },
+    RemoveCollectionRoyaltyDefault {
+        collection: String,
+    },
+    RemoveCollectionRoyaltyProtocol {
+        collection: String,
+        protocol: String,
+    },
+    FreezeCollectionRoyalty {
+        collection: String,
+    },
+    UnfreezeCollectionRoyalty {
+        collection: String,
+    },
 }
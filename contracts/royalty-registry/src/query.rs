@@ -1,12 +1,31 @@
 use crate::{
-    msg::{QueryMsg, RoyaltyPaymentResponse},
+    adapter::{CheckRoyaltiesResponse, RoyaltiesInfoResponse},
+    constants::{
+        MAX_BATCH_SIZE, MAX_MAX_SHARE_DELTA, MAX_UPDATE_WAIT_PERIOD, MIN_MAX_SHARE_DELTA,
+        MIN_UPDATE_WAIT_PERIOD,
+    },
+    external::{query_collection_admin, query_collection_royalty_entry},
+    msg::{
+        CollectionCreatorResponse, CollectionRoyaltyLocks, ConfigBoundsResponse, QueryMsg,
+        RoyaltyDetailsResponse, RoyaltyPaymentResponse, RoyaltySyncResponse,
+    },
+    resolve::{royalty_payment, select_royalty_entry, RoyaltyPaymentState},
     state::{
-        Config, RoyaltyDefault, RoyaltyProtocol, RoyaltyProtocolKey, CONFIG, ROYALTY_DEFAULTS,
-        ROYALTY_PROTOCOLS,
+        royalty_defaults, royalty_protocols, CollectionManager, Config, RegisteredProtocol,
+        RoyaltyDefault, RoyaltyHistoryEntry, RoyaltyLocks, RoyaltyPeriod, RoyaltyPromotion,
+        RoyaltyProposal, RoyaltyProtocol, RoyaltyProtocolKey, COLLECTION_CREATORS,
+        COLLECTION_MANAGERS, COLLECTION_ROYALTY_PERIODS, COLLECTION_ROYALTY_TOTALS, CONFIG,
+        FROZEN_COLLECTIONS, PROTOCOL_ROYALTY_TOTALS, RECIPIENT_ROYALTY_TOTALS,
+        REGISTERED_PROTOCOLS, ROYALTY_BALANCES, ROYALTY_DEFAULT_METADATA,
+        ROYALTY_DEFAULT_PROMOTIONS, ROYALTY_DEFAULT_PROPOSALS, ROYALTY_HISTORY, ROYALTY_LOCKS,
+        ROYALTY_PROTOCOL_METADATA, ROYALTY_PROTOCOL_PROMOTIONS, ROYALTY_PROTOCOL_PROPOSALS,
     },
 };
 
-use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, Env, StdResult};
+use cosmwasm_std::{
+    coin, to_json_binary, Addr, Binary, Coin, Deps, Env, StdError, StdResult, Uint128,
+};
+use cw_storage_plus::Map;
 use cw_utils::maybe_addr;
 use sg_index_query::{QueryOptions, QueryOptionsInternal};
 
@@ -14,14 +33,23 @@ use sg_index_query::{QueryOptions, QueryOptionsInternal};
 use cosmwasm_std::entry_point;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     let api = deps.api;
 
     match msg {
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::ConfigBounds {} => to_json_binary(&query_config_bounds()),
         QueryMsg::CollectionRoyaltyDefault { collection } => to_json_binary(
             &query_collection_royalty_default(deps, api.addr_validate(&collection)?)?,
         ),
+        QueryMsg::CollectionRoyaltyDetails {
+            collection,
+            protocol,
+        } => to_json_binary(&query_collection_royalty_details(
+            deps,
+            api.addr_validate(&collection)?,
+            maybe_addr(api, protocol)?,
+        )?),
         QueryMsg::CollectionRoyaltyProtocol {
             collection,
             protocol,
@@ -41,10 +69,199 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::RoyaltyPayment {
             collection,
             protocol,
+            denom,
         } => to_json_binary(&query_royalty_payment(
             deps,
+            env,
+            api.addr_validate(&collection)?,
+            maybe_addr(api, protocol)?,
+            denom,
+        )?),
+        QueryMsg::RoyaltyInfo {
+            collection,
+            protocol,
+            token_id: _,
+            sale_price,
+        } => to_json_binary(&query_royalty_info(
+            deps,
+            env,
             api.addr_validate(&collection)?,
             maybe_addr(api, protocol)?,
+            sale_price,
+        )?),
+        QueryMsg::CheckRoyalties {
+            collection,
+            protocol,
+        } => to_json_binary(&query_check_royalties(
+            deps,
+            env,
+            api.addr_validate(&collection)?,
+            maybe_addr(api, protocol)?,
+        )?),
+        QueryMsg::CollectionCreator { collection } => to_json_binary(&query_collection_creator(
+            deps,
+            api.addr_validate(&collection)?,
+        )?),
+        QueryMsg::CollectionRoyaltySync { collection } => to_json_binary(
+            &query_collection_royalty_sync(deps, api.addr_validate(&collection)?)?,
+        ),
+        QueryMsg::RoyaltyPayments {
+            collections,
+            protocol,
+            denom,
+        } => to_json_binary(&query_royalty_payments(
+            deps,
+            env,
+            collections
+                .iter()
+                .map(|collection| api.addr_validate(collection))
+                .collect::<StdResult<_>>()?,
+            maybe_addr(api, protocol)?,
+            denom,
+        )?),
+        QueryMsg::AllRoyaltyDefaults { query_options } => to_json_binary(
+            &query_all_royalty_defaults(deps, query_options.unwrap_or_default())?,
+        ),
+        QueryMsg::AllRoyaltyProtocols { query_options } => to_json_binary(
+            &query_all_royalty_protocols(deps, query_options.unwrap_or_default())?,
+        ),
+        QueryMsg::RoyaltyProtocolsByProtocol {
+            protocol,
+            query_options,
+        } => to_json_binary(&query_royalty_protocols_by_protocol(
+            deps,
+            api.addr_validate(&protocol)?,
+            query_options.unwrap_or_default(),
+        )?),
+        QueryMsg::RoyaltyDefaultsByRecipient {
+            recipient,
+            query_options,
+        } => to_json_binary(&query_royalty_defaults_by_recipient(
+            deps,
+            api.addr_validate(&recipient)?,
+            query_options.unwrap_or_default(),
+        )?),
+        QueryMsg::RoyaltyProtocolsByRecipient {
+            recipient,
+            query_options,
+        } => to_json_binary(&query_royalty_protocols_by_recipient(
+            deps,
+            api.addr_validate(&recipient)?,
+            query_options.unwrap_or_default(),
+        )?),
+        QueryMsg::CollectionRoyaltyLocks { collection } => to_json_binary(
+            &query_collection_royalty_locks(deps, api.addr_validate(&collection)?)?,
+        ),
+        QueryMsg::AllRoyaltyLocks { query_options } => to_json_binary(&query_all_royalty_locks(
+            deps,
+            query_options.unwrap_or_default(),
+        )?),
+        QueryMsg::CollectionRoyaltyFrozen { collection } => to_json_binary(
+            &query_collection_royalty_frozen(deps, api.addr_validate(&collection)?)?,
+        ),
+        QueryMsg::RoyaltyHistory {
+            collection,
+            query_options,
+        } => to_json_binary(&query_royalty_history(
+            deps,
+            api.addr_validate(&collection)?,
+            query_options.unwrap_or_default(),
+        )?),
+        QueryMsg::CollectionRoyaltyDefaultProposal { collection } => to_json_binary(
+            &query_collection_royalty_default_proposal(deps, api.addr_validate(&collection)?)?,
+        ),
+        QueryMsg::CollectionRoyaltyProtocolProposal {
+            collection,
+            protocol,
+        } => to_json_binary(&query_collection_royalty_protocol_proposal(
+            deps,
+            api.addr_validate(&collection)?,
+            api.addr_validate(&protocol)?,
+        )?),
+        QueryMsg::RoyaltyBalance { recipient, denom } => to_json_binary(&query_royalty_balance(
+            deps,
+            api.addr_validate(&recipient)?,
+            denom,
+        )?),
+        QueryMsg::RoyaltyBalances {
+            recipient,
+            query_options,
+        } => to_json_binary(&query_royalty_balances(
+            deps,
+            api.addr_validate(&recipient)?,
+            query_options.unwrap_or_default(),
+        )?),
+        QueryMsg::CollectionRoyaltyTotals {
+            collection,
+            query_options,
+        } => to_json_binary(&query_royalty_totals(
+            deps,
+            COLLECTION_ROYALTY_TOTALS,
+            api.addr_validate(&collection)?,
+            query_options.unwrap_or_default(),
+        )?),
+        QueryMsg::RecipientRoyaltyTotals {
+            recipient,
+            query_options,
+        } => to_json_binary(&query_royalty_totals(
+            deps,
+            RECIPIENT_ROYALTY_TOTALS,
+            api.addr_validate(&recipient)?,
+            query_options.unwrap_or_default(),
+        )?),
+        QueryMsg::ProtocolRoyaltyTotals {
+            protocol,
+            query_options,
+        } => to_json_binary(&query_royalty_totals(
+            deps,
+            PROTOCOL_ROYALTY_TOTALS,
+            api.addr_validate(&protocol)?,
+            query_options.unwrap_or_default(),
+        )?),
+        QueryMsg::CollectionRoyaltyPeriods {
+            collection,
+            query_options,
+        } => to_json_binary(&query_collection_royalty_periods(
+            deps,
+            api.addr_validate(&collection)?,
+            query_options.unwrap_or_default(),
+        )?),
+        QueryMsg::CollectionRoyaltyPromotion {
+            collection,
+            protocol,
+        } => to_json_binary(&query_collection_royalty_promotion(
+            deps,
+            api.addr_validate(&collection)?,
+            maybe_addr(api, protocol)?,
+        )?),
+        QueryMsg::AllRoyaltyDefaultPromotions { query_options } => to_json_binary(
+            &query_all_royalty_default_promotions(deps, query_options.unwrap_or_default())?,
+        ),
+        QueryMsg::AllRoyaltyProtocolPromotions { query_options } => to_json_binary(
+            &query_all_royalty_protocol_promotions(deps, query_options.unwrap_or_default())?,
+        ),
+        QueryMsg::RegisteredProtocol { protocol } => to_json_binary(&query_registered_protocol(
+            deps,
+            api.addr_validate(&protocol)?,
+        )?),
+        QueryMsg::RegisteredProtocols { query_options } => to_json_binary(
+            &query_registered_protocols(deps, query_options.unwrap_or_default())?,
+        ),
+        QueryMsg::CollectionManager {
+            collection,
+            manager,
+        } => to_json_binary(&query_collection_manager(
+            deps,
+            api.addr_validate(&collection)?,
+            api.addr_validate(&manager)?,
+        )?),
+        QueryMsg::CollectionManagers {
+            collection,
+            query_options,
+        } => to_json_binary(&query_collection_managers(
+            deps,
+            api.addr_validate(&collection)?,
+            query_options.unwrap_or_default(),
         )?),
     }
 }
@@ -54,21 +271,57 @@ pub fn query_config(deps: Deps) -> StdResult<Config> {
     Ok(config)
 }
 
+pub fn query_config_bounds() -> ConfigBoundsResponse {
+    ConfigBoundsResponse {
+        min_update_wait_period: MIN_UPDATE_WAIT_PERIOD,
+        max_update_wait_period: MAX_UPDATE_WAIT_PERIOD,
+        min_max_share_delta: MIN_MAX_SHARE_DELTA,
+        max_max_share_delta: MAX_MAX_SHARE_DELTA,
+    }
+}
+
 pub fn query_collection_royalty_default(
     deps: Deps,
     collection: Addr,
 ) -> StdResult<Option<RoyaltyDefault>> {
-    let royalty_default = ROYALTY_DEFAULTS.may_load(deps.storage, collection)?;
+    let royalty_default = royalty_defaults().may_load(deps.storage, collection)?;
     Ok(royalty_default)
 }
 
+pub fn query_collection_royalty_details(
+    deps: Deps,
+    collection: Addr,
+    protocol: Option<Addr>,
+) -> StdResult<RoyaltyDetailsResponse> {
+    let royalty_default = royalty_defaults().may_load(deps.storage, collection.clone())?;
+    let default_metadata = ROYALTY_DEFAULT_METADATA.may_load(deps.storage, collection.clone())?;
+
+    let (royalty_protocol, protocol_metadata) = match protocol {
+        Some(protocol) => {
+            let royalty_protocol_key: RoyaltyProtocolKey = (collection, protocol);
+            (
+                royalty_protocols().may_load(deps.storage, royalty_protocol_key.clone())?,
+                ROYALTY_PROTOCOL_METADATA.may_load(deps.storage, royalty_protocol_key)?,
+            )
+        }
+        None => (None, None),
+    };
+
+    Ok(RoyaltyDetailsResponse {
+        royalty_default,
+        default_metadata,
+        royalty_protocol,
+        protocol_metadata,
+    })
+}
+
 pub fn query_collection_royalty_protocol(
     deps: Deps,
     collection: Addr,
     protocol: Addr,
 ) -> StdResult<Option<RoyaltyProtocol>> {
     let royalty_protocol_key: RoyaltyProtocolKey = (collection, protocol);
-    let royalty_protocol = ROYALTY_PROTOCOLS.may_load(deps.storage, royalty_protocol_key)?;
+    let royalty_protocol = royalty_protocols().may_load(deps.storage, royalty_protocol_key)?;
     Ok(royalty_protocol)
 }
 
@@ -88,7 +341,7 @@ pub fn query_royalty_protocol_by_collection(
         None,
     );
 
-    let royalty_protocols: Vec<RoyaltyProtocol> = ROYALTY_PROTOCOLS
+    let royalty_protocols: Vec<RoyaltyProtocol> = royalty_protocols()
         .prefix(collection)
         .range(deps.storage, min, max, order)
         .take(limit)
@@ -100,19 +353,584 @@ pub fn query_royalty_protocol_by_collection(
 
 pub fn query_royalty_payment(
     deps: Deps,
+    env: Env,
     collection: Addr,
     protocol: Option<Addr>,
+    denom: Option<String>,
 ) -> StdResult<RoyaltyPaymentResponse> {
-    let royalty_default = ROYALTY_DEFAULTS.may_load(deps.storage, collection.clone())?;
+    let royalty_default = royalty_defaults().may_load(deps.storage, collection.clone())?;
+    let royalty_locks = RoyaltyLocks::load(deps.storage, &collection)?;
+    let default_promotion =
+        ROYALTY_DEFAULT_PROMOTIONS.may_load(deps.storage, collection.clone())?;
 
     let mut royalty_protocol = None;
+    let mut protocol_max_share = None;
+    let mut protocol_promotion = None;
     if let Some(protocol_val) = &protocol {
-        let royalty_protocol_key: RoyaltyProtocolKey = (collection, protocol_val.clone());
-        royalty_protocol = ROYALTY_PROTOCOLS.may_load(deps.storage, royalty_protocol_key)?;
+        let royalty_protocol_key: RoyaltyProtocolKey = (collection.clone(), protocol_val.clone());
+        royalty_protocol =
+            royalty_protocols().may_load(deps.storage, royalty_protocol_key.clone())?;
+        protocol_max_share = REGISTERED_PROTOCOLS
+            .may_load(deps.storage, protocol_val.clone())?
+            .map(|registered_protocol| registered_protocol.max_share);
+        protocol_promotion =
+            ROYALTY_PROTOCOL_PROMOTIONS.may_load(deps.storage, royalty_protocol_key)?;
     }
 
-    Ok(RoyaltyPaymentResponse {
+    Ok(royalty_payment(
+        RoyaltyPaymentState {
+            collection,
+            protocol,
+            royalty_default,
+            royalty_protocol,
+            protocol_max_share,
+            royalty_locks,
+            default_promotion,
+            protocol_promotion,
+        },
+        env.block.time,
+        denom.as_deref(),
+    ))
+}
+
+pub fn query_royalty_balance(deps: Deps, recipient: Addr, denom: String) -> StdResult<Coin> {
+    let amount = ROYALTY_BALANCES
+        .may_load(deps.storage, (recipient, denom.clone()))?
+        .unwrap_or_default();
+    Ok(coin(amount.u128(), denom))
+}
+
+pub fn query_royalty_balances(
+    deps: Deps,
+    recipient: Addr,
+    query_options: QueryOptions<String>,
+) -> StdResult<Vec<Coin>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(&|denom: &String| denom.clone(), None, None);
+
+    let royalty_balances: Vec<Coin> = ROYALTY_BALANCES
+        .prefix(recipient)
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(denom, amount)| coin(amount.u128(), denom)))
+        .collect::<StdResult<_>>()?;
+
+    Ok(royalty_balances)
+}
+
+/// Queries the royalty totals per denom for a collection, recipient or protocol.
+pub fn query_royalty_totals(
+    deps: Deps,
+    royalty_totals: Map<(Addr, String), Uint128>,
+    address: Addr,
+    query_options: QueryOptions<String>,
+) -> StdResult<Vec<Coin>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(&|denom: &String| denom.clone(), None, None);
+
+    let totals: Vec<Coin> = royalty_totals
+        .prefix(address)
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(denom, amount)| coin(amount.u128(), denom)))
+        .collect::<StdResult<_>>()?;
+
+    Ok(totals)
+}
+
+pub fn query_collection_royalty_periods(
+    deps: Deps,
+    collection: Addr,
+    query_options: QueryOptions<u64>,
+) -> StdResult<Vec<RoyaltyPeriod>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(&|period_start: &u64| *period_start, None, None);
+
+    let royalty_periods: Vec<RoyaltyPeriod> = COLLECTION_ROYALTY_PERIODS
+        .prefix(collection)
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(_, v)| v))
+        .collect::<StdResult<_>>()?;
+
+    Ok(royalty_periods)
+}
+
+pub fn query_collection_royalty_promotion(
+    deps: Deps,
+    collection: Addr,
+    protocol: Option<Addr>,
+) -> StdResult<Option<RoyaltyPromotion>> {
+    let royalty_promotion = match protocol {
+        Some(protocol) => {
+            ROYALTY_PROTOCOL_PROMOTIONS.may_load(deps.storage, (collection, protocol))?
+        }
+        None => ROYALTY_DEFAULT_PROMOTIONS.may_load(deps.storage, collection)?,
+    };
+    Ok(royalty_promotion)
+}
+
+pub fn query_all_royalty_default_promotions(
+    deps: Deps,
+    query_options: QueryOptions<String>,
+) -> StdResult<Vec<RoyaltyPromotion>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(
+        &Box::new(|sa: &String| Addr::unchecked(sa.clone())),
+        None,
+        None,
+    );
+
+    let royalty_promotions: Vec<RoyaltyPromotion> = ROYALTY_DEFAULT_PROMOTIONS
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(_, v)| v))
+        .collect::<StdResult<_>>()?;
+
+    Ok(royalty_promotions)
+}
+
+pub fn query_all_royalty_protocol_promotions(
+    deps: Deps,
+    query_options: QueryOptions<(String, String)>,
+) -> StdResult<Vec<RoyaltyPromotion>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(
+        &Box::new(|(collection, protocol): &(String, String)| {
+            (
+                Addr::unchecked(collection.clone()),
+                Addr::unchecked(protocol.clone()),
+            )
+        }),
+        None,
+        None,
+    );
+
+    let royalty_promotions: Vec<RoyaltyPromotion> = ROYALTY_PROTOCOL_PROMOTIONS
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(_, v)| v))
+        .collect::<StdResult<_>>()?;
+
+    Ok(royalty_promotions)
+}
+
+pub fn query_royalty_payments(
+    deps: Deps,
+    env: Env,
+    collections: Vec<Addr>,
+    protocol: Option<Addr>,
+    denom: Option<String>,
+) -> StdResult<Vec<RoyaltyPaymentResponse>> {
+    if collections.len() > MAX_BATCH_SIZE {
+        return Err(StdError::generic_err(format!(
+            "Cannot query more than {} collections",
+            MAX_BATCH_SIZE
+        )));
+    }
+
+    collections
+        .into_iter()
+        .map(|collection| {
+            query_royalty_payment(
+                deps,
+                env.clone(),
+                collection,
+                protocol.clone(),
+                denom.clone(),
+            )
+        })
+        .collect()
+}
+
+pub fn query_royalty_info(
+    deps: Deps,
+    env: Env,
+    collection: Addr,
+    protocol: Option<Addr>,
+    sale_price: Uint128,
+) -> StdResult<RoyaltiesInfoResponse> {
+    let royalty_payment_response = query_royalty_payment(deps, env, collection, protocol, None)?;
+
+    let royalties_info = match select_royalty_entry(royalty_payment_response) {
+        Some(resolved_royalty_entry) => RoyaltiesInfoResponse {
+            address: resolved_royalty_entry.royalty_entry.recipient.to_string(),
+            royalty_amount: sale_price.mul_floor(resolved_royalty_entry.royalty_entry.share),
+        },
+        None => RoyaltiesInfoResponse {
+            address: String::new(),
+            royalty_amount: Uint128::zero(),
+        },
+    };
+    Ok(royalties_info)
+}
+
+pub fn query_check_royalties(
+    deps: Deps,
+    env: Env,
+    collection: Addr,
+    protocol: Option<Addr>,
+) -> StdResult<CheckRoyaltiesResponse> {
+    let royalty_payment_response = query_royalty_payment(deps, env, collection, protocol, None)?;
+
+    Ok(CheckRoyaltiesResponse {
+        royalty_payments: select_royalty_entry(royalty_payment_response).is_some(),
+    })
+}
+
+pub fn query_collection_creator(
+    deps: Deps,
+    collection: Addr,
+) -> StdResult<CollectionCreatorResponse> {
+    let recorded_creator = COLLECTION_CREATORS.may_load(deps.storage, collection.clone())?;
+    let current_creator = query_collection_admin(&deps.querier, &collection).ok();
+
+    let changed = match (&recorded_creator, &current_creator) {
+        (Some(recorded_creator), Some(current_creator)) => {
+            Some(recorded_creator != current_creator)
+        }
+        _ => None,
+    };
+
+    Ok(CollectionCreatorResponse {
+        recorded_creator,
+        current_creator,
+        changed,
+    })
+}
+
+pub fn query_collection_royalty_sync(
+    deps: Deps,
+    collection: Addr,
+) -> StdResult<RoyaltySyncResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let royalty_default = royalty_defaults().may_load(deps.storage, collection.clone())?;
+    let collection_royalty = query_collection_royalty_entry(&deps.querier, deps.api, &collection)
+        .map_err(|err| StdError::generic_err(err.to_string()))?
+        .map(|mut royalty_entry| {
+            royalty_entry.share = config.clamp_share(royalty_entry.share);
+            royalty_entry
+        });
+
+    let in_sync = match (&royalty_default, &collection_royalty) {
+        (Some(royalty_default), Some(collection_royalty)) => {
+            royalty_default.royalty_entry.recipient == collection_royalty.recipient
+                && royalty_default.royalty_entry.share == collection_royalty.share
+        }
+        (None, None) => true,
+        _ => false,
+    };
+
+    Ok(RoyaltySyncResponse {
         royalty_default,
-        royalty_protocol,
+        collection_royalty,
+        in_sync,
     })
 }
+
+pub fn query_all_royalty_defaults(
+    deps: Deps,
+    query_options: QueryOptions<String>,
+) -> StdResult<Vec<RoyaltyDefault>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(
+        &Box::new(|sa: &String| Addr::unchecked(sa.clone())),
+        None,
+        None,
+    );
+
+    let royalty_defaults: Vec<RoyaltyDefault> = royalty_defaults()
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(_, v)| v))
+        .collect::<StdResult<_>>()?;
+
+    Ok(royalty_defaults)
+}
+
+pub fn query_all_royalty_protocols(
+    deps: Deps,
+    query_options: QueryOptions<(String, String)>,
+) -> StdResult<Vec<RoyaltyProtocol>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(
+        &Box::new(|(collection, protocol): &(String, String)| {
+            (
+                Addr::unchecked(collection.clone()),
+                Addr::unchecked(protocol.clone()),
+            )
+        }),
+        None,
+        None,
+    );
+
+    let royalty_protocols: Vec<RoyaltyProtocol> = royalty_protocols()
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(_, v)| v))
+        .collect::<StdResult<_>>()?;
+
+    Ok(royalty_protocols)
+}
+
+pub fn query_royalty_protocols_by_protocol(
+    deps: Deps,
+    protocol: Addr,
+    query_options: QueryOptions<String>,
+) -> StdResult<Vec<RoyaltyProtocol>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(
+        &Box::new(|collection: &String| (Addr::unchecked(collection.clone()), protocol.clone())),
+        None,
+        None,
+    );
+
+    let royalty_protocols: Vec<RoyaltyProtocol> = royalty_protocols()
+        .idx
+        .protocol
+        .prefix(protocol.clone())
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(_, v)| v))
+        .collect::<StdResult<_>>()?;
+
+    Ok(royalty_protocols)
+}
+
+pub fn query_royalty_defaults_by_recipient(
+    deps: Deps,
+    recipient: Addr,
+    query_options: QueryOptions<String>,
+) -> StdResult<Vec<RoyaltyDefault>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(
+        &Box::new(|sa: &String| Addr::unchecked(sa.clone())),
+        None,
+        None,
+    );
+
+    let royalty_defaults: Vec<RoyaltyDefault> = royalty_defaults()
+        .idx
+        .recipient
+        .prefix(recipient)
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(_, v)| v))
+        .collect::<StdResult<_>>()?;
+
+    Ok(royalty_defaults)
+}
+
+pub fn query_royalty_protocols_by_recipient(
+    deps: Deps,
+    recipient: Addr,
+    query_options: QueryOptions<(String, String)>,
+) -> StdResult<Vec<RoyaltyProtocol>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(
+        &Box::new(|(collection, protocol): &(String, String)| {
+            (
+                Addr::unchecked(collection.clone()),
+                Addr::unchecked(protocol.clone()),
+            )
+        }),
+        None,
+        None,
+    );
+
+    let royalty_protocols: Vec<RoyaltyProtocol> = royalty_protocols()
+        .idx
+        .recipient
+        .prefix(recipient)
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(_, v)| v))
+        .collect::<StdResult<_>>()?;
+
+    Ok(royalty_protocols)
+}
+
+pub fn query_collection_royalty_locks(deps: Deps, collection: Addr) -> StdResult<RoyaltyLocks> {
+    RoyaltyLocks::load(deps.storage, &collection)
+}
+
+pub fn query_all_royalty_locks(
+    deps: Deps,
+    query_options: QueryOptions<String>,
+) -> StdResult<Vec<CollectionRoyaltyLocks>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(
+        &Box::new(|sa: &String| Addr::unchecked(sa.clone())),
+        None,
+        None,
+    );
+
+    let royalty_locks: Vec<CollectionRoyaltyLocks> = ROYALTY_LOCKS
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| {
+            item.map(|(collection, royalty_locks)| CollectionRoyaltyLocks {
+                collection,
+                royalty_locks,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    Ok(royalty_locks)
+}
+
+pub fn query_collection_royalty_frozen(deps: Deps, collection: Addr) -> StdResult<bool> {
+    Ok(FROZEN_COLLECTIONS.has(deps.storage, collection))
+}
+
+pub fn query_royalty_history(
+    deps: Deps,
+    collection: Addr,
+    query_options: QueryOptions<u64>,
+) -> StdResult<Vec<RoyaltyHistoryEntry>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(&|id: &u64| *id, None, None);
+
+    let royalty_history: Vec<RoyaltyHistoryEntry> = ROYALTY_HISTORY
+        .prefix(collection)
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(_, v)| v))
+        .collect::<StdResult<_>>()?;
+
+    Ok(royalty_history)
+}
+
+pub fn query_collection_royalty_default_proposal(
+    deps: Deps,
+    collection: Addr,
+) -> StdResult<Option<RoyaltyProposal>> {
+    let royalty_proposal = ROYALTY_DEFAULT_PROPOSALS.may_load(deps.storage, collection)?;
+    Ok(royalty_proposal)
+}
+
+pub fn query_collection_royalty_protocol_proposal(
+    deps: Deps,
+    collection: Addr,
+    protocol: Addr,
+) -> StdResult<Option<RoyaltyProposal>> {
+    let royalty_protocol_key: RoyaltyProtocolKey = (collection, protocol);
+    let royalty_proposal =
+        ROYALTY_PROTOCOL_PROPOSALS.may_load(deps.storage, royalty_protocol_key)?;
+    Ok(royalty_proposal)
+}
+
+pub fn query_registered_protocol(
+    deps: Deps,
+    protocol: Addr,
+) -> StdResult<Option<RegisteredProtocol>> {
+    let registered_protocol = REGISTERED_PROTOCOLS.may_load(deps.storage, protocol)?;
+    Ok(registered_protocol)
+}
+
+pub fn query_registered_protocols(
+    deps: Deps,
+    query_options: QueryOptions<String>,
+) -> StdResult<Vec<RegisteredProtocol>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(
+        &Box::new(|sa: &String| Addr::unchecked(sa.clone())),
+        None,
+        None,
+    );
+
+    let registered_protocols: Vec<RegisteredProtocol> = REGISTERED_PROTOCOLS
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(_, v)| v))
+        .collect::<StdResult<_>>()?;
+
+    Ok(registered_protocols)
+}
+
+pub fn query_collection_manager(
+    deps: Deps,
+    collection: Addr,
+    manager: Addr,
+) -> StdResult<Option<CollectionManager>> {
+    let collection_manager = COLLECTION_MANAGERS.may_load(deps.storage, (collection, manager))?;
+    Ok(collection_manager)
+}
+
+pub fn query_collection_managers(
+    deps: Deps,
+    collection: Addr,
+    query_options: QueryOptions<String>,
+) -> StdResult<Vec<CollectionManager>> {
+    let QueryOptionsInternal {
+        limit,
+        order,
+        min,
+        max,
+    } = query_options.unpack(
+        &Box::new(|sa: &String| Addr::unchecked(sa.clone())),
+        None,
+        None,
+    );
+
+    let collection_managers: Vec<CollectionManager> = COLLECTION_MANAGERS
+        .prefix(collection)
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(_, v)| v))
+        .collect::<StdResult<_>>()?;
+
+    Ok(collection_managers)
+}
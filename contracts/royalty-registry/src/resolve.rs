@@ -0,0 +1,282 @@
+//! The boundary between the contract and the royalty resolution rules of
+//! [stargaze_royalty_resolver], which work on plain state.
+//!
+//! The contract loads the state relevant to a sale and resolves it with these functions,
+//! and off-chain services can do the same from a [RoyaltySnapshot] of the registry
+//! to reproduce `RoyaltyPayment` queries and `fetch_royalty_entry` offline.
+
+use crate::{
+    msg::{CollectionRoyaltyLocks, ResolvedRoyaltyEntry, RoyaltyPaymentResponse, RoyaltySource},
+    state::{
+        DenomShare, RegisteredProtocol, RoyaltyDefault, RoyaltyEntry, RoyaltyLocks,
+        RoyaltyPromotion, RoyaltyProtocol,
+    },
+};
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use stargaze_royalty_resolver as resolver;
+
+/// The registry state that determines the royalty of a sale of a collection,
+/// optionally on a protocol.
+#[cw_serde]
+pub struct RoyaltyPaymentState {
+    pub collection: Addr,
+    pub protocol: Option<Addr>,
+    pub royalty_default: Option<RoyaltyDefault>,
+    /// The protocol entry, if a protocol is given
+    pub royalty_protocol: Option<RoyaltyProtocol>,
+    /// The maximum share honored by the protocol, if it is registered
+    pub protocol_max_share: Option<Decimal>,
+    pub royalty_locks: RoyaltyLocks,
+    pub default_promotion: Option<RoyaltyPromotion>,
+    /// The promotion for the protocol, if a protocol is given
+    pub protocol_promotion: Option<RoyaltyPromotion>,
+}
+
+/// Resolves the royalty entries for a sale at `block_time`, applying the share for the
+/// sale `denom` if given, and any active promotions.
+pub fn royalty_payment(
+    state: RoyaltyPaymentState,
+    block_time: Timestamp,
+    denom: Option<&str>,
+) -> RoyaltyPaymentResponse {
+    let RoyaltyPaymentState {
+        collection,
+        protocol,
+        mut royalty_default,
+        mut royalty_protocol,
+        protocol_max_share,
+        royalty_locks,
+        default_promotion,
+        protocol_promotion,
+    } = state;
+
+    let royalty_payment = resolver::royalty_payment(
+        resolver::RoyaltyPaymentState {
+            royalty_default: royalty_default
+                .as_ref()
+                .map(|royalty_default| to_resolver_entry(&royalty_default.royalty_entry)),
+            on_protocol: protocol.is_some(),
+            royalty_protocol: royalty_protocol
+                .as_ref()
+                .map(|royalty_protocol| to_resolver_entry(&royalty_protocol.royalty_entry)),
+            default_promotion: default_promotion.as_ref().map(to_resolver_promotion),
+            protocol_promotion: protocol_promotion.as_ref().map(to_resolver_promotion),
+        },
+        block_time.nanos(),
+        denom,
+    );
+
+    // A protocol entry derived from the royalty default keeps the default's entry
+    if royalty_payment.protocol_from_default {
+        royalty_protocol =
+            royalty_default
+                .as_ref()
+                .zip(protocol)
+                .map(|(royalty_default, protocol)| RoyaltyProtocol {
+                    collection,
+                    protocol,
+                    royalty_entry: royalty_default.royalty_entry.clone(),
+                });
+    }
+
+    if let (Some(royalty_default), Some(resolved)) =
+        (royalty_default.as_mut(), &royalty_payment.royalty_default)
+    {
+        royalty_default.royalty_entry.share = from_resolver_share(resolved.share);
+    }
+    if let (Some(royalty_protocol), Some(resolved)) =
+        (royalty_protocol.as_mut(), &royalty_payment.royalty_protocol)
+    {
+        royalty_protocol.royalty_entry.share = from_resolver_share(resolved.share);
+    }
+
+    let royalty_promotions = default_promotion
+        .filter(|_| royalty_payment.default_promotion_applied)
+        .into_iter()
+        .chain(protocol_promotion.filter(|_| royalty_payment.protocol_promotion_applied))
+        .collect();
+
+    RoyaltyPaymentResponse {
+        royalty_default,
+        royalty_protocol,
+        protocol_max_share,
+        royalty_locks,
+        royalty_promotions,
+    }
+}
+
+/// Selects the protocol entry over the default entry, capped at the protocol max share.
+pub fn select_royalty_entry(
+    royalty_payment_response: RoyaltyPaymentResponse,
+) -> Option<ResolvedRoyaltyEntry> {
+    let RoyaltyPaymentResponse {
+        royalty_default,
+        royalty_protocol,
+        protocol_max_share,
+        ..
+    } = royalty_payment_response;
+
+    let royalty_default = royalty_default.map(|royalty_default| royalty_default.royalty_entry);
+    let royalty_protocol = royalty_protocol.map(|royalty_protocol| royalty_protocol.royalty_entry);
+
+    let (resolved, source) = resolver::select_royalty_entry(
+        royalty_default.as_ref().map(to_resolver_entry),
+        royalty_protocol.as_ref().map(to_resolver_entry),
+        protocol_max_share.map(to_resolver_share),
+    )?;
+    let royalty_entry = match source {
+        resolver::RoyaltySource::RegistryProtocol => royalty_protocol,
+        _ => royalty_default,
+    }?;
+
+    Some(resolved_royalty_entry(royalty_entry, resolved, source))
+}
+
+/// Resolves the royalty set on the collection contract, for collections not yet
+/// initialized on the registry.
+pub fn collection_fallback(
+    royalty_entry: RoyaltyEntry,
+    protocol_max_share: Option<Decimal>,
+) -> ResolvedRoyaltyEntry {
+    let (resolved, source) = resolver::collection_fallback(
+        to_resolver_entry(&royalty_entry),
+        protocol_max_share.map(to_resolver_share),
+    );
+    resolved_royalty_entry(royalty_entry, resolved, source)
+}
+
+fn resolved_royalty_entry(
+    mut royalty_entry: RoyaltyEntry,
+    resolved: resolver::RoyaltyEntry,
+    source: resolver::RoyaltySource,
+) -> ResolvedRoyaltyEntry {
+    royalty_entry.share = from_resolver_share(resolved.share);
+    ResolvedRoyaltyEntry {
+        royalty_entry,
+        source: match source {
+            resolver::RoyaltySource::RegistryProtocol => RoyaltySource::RegistryProtocol,
+            resolver::RoyaltySource::RegistryDefault => RoyaltySource::RegistryDefault,
+            resolver::RoyaltySource::CollectionFallback => RoyaltySource::CollectionFallback,
+        },
+    }
+}
+
+fn to_resolver_share(share: Decimal) -> u128 {
+    share.atomics().u128()
+}
+
+fn from_resolver_share(share: u128) -> Decimal {
+    Decimal::new(Uint128::new(share))
+}
+
+fn to_resolver_entry(royalty_entry: &RoyaltyEntry) -> resolver::RoyaltyEntry {
+    resolver::RoyaltyEntry {
+        recipient: royalty_entry.recipient.to_string(),
+        share: to_resolver_share(royalty_entry.share),
+        denom_shares: royalty_entry
+            .denom_shares
+            .iter()
+            .map(|DenomShare { denom, share }| resolver::DenomShare {
+                denom: denom.clone(),
+                share: to_resolver_share(*share),
+            })
+            .collect(),
+    }
+}
+
+fn to_resolver_promotion(royalty_promotion: &RoyaltyPromotion) -> resolver::RoyaltyPromotion {
+    resolver::RoyaltyPromotion {
+        share: to_resolver_share(royalty_promotion.share),
+        start: royalty_promotion.start.nanos(),
+        end: royalty_promotion.end.nanos(),
+    }
+}
+
+/// A snapshot of the registry state that affects royalty resolution, as returned by the
+/// registry's `AllRoyaltyDefaults`, `AllRoyaltyProtocols`, `RegisteredProtocols`,
+/// `AllRoyaltyDefaultPromotions`, `AllRoyaltyProtocolPromotions` and `AllRoyaltyLocks`
+/// queries, with promotions of both kinds in `royalty_promotions`.
+#[cw_serde]
+#[derive(Default)]
+pub struct RoyaltySnapshot {
+    #[serde(default)]
+    pub royalty_defaults: Vec<RoyaltyDefault>,
+    #[serde(default)]
+    pub royalty_protocols: Vec<RoyaltyProtocol>,
+    #[serde(default)]
+    pub registered_protocols: Vec<RegisteredProtocol>,
+    #[serde(default)]
+    pub royalty_promotions: Vec<RoyaltyPromotion>,
+    #[serde(default)]
+    pub royalty_locks: Vec<CollectionRoyaltyLocks>,
+    /// The block time at which the snapshot was taken
+    #[serde(default)]
+    pub block_time: Option<Timestamp>,
+}
+
+impl RoyaltySnapshot {
+    /// Extracts the state that determines the royalty of a sale of the collection,
+    /// optionally on a protocol.
+    pub fn payment_state(&self, collection: &Addr, protocol: Option<&Addr>) -> RoyaltyPaymentState {
+        let find_promotion = |protocol: Option<&Addr>| {
+            self.royalty_promotions
+                .iter()
+                .find(|royalty_promotion| {
+                    royalty_promotion.collection == *collection
+                        && royalty_promotion.protocol.as_ref() == protocol
+                })
+                .cloned()
+        };
+
+        RoyaltyPaymentState {
+            collection: collection.clone(),
+            protocol: protocol.cloned(),
+            royalty_default: self
+                .royalty_defaults
+                .iter()
+                .find(|royalty_default| royalty_default.collection == *collection)
+                .cloned(),
+            royalty_protocol: protocol.and_then(|protocol| {
+                self.royalty_protocols
+                    .iter()
+                    .find(|royalty_protocol| {
+                        royalty_protocol.collection == *collection
+                            && royalty_protocol.protocol == *protocol
+                    })
+                    .cloned()
+            }),
+            protocol_max_share: protocol.and_then(|protocol| {
+                self.registered_protocols
+                    .iter()
+                    .find(|registered_protocol| registered_protocol.protocol == *protocol)
+                    .map(|registered_protocol| registered_protocol.max_share)
+            }),
+            royalty_locks: self
+                .royalty_locks
+                .iter()
+                .find(|royalty_locks| royalty_locks.collection == *collection)
+                .map(|royalty_locks| royalty_locks.royalty_locks.clone())
+                .unwrap_or_default(),
+            default_promotion: find_promotion(None),
+            protocol_promotion: protocol.and_then(|protocol| find_promotion(Some(protocol))),
+        }
+    }
+
+    /// Resolves the effective royalty entry for a sale of the collection at `block_time`,
+    /// optionally on a protocol and in a denom.
+    pub fn resolve(
+        &self,
+        collection: &Addr,
+        protocol: Option<&Addr>,
+        denom: Option<&str>,
+        block_time: Timestamp,
+    ) -> Option<ResolvedRoyaltyEntry> {
+        select_royalty_entry(royalty_payment(
+            self.payment_state(collection, protocol),
+            block_time,
+            denom,
+        ))
+    }
+}
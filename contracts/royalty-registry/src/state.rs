@@ -1,9 +1,17 @@
-use crate::ContractError;
+use crate::{
+    constants::{
+        MAX_DENOM_SHARES, MAX_MAX_SHARE_DELTA, MAX_METADATA_LABEL_LENGTH, MAX_METADATA_URL_LENGTH,
+        MAX_UPDATE_WAIT_PERIOD, MIN_MAX_SHARE_DELTA, MIN_UPDATE_WAIT_PERIOD, ROYALTY_STATS_PERIOD,
+    },
+    ContractError,
+};
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{ensure, Addr, Decimal, Storage, Timestamp};
-use cw_storage_plus::{Item, Map};
-use std::cmp::min;
+use cosmwasm_std::{coin, ensure, Addr, Coin, Decimal, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use cw_utils::Expiration;
+use std::cmp::{max, min};
+use std::fmt;
 
 #[cw_serde]
 pub struct Config {
@@ -11,9 +19,14 @@ pub struct Config {
     pub update_wait_period: u64,
     /// The maximum that can be added or removed from a royalty entry in a single update.
     pub max_share_delta: Decimal,
+    /// The minimum royalty share that a collection admin can set.
+    pub min_share: Decimal,
+    /// The maximum royalty share that a collection admin can set.
+    pub max_share: Decimal,
 }
 
 impl Config {
+    /// Saves the config, rejecting values outside of the config bounds.
     pub fn save(&self, storage: &mut dyn Storage) -> Result<(), ContractError> {
         self.validate()?;
         CONFIG.save(storage, self)?;
@@ -22,15 +35,130 @@ impl Config {
 
     fn validate(&self) -> Result<(), ContractError> {
         ensure!(
-            self.max_share_delta < Decimal::one(),
-            ContractError::InvalidConfig("max_share_delta must be greater than 0".to_string())
+            (MIN_UPDATE_WAIT_PERIOD..=MAX_UPDATE_WAIT_PERIOD).contains(&self.update_wait_period),
+            ContractError::UpdateWaitPeriodOutOfRange {
+                update_wait_period: self.update_wait_period,
+                min: MIN_UPDATE_WAIT_PERIOD,
+                max: MAX_UPDATE_WAIT_PERIOD,
+            }
+        );
+        ensure!(
+            MIN_MAX_SHARE_DELTA <= self.max_share_delta
+                && self.max_share_delta <= MAX_MAX_SHARE_DELTA,
+            ContractError::MaxShareDeltaOutOfRange {
+                max_share_delta: self.max_share_delta,
+                min: MIN_MAX_SHARE_DELTA,
+                max: MAX_MAX_SHARE_DELTA,
+            }
+        );
+        ensure!(
+            self.min_share <= self.max_share && self.max_share <= Decimal::one(),
+            ContractError::InvalidShareBounds {
+                min_share: self.min_share,
+                max_share: self.max_share,
+            }
+        );
+        Ok(())
+    }
+
+    /// Ensures that the share is within the `min_share` and `max_share` bounds.
+    pub fn ensure_share_in_bounds(&self, share: Decimal) -> Result<(), ContractError> {
+        ensure!(
+            self.min_share <= share && share <= self.max_share,
+            ContractError::ShareOutOfRange {
+                share,
+                min_share: self.min_share,
+                max_share: self.max_share,
+            }
         );
         Ok(())
     }
+
+    /// Clamps the share to the `min_share` and `max_share` bounds.
+    pub fn clamp_share(&self, share: Decimal) -> Decimal {
+        share.clamp(self.min_share, self.max_share)
+    }
+
+    /// Returns the config with a collection's update params applied. The stricter of
+    /// the global and collection values is used, so that the result is never looser
+    /// than the global config, even after governance tightens it.
+    pub fn effective(&self, update_params: Option<&UpdateParams>) -> Config {
+        let mut config = self.clone();
+        if let Some(update_params) = update_params {
+            if let Some(update_wait_period) = update_params.update_wait_period {
+                config.update_wait_period = max(config.update_wait_period, update_wait_period);
+            }
+            if let Some(max_share_delta) = update_params.max_share_delta {
+                config.max_share_delta = min(config.max_share_delta, max_share_delta);
+            }
+        }
+        config
+    }
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
+/// Loads the config that applies to updates of the collection's royalty entries.
+pub fn load_effective_config(
+    storage: &dyn Storage,
+    collection: &Addr,
+) -> Result<Config, ContractError> {
+    let update_params = royalty_defaults()
+        .may_load(storage, collection.clone())?
+        .and_then(|royalty_default| royalty_default.update_params);
+    Ok(CONFIG.load(storage)?.effective(update_params.as_ref()))
+}
+
+/// Stricter update rules that a collection has opted into, overriding the global config.
+#[cw_serde]
+#[derive(Default)]
+pub struct UpdateParams {
+    /// The number of seconds to wait before updating a royalty entry of the collection
+    pub update_wait_period: Option<u64>,
+    /// The maximum that can be added or removed from a royalty entry of the collection
+    /// in a single update
+    pub max_share_delta: Option<Decimal>,
+}
+
+impl UpdateParams {
+    /// Ensures that the values set in the params are at least as strict as both the
+    /// global config and the `previous` params of the collection.
+    pub fn validate(&self, config: &Config, previous: &UpdateParams) -> Result<(), ContractError> {
+        let effective = config.effective(Some(previous));
+        if let Some(update_wait_period) = self.update_wait_period {
+            ensure!(
+                (effective.update_wait_period..=MAX_UPDATE_WAIT_PERIOD)
+                    .contains(&update_wait_period),
+                ContractError::UpdateWaitPeriodOutOfRange {
+                    update_wait_period,
+                    min: effective.update_wait_period,
+                    max: MAX_UPDATE_WAIT_PERIOD,
+                }
+            );
+        }
+        if let Some(max_share_delta) = self.max_share_delta {
+            ensure!(
+                MIN_MAX_SHARE_DELTA <= max_share_delta
+                    && max_share_delta <= effective.max_share_delta,
+                ContractError::MaxShareDeltaOutOfRange {
+                    max_share_delta,
+                    min: MIN_MAX_SHARE_DELTA,
+                    max: effective.max_share_delta,
+                }
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the params with the values set in `update` replacing the current ones.
+    pub fn merge(&self, update: &UpdateParams) -> UpdateParams {
+        UpdateParams {
+            update_wait_period: update.update_wait_period.or(self.update_wait_period),
+            max_share_delta: update.max_share_delta.or(self.max_share_delta),
+        }
+    }
+}
+
 #[cw_serde]
 pub struct RoyaltyEntry {
     /// The address that will receive the royalty payments
@@ -39,45 +167,241 @@ pub struct RoyaltyEntry {
     pub share: Decimal,
     /// The last time the royalty entry was updated
     pub updated: Option<Timestamp>,
+    /// Shares that override `share` for sales settled in a specific denom
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub denom_shares: Vec<DenomShare>,
+}
+
+#[cw_serde]
+pub struct DenomShare {
+    pub denom: String,
+    pub share: Decimal,
 }
 
 impl RoyaltyEntry {
     pub fn validate(&self) -> Result<(), ContractError> {
         ensure!(
-            self.share <= Decimal::one(),
-            ContractError::InvalidCollectionRoyalty(
-                "Royalty share must be less than or equal to 1".to_string()
-            )
+            self.max_share() <= Decimal::one(),
+            ContractError::ShareOutOfRange {
+                share: self.max_share(),
+                min_share: Decimal::zero(),
+                max_share: Decimal::one(),
+            }
+        );
+        ensure!(
+            self.denom_shares.len() <= MAX_DENOM_SHARES,
+            ContractError::TooManyDenomShares {
+                max_denom_shares: MAX_DENOM_SHARES,
+            }
         );
         Ok(())
     }
 
-    pub fn update_share(
+    /// Returns the share for sales settled in the given denom.
+    pub fn share_for_denom(&self, denom: &str) -> Decimal {
+        self.denom_shares
+            .iter()
+            .find(|denom_share| denom_share.denom == denom)
+            .map_or(self.share, |denom_share| denom_share.share)
+    }
+
+    /// Returns the highest share across `share` and the denom shares.
+    pub fn max_share(&self) -> Decimal {
+        self.denom_shares
+            .iter()
+            .fold(self.share, |max_share, denom_share| {
+                max(max_share, denom_share.share)
+            })
+    }
+
+    /// Sets or, if `share` is `None`, removes the share override for a denom, enforcing the
+    /// `max_share_delta`, `min_share` and `max_share` rules of the config against the share
+    /// currently paid for the denom.
+    pub fn set_denom_share(
         &mut self,
         config: &Config,
-        share_delta: Decimal,
-        decrement: Option<bool>,
+        denom: &str,
+        share: Option<Decimal>,
+    ) -> Result<(), ContractError> {
+        let previous_share = self.share_for_denom(denom);
+        let current_share = share.unwrap_or(self.share);
+        ensure_share_delta(
+            config,
+            max(previous_share, current_share) - min(previous_share, current_share),
+        )?;
+        config.ensure_share_in_bounds(current_share)?;
+
+        self.denom_shares
+            .retain(|denom_share| denom_share.denom != denom);
+        if let Some(share) = share {
+            self.denom_shares.push(DenomShare {
+                denom: denom.to_string(),
+                share,
+            });
+        }
+        Ok(())
+    }
+
+    /// Ensures that `update_wait_period` has elapsed since the last update.
+    pub fn ensure_updatable(
+        &self,
+        config: &Config,
+        block_time: Timestamp,
     ) -> Result<(), ContractError> {
-        let delta = min(share_delta, config.max_share_delta);
-        let decrement = decrement.is_some() && decrement.unwrap();
+        if let Some(updated) = self.updated {
+            let until = updated.plus_seconds(config.update_wait_period);
+            ensure!(until <= block_time, ContractError::CooldownActive { until });
+        }
+        Ok(())
+    }
 
-        if decrement {
-            self.share -= delta;
-        } else {
-            self.share += delta;
+    /// Applies a share update, enforcing the `max_share_delta`, `min_share` and `max_share`
+    /// rules of the config. The entry is left unchanged if the update is rejected.
+    pub fn update_share(
+        &mut self,
+        config: &Config,
+        share_update: &ShareUpdate,
+    ) -> Result<(), ContractError> {
+        match share_update {
+            ShareUpdate::Set(share) => self.set_share(config, *share),
+            ShareUpdate::Increment(delta) => self.increment_share(config, *delta),
+            ShareUpdate::Decrement(delta) => self.decrement_share(config, *delta),
         }
+    }
+
+    /// Sets the share to an absolute value within `max_share_delta` of the current share.
+    pub fn set_share(&mut self, config: &Config, share: Decimal) -> Result<(), ContractError> {
+        let delta = max(share, self.share) - min(share, self.share);
+        ensure_share_delta(config, delta)?;
+        config.ensure_share_in_bounds(share)?;
+
+        self.share = share;
+        Ok(())
+    }
+
+    /// Increases the share by `delta`, rejecting overflows and out of bounds results.
+    pub fn increment_share(
+        &mut self,
+        config: &Config,
+        delta: Decimal,
+    ) -> Result<(), ContractError> {
+        ensure_share_delta(config, delta)?;
+        let share = self
+            .share
+            .checked_add(delta)
+            .map_err(|_| ContractError::ShareOutOfRange {
+                share: Decimal::MAX,
+                min_share: config.min_share,
+                max_share: config.max_share,
+            })?;
+        config.ensure_share_in_bounds(share)?;
 
+        self.share = share;
         Ok(())
     }
+
+    /// Decreases the share by `delta`, rejecting underflows and out of bounds results.
+    pub fn decrement_share(
+        &mut self,
+        config: &Config,
+        delta: Decimal,
+    ) -> Result<(), ContractError> {
+        ensure_share_delta(config, delta)?;
+        let share = self
+            .share
+            .checked_sub(delta)
+            .map_err(|_| ContractError::ShareUnderflow {
+                share: self.share,
+                delta,
+            })?;
+        config.ensure_share_in_bounds(share)?;
+
+        self.share = share;
+        Ok(())
+    }
+}
+
+fn ensure_share_delta(config: &Config, delta: Decimal) -> Result<(), ContractError> {
+    ensure!(
+        delta <= config.max_share_delta,
+        ContractError::ShareDeltaExceeded {
+            delta,
+            max_share_delta: config.max_share_delta,
+        }
+    );
+    Ok(())
+}
+
+/// A change to the share of a royalty entry.
+#[cw_serde]
+pub enum ShareUpdate {
+    /// Set the share to an absolute value, within `max_share_delta` of the current share
+    Set(Decimal),
+    /// Add to the share, by at most `max_share_delta`
+    Increment(Decimal),
+    /// Remove from the share, by at most `max_share_delta`
+    Decrement(Decimal),
+}
+
+impl fmt::Display for ShareUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShareUpdate::Set(share) => write!(f, "set:{}", share),
+            ShareUpdate::Increment(delta) => write!(f, "increment:{}", delta),
+            ShareUpdate::Decrement(delta) => write!(f, "decrement:{}", delta),
+        }
+    }
+}
+
+impl ShareUpdate {
+    /// Builds a share update from the `share`, `share_delta` and `decrement` message fields.
+    pub fn from_msg(
+        share: Option<Decimal>,
+        share_delta: Option<Decimal>,
+        decrement: Option<bool>,
+    ) -> Result<Option<Self>, ContractError> {
+        match (share, share_delta) {
+            (Some(_), Some(_)) => Err(ContractError::ConflictingShareUpdate {}),
+            (Some(share), None) => Ok(Some(ShareUpdate::Set(share))),
+            (None, Some(share_delta)) if decrement.unwrap_or(false) => {
+                Ok(Some(ShareUpdate::Decrement(share_delta)))
+            }
+            (None, Some(share_delta)) => Ok(Some(ShareUpdate::Increment(share_delta))),
+            (None, None) => Ok(None),
+        }
+    }
 }
 
 #[cw_serde]
 pub struct RoyaltyDefault {
     pub collection: Addr,
     pub royalty_entry: RoyaltyEntry,
+    /// Stricter update rules for the collection's royalty entries, if opted into
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_params: Option<UpdateParams>,
+}
+
+pub struct RoyaltyDefaultIndices<'a> {
+    pub recipient: MultiIndex<'a, Addr, RoyaltyDefault, Addr>,
+}
+
+impl<'a> IndexList<RoyaltyDefault> for RoyaltyDefaultIndices<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<RoyaltyDefault>> + '_> {
+        let v: Vec<&dyn Index<RoyaltyDefault>> = vec![&self.recipient];
+        Box::new(v.into_iter())
+    }
 }
 
-pub const ROYALTY_DEFAULTS: Map<Addr, RoyaltyDefault> = Map::new("rd");
+pub fn royalty_defaults<'a>() -> IndexedMap<'a, Addr, RoyaltyDefault, RoyaltyDefaultIndices<'a>> {
+    let indexes = RoyaltyDefaultIndices {
+        recipient: MultiIndex::new(
+            |_pk: &[u8], rd: &RoyaltyDefault| rd.royalty_entry.recipient.clone(),
+            "rd",
+            "rd__recipient",
+        ),
+    };
+    IndexedMap::new("rd", indexes)
+}
 
 // (collection, protocol) -> RoyaltyProtocol
 pub type RoyaltyProtocolKey = (Addr, Addr);
@@ -89,4 +413,765 @@ pub struct RoyaltyProtocol {
     pub royalty_entry: RoyaltyEntry,
 }
 
-pub const ROYALTY_PROTOCOLS: Map<RoyaltyProtocolKey, RoyaltyProtocol> = Map::new("rp");
+pub struct RoyaltyProtocolIndices<'a> {
+    pub recipient: MultiIndex<'a, Addr, RoyaltyProtocol, RoyaltyProtocolKey>,
+    pub protocol: MultiIndex<'a, Addr, RoyaltyProtocol, RoyaltyProtocolKey>,
+}
+
+impl<'a> IndexList<RoyaltyProtocol> for RoyaltyProtocolIndices<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<RoyaltyProtocol>> + '_> {
+        let v: Vec<&dyn Index<RoyaltyProtocol>> = vec![&self.recipient, &self.protocol];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn royalty_protocols<'a>(
+) -> IndexedMap<'a, RoyaltyProtocolKey, RoyaltyProtocol, RoyaltyProtocolIndices<'a>> {
+    let indexes = RoyaltyProtocolIndices {
+        recipient: MultiIndex::new(
+            |_pk: &[u8], rp: &RoyaltyProtocol| rp.royalty_entry.recipient.clone(),
+            "rp",
+            "rp__recipient",
+        ),
+        protocol: MultiIndex::new(
+            |_pk: &[u8], rp: &RoyaltyProtocol| rp.protocol.clone(),
+            "rp",
+            "rp__protocol",
+        ),
+    };
+    IndexedMap::new("rp", indexes)
+}
+
+#[cw_serde]
+pub enum RoyaltyAction {
+    Initialize,
+    Set,
+    Update,
+    /// The royalty default was synced with the royalty on the collection contract
+    Sync,
+    /// A royalty entry was set by governance, bypassing the update rules
+    GovernanceSet,
+    /// A royalty entry was removed by governance
+    GovernanceRemove,
+    /// Control of the collection royalties was claimed by a new collection admin
+    Claim,
+}
+
+/// A record of a change made to a royalty default or protocol entry.
+#[cw_serde]
+pub struct RoyaltyHistoryEntry {
+    /// The sequential id of the change within the collection's history
+    pub id: u64,
+    pub collection: Addr,
+    /// The protocol of the royalty entry, if the change targeted a protocol entry
+    pub protocol: Option<Addr>,
+    pub action: RoyaltyAction,
+    /// The royalty entry before the change
+    pub previous: Option<RoyaltyEntry>,
+    /// The royalty entry after the change
+    pub current: Option<RoyaltyEntry>,
+    /// The address that performed the change, or the registry itself for governance actions
+    pub actor: Addr,
+    pub timestamp: Timestamp,
+}
+
+// (collection, id) -> RoyaltyHistoryEntry
+pub const ROYALTY_HISTORY: Map<(Addr, u64), RoyaltyHistoryEntry> = Map::new("rh");
+
+// collection -> number of history entries
+pub const ROYALTY_HISTORY_COUNT: Map<Addr, u64> = Map::new("rhc");
+
+/// Appends a change to the collection's royalty history, returning the id of the new record.
+#[allow(clippy::too_many_arguments)]
+pub fn append_royalty_history(
+    storage: &mut dyn Storage,
+    collection: &Addr,
+    protocol: Option<&Addr>,
+    action: RoyaltyAction,
+    previous: Option<&RoyaltyEntry>,
+    current: Option<&RoyaltyEntry>,
+    actor: &Addr,
+    timestamp: Timestamp,
+) -> StdResult<u64> {
+    let id = ROYALTY_HISTORY_COUNT
+        .may_load(storage, collection.clone())?
+        .unwrap_or_default();
+
+    ROYALTY_HISTORY.save(
+        storage,
+        (collection.clone(), id),
+        &RoyaltyHistoryEntry {
+            id,
+            collection: collection.clone(),
+            protocol: protocol.cloned(),
+            action,
+            previous: previous.cloned(),
+            current: current.cloned(),
+            actor: actor.clone(),
+            timestamp,
+        },
+    )?;
+    ROYALTY_HISTORY_COUNT.save(storage, collection.clone(), &(id + 1))?;
+
+    Ok(id)
+}
+
+// collection -> time at which governance froze the collection's royalties
+pub const FROZEN_COLLECTIONS: Map<Addr, Timestamp> = Map::new("fc");
+
+/// Ensures that governance has not frozen the royalties of the collection.
+pub fn ensure_collection_not_frozen(
+    storage: &dyn Storage,
+    collection: &Addr,
+) -> Result<(), ContractError> {
+    ensure!(
+        !FROZEN_COLLECTIONS.has(storage, collection.clone()),
+        ContractError::CollectionFrozen {
+            collection: collection.clone(),
+        }
+    );
+    Ok(())
+}
+
+/// A permanent restriction placed by the collection admin on future royalty changes.
+#[cw_serde]
+#[derive(Eq, PartialOrd, Ord)]
+pub enum RoyaltyLock {
+    /// Royalty shares can no longer be increased
+    IncreasesOnly,
+    /// Royalty entries can no longer be changed
+    Full,
+}
+
+impl fmt::Display for RoyaltyLock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RoyaltyLock::IncreasesOnly => write!(f, "increases_only"),
+            RoyaltyLock::Full => write!(f, "full"),
+        }
+    }
+}
+
+impl RoyaltyLock {
+    /// Ensures that the change from `previous` to `current` is permitted by the lock.
+    pub fn validate_update(
+        &self,
+        previous: &RoyaltyEntry,
+        current: &RoyaltyEntry,
+    ) -> Result<(), ContractError> {
+        match self {
+            RoyaltyLock::Full => Err(ContractError::RoyaltyLocked { lock: self.clone() }),
+            RoyaltyLock::IncreasesOnly => {
+                let increased = current.share > previous.share
+                    || previous
+                        .denom_shares
+                        .iter()
+                        .chain(current.denom_shares.iter())
+                        .any(|denom_share| {
+                            current.share_for_denom(&denom_share.denom)
+                                > previous.share_for_denom(&denom_share.denom)
+                        });
+                ensure!(
+                    !increased,
+                    ContractError::RoyaltyLocked { lock: self.clone() }
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Ensures that a new royalty entry, which replaces a royalty of `baseline_share`
+    /// for the sales it applies to, is permitted by the lock.
+    pub fn validate_new_entry(
+        &self,
+        baseline_share: Decimal,
+        royalty_entry: &RoyaltyEntry,
+    ) -> Result<(), ContractError> {
+        match self {
+            RoyaltyLock::Full => Err(ContractError::RoyaltyLocked { lock: self.clone() }),
+            RoyaltyLock::IncreasesOnly => {
+                ensure!(
+                    royalty_entry.max_share() <= baseline_share,
+                    ContractError::RoyaltyLocked { lock: self.clone() }
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct RoyaltyLocks {
+    /// The lock placed on the collection's royalty default
+    pub default: Option<RoyaltyLock>,
+    /// The lock placed on all of the collection's protocol royalty entries
+    pub protocols: Option<RoyaltyLock>,
+}
+
+impl RoyaltyLocks {
+    pub fn is_empty(&self) -> bool {
+        self.default.is_none() && self.protocols.is_none()
+    }
+
+    pub fn load(storage: &dyn Storage, collection: &Addr) -> StdResult<Self> {
+        Ok(ROYALTY_LOCKS
+            .may_load(storage, collection.clone())?
+            .unwrap_or_default())
+    }
+}
+
+pub const ROYALTY_LOCKS: Map<Addr, RoyaltyLocks> = Map::new("rl");
+
+/// A pending replacement of a royalty entry that can be applied once `activation` has
+/// passed. The entry cannot be updated directly while the proposal is pending.
+#[cw_serde]
+pub struct RoyaltyProposal {
+    pub collection: Addr,
+    /// The protocol of the royalty entry, if the proposal targets a protocol entry
+    pub protocol: Option<Addr>,
+    /// The royalty entry that replaces the current entry when the proposal is applied
+    pub royalty_entry: RoyaltyEntry,
+    /// The address that created the proposal
+    pub proposer: Addr,
+    /// The time after which the proposal can be applied
+    pub activation: Timestamp,
+}
+
+pub const ROYALTY_DEFAULT_PROPOSALS: Map<Addr, RoyaltyProposal> = Map::new("rdp");
+
+pub const ROYALTY_PROTOCOL_PROPOSALS: Map<RoyaltyProtocolKey, RoyaltyProposal> = Map::new("rpp");
+
+/// Ensures that no proposal is pending for the royalty default or protocol entry,
+/// since applying it would overwrite any direct update.
+pub fn ensure_no_pending_proposal(
+    storage: &dyn Storage,
+    collection: &Addr,
+    protocol: Option<&Addr>,
+) -> Result<(), ContractError> {
+    let pending = match protocol {
+        Some(protocol) => {
+            ROYALTY_PROTOCOL_PROPOSALS.has(storage, (collection.clone(), protocol.clone()))
+        }
+        None => ROYALTY_DEFAULT_PROPOSALS.has(storage, collection.clone()),
+    };
+    ensure!(
+        !pending,
+        ContractError::ProposalPending {
+            collection: collection.clone(),
+            protocol: protocol.cloned(),
+        }
+    );
+    Ok(())
+}
+
+/// A temporary royalty share that overrides a royalty entry between `start` and `end`.
+#[cw_serde]
+pub struct RoyaltyPromotion {
+    pub collection: Addr,
+    /// The protocol of the royalty entry, if the promotion targets a protocol entry
+    pub protocol: Option<Addr>,
+    pub share: Decimal,
+    /// The time at which the promotion begins, inclusive
+    pub start: Timestamp,
+    /// The time at which the promotion ends, exclusive
+    pub end: Timestamp,
+}
+
+impl RoyaltyPromotion {
+    pub fn validate(
+        &self,
+        config: &Config,
+        block_time: Timestamp,
+        baseline_share: Decimal,
+    ) -> Result<(), ContractError> {
+        config.ensure_share_in_bounds(self.share)?;
+        ensure!(
+            self.start < self.end,
+            ContractError::InvalidPromotionPeriod {
+                start: self.start,
+                end: self.end,
+            }
+        );
+        ensure!(
+            block_time < self.end,
+            ContractError::PromotionExpired { end: self.end }
+        );
+        ensure!(
+            self.share <= baseline_share,
+            ContractError::PromotionIncreasesShare {
+                share: self.share,
+                baseline_share,
+            }
+        );
+        Ok(())
+    }
+
+    pub fn is_active(&self, block_time: Timestamp) -> bool {
+        self.start <= block_time && block_time < self.end
+    }
+
+    /// Overrides the share of the royalty entry, never increasing it.
+    pub fn apply(&self, royalty_entry: &mut RoyaltyEntry) {
+        royalty_entry.share = min(royalty_entry.share, self.share);
+    }
+}
+
+pub const ROYALTY_DEFAULT_PROMOTIONS: Map<Addr, RoyaltyPromotion> = Map::new("rdpr");
+
+pub const ROYALTY_PROTOCOL_PROMOTIONS: Map<RoyaltyProtocolKey, RoyaltyPromotion> = Map::new("rppr");
+
+/// A protocol known to governance, which collections can set protocol royalty entries for.
+#[cw_serde]
+pub struct RegisteredProtocol {
+    pub protocol: Addr,
+    pub name: String,
+    pub url: String,
+    /// The maximum royalty share that the protocol will honor
+    pub max_share: Decimal,
+    /// Whether collections can set or update royalty entries for the protocol
+    pub active: bool,
+}
+
+impl RegisteredProtocol {
+    pub fn save(&self, storage: &mut dyn Storage) -> Result<(), ContractError> {
+        self.validate()?;
+        REGISTERED_PROTOCOLS.save(storage, self.protocol.clone(), self)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ContractError> {
+        ensure!(!self.name.is_empty(), ContractError::InvalidProtocolName {});
+        ensure!(
+            self.max_share <= Decimal::one(),
+            ContractError::InvalidProtocolMaxShare {
+                max_share: self.max_share,
+            }
+        );
+        Ok(())
+    }
+
+    /// Loads the registered protocol, ensuring that it is active.
+    pub fn load_active(storage: &dyn Storage, protocol: &Addr) -> Result<Self, ContractError> {
+        let registered_protocol = REGISTERED_PROTOCOLS
+            .load(storage, protocol.clone())
+            .map_err(|_| ContractError::ProtocolNotRegistered {
+                protocol: protocol.clone(),
+            })?;
+
+        ensure!(
+            registered_protocol.active,
+            ContractError::ProtocolInactive {
+                protocol: protocol.clone(),
+            }
+        );
+
+        Ok(registered_protocol)
+    }
+
+    /// Returns whether the protocol is registered and active.
+    pub fn is_active(storage: &dyn Storage, protocol: &Addr) -> StdResult<bool> {
+        Ok(REGISTERED_PROTOCOLS
+            .may_load(storage, protocol.clone())?
+            .is_some_and(|registered_protocol| registered_protocol.active))
+    }
+
+    /// Ensures that the royalty entry does not exceed the share honored by the protocol.
+    pub fn validate_royalty_entry(
+        &self,
+        royalty_entry: &RoyaltyEntry,
+    ) -> Result<(), ContractError> {
+        ensure!(
+            royalty_entry.max_share() <= self.max_share,
+            ContractError::ShareOutOfRange {
+                share: royalty_entry.max_share(),
+                min_share: Decimal::zero(),
+                max_share: self.max_share,
+            }
+        );
+        Ok(())
+    }
+
+    /// Ensures that the update of a protocol entry from `previous` to `current` is
+    /// permitted. Updates that do not raise the share are always permitted, so that entries
+    /// of protocols that are unregistered or inactive can still be lowered or redirected.
+    pub fn validate_update(
+        storage: &dyn Storage,
+        protocol: &Addr,
+        previous: &RoyaltyEntry,
+        current: &RoyaltyEntry,
+    ) -> Result<(), ContractError> {
+        if current.max_share() <= previous.max_share() {
+            return Ok(());
+        }
+        Self::load_active(storage, protocol)?.validate_royalty_entry(current)
+    }
+}
+
+pub const REGISTERED_PROTOCOLS: Map<Addr, RegisteredProtocol> = Map::new("prot");
+
+// (collection, manager) -> CollectionManager
+pub type CollectionManagerKey = (Addr, Addr);
+
+#[cw_serde]
+pub struct CollectionManager {
+    pub collection: Addr,
+    /// The address that is allowed to manage royalties on behalf of the collection admin
+    pub manager: Addr,
+    /// When the manager loses its permissions
+    pub expires: Expiration,
+}
+
+pub const COLLECTION_MANAGERS: Map<CollectionManagerKey, CollectionManager> = Map::new("cm");
+
+/// The role of a royalty recipient, for display purposes.
+#[cw_serde]
+pub enum RoyaltyRole {
+    Artist,
+    Dao,
+    Treasury,
+    Charity,
+    Other,
+}
+
+impl fmt::Display for RoyaltyRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RoyaltyRole::Artist => write!(f, "artist"),
+            RoyaltyRole::Dao => write!(f, "dao"),
+            RoyaltyRole::Treasury => write!(f, "treasury"),
+            RoyaltyRole::Charity => write!(f, "charity"),
+            RoyaltyRole::Other => write!(f, "other"),
+        }
+    }
+}
+
+/// Display information about the recipient of a royalty entry. Metadata is stored
+/// separately from royalty entries, so that it does not bloat payout lookups.
+#[cw_serde]
+pub struct RoyaltyMetadata {
+    pub label: Option<String>,
+    pub role: Option<RoyaltyRole>,
+    pub url: Option<String>,
+}
+
+impl RoyaltyMetadata {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if let Some(label) = &self.label {
+            ensure!(
+                !label.is_empty() && label.len() <= MAX_METADATA_LABEL_LENGTH,
+                ContractError::InvalidRoyaltyLabel {
+                    max_length: MAX_METADATA_LABEL_LENGTH,
+                }
+            );
+        }
+        if let Some(url) = &self.url {
+            ensure!(
+                url.len() <= MAX_METADATA_URL_LENGTH,
+                ContractError::InvalidRoyaltyUrl {
+                    max_length: MAX_METADATA_URL_LENGTH,
+                }
+            );
+            ensure!(
+                url.starts_with("https://"),
+                ContractError::InvalidRoyaltyUrl {
+                    max_length: MAX_METADATA_URL_LENGTH,
+                }
+            );
+        }
+        Ok(())
+    }
+}
+
+pub const ROYALTY_DEFAULT_METADATA: Map<Addr, RoyaltyMetadata> = Map::new("rdm");
+
+pub const ROYALTY_PROTOCOL_METADATA: Map<RoyaltyProtocolKey, RoyaltyMetadata> = Map::new("rpm");
+
+/// The collection admin at the time the collection royalties were registered, or last claimed.
+pub const COLLECTION_CREATORS: Map<Addr, Addr> = Map::new("cc");
+
+// (recipient, denom) -> amount
+pub type RoyaltyBalanceKey = (Addr, String);
+
+/// Royalties held in escrow by the registry until they are claimed by the recipient.
+pub const ROYALTY_BALANCES: Map<RoyaltyBalanceKey, Uint128> = Map::new("rb");
+
+// (collection, denom) -> amount
+pub const COLLECTION_ROYALTY_TOTALS: Map<(Addr, String), Uint128> = Map::new("crt");
+
+// (recipient, denom) -> amount
+pub const RECIPIENT_ROYALTY_TOTALS: Map<(Addr, String), Uint128> = Map::new("rrt");
+
+// (protocol, denom) -> amount
+pub const PROTOCOL_ROYALTY_TOTALS: Map<(Addr, String), Uint128> = Map::new("prt");
+
+/// The royalties paid for a collection during a period of `ROYALTY_STATS_PERIOD` seconds.
+#[cw_serde]
+pub struct RoyaltyPeriod {
+    pub collection: Addr,
+    pub period_start: Timestamp,
+    pub amounts: Vec<Coin>,
+}
+
+// (collection, period start in seconds) -> RoyaltyPeriod
+pub const COLLECTION_ROYALTY_PERIODS: Map<(Addr, u64), RoyaltyPeriod> = Map::new("crp");
+
+/// Adds a royalty payment made by the protocol to the totals and to the current period.
+/// Only active registered protocols can record royalty payments.
+pub fn record_royalty_payment(
+    storage: &mut dyn Storage,
+    collection: &Addr,
+    recipient: &Addr,
+    protocol: &Addr,
+    amount: &Coin,
+    block_time: Timestamp,
+) -> Result<(), ContractError> {
+    RegisteredProtocol::load_active(storage, protocol)?;
+    ensure!(
+        !amount.amount.is_zero(),
+        ContractError::ZeroRoyaltyPayment {}
+    );
+
+    let add_amount = |total: Option<Uint128>| -> StdResult<Uint128> {
+        Ok(total.unwrap_or_default().checked_add(amount.amount)?)
+    };
+
+    COLLECTION_ROYALTY_TOTALS.update(
+        storage,
+        (collection.clone(), amount.denom.clone()),
+        add_amount,
+    )?;
+    RECIPIENT_ROYALTY_TOTALS.update(
+        storage,
+        (recipient.clone(), amount.denom.clone()),
+        add_amount,
+    )?;
+    PROTOCOL_ROYALTY_TOTALS.update(
+        storage,
+        (protocol.clone(), amount.denom.clone()),
+        add_amount,
+    )?;
+
+    let period_start = block_time.seconds() - block_time.seconds() % ROYALTY_STATS_PERIOD;
+    COLLECTION_ROYALTY_PERIODS.update(
+        storage,
+        (collection.clone(), period_start),
+        |royalty_period| -> StdResult<_> {
+            let mut royalty_period = royalty_period.unwrap_or_else(|| RoyaltyPeriod {
+                collection: collection.clone(),
+                period_start: Timestamp::from_seconds(period_start),
+                amounts: vec![],
+            });
+            match royalty_period
+                .amounts
+                .iter_mut()
+                .find(|period_amount| period_amount.denom == amount.denom)
+            {
+                Some(period_amount) => {
+                    period_amount.amount = period_amount.amount.checked_add(amount.amount)?
+                }
+                None => royalty_period
+                    .amounts
+                    .push(coin(amount.amount.u128(), &amount.denom)),
+            }
+            Ok(royalty_period)
+        },
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::testing::MockStorage;
+    use proptest::prelude::*;
+
+    fn decimal() -> impl Strategy<Value = Decimal> {
+        (0u128..=10u128.pow(18)).prop_map(Decimal::raw)
+    }
+
+    fn config() -> impl Strategy<Value = Config> {
+        (decimal(), decimal(), decimal()).prop_map(|(max_share_delta, a, b)| Config {
+            update_wait_period: 0,
+            max_share_delta,
+            min_share: min(a, b),
+            max_share: max(a, b),
+        })
+    }
+
+    fn royalty_entry(share: Decimal) -> RoyaltyEntry {
+        RoyaltyEntry {
+            recipient: Addr::unchecked("recipient"),
+            share,
+            updated: None,
+            denom_shares: vec![],
+        }
+    }
+
+    fn abs_diff(a: Decimal, b: Decimal) -> Decimal {
+        max(a, b) - min(a, b)
+    }
+
+    proptest! {
+        #[test]
+        fn share_updates_respect_config(
+            config in config(),
+            share in decimal(),
+            amount in decimal(),
+            kind in 0u8..3,
+        ) {
+            let share_update = match kind {
+                0 => ShareUpdate::Set(amount),
+                1 => ShareUpdate::Increment(amount),
+                _ => ShareUpdate::Decrement(amount),
+            };
+
+            let mut entry = royalty_entry(share);
+            match entry.update_share(&config, &share_update) {
+                Ok(()) => {
+                    prop_assert!(config.min_share <= entry.share);
+                    prop_assert!(entry.share <= config.max_share);
+                    prop_assert!(abs_diff(entry.share, share) <= config.max_share_delta);
+                }
+                Err(_) => prop_assert_eq!(entry, royalty_entry(share)),
+            }
+        }
+
+        #[test]
+        fn set_share_succeeds_iff_within_delta_and_bounds(
+            config in config(),
+            share in decimal(),
+            target in decimal(),
+        ) {
+            let expected = abs_diff(target, share) <= config.max_share_delta
+                && config.min_share <= target
+                && target <= config.max_share;
+
+            let mut entry = royalty_entry(share);
+            prop_assert_eq!(entry.set_share(&config, target).is_ok(), expected);
+            if expected {
+                prop_assert_eq!(entry.share, target);
+            }
+        }
+
+        #[test]
+        fn increment_then_decrement_round_trips(
+            config in config(),
+            share in decimal(),
+            delta in decimal(),
+        ) {
+            prop_assume!(config.ensure_share_in_bounds(share).is_ok());
+
+            let mut entry = royalty_entry(share);
+            if entry.increment_share(&config, delta).is_ok() {
+                prop_assert!(entry.decrement_share(&config, delta).is_ok());
+                prop_assert_eq!(entry.share, share);
+            }
+        }
+
+        #[test]
+        fn decrement_never_underflows(
+            config in config(),
+            share in decimal(),
+            delta in decimal(),
+        ) {
+            let mut entry = royalty_entry(share);
+            let result = entry.decrement_share(&config, delta);
+            if delta > share && delta <= config.max_share_delta {
+                prop_assert!(
+                    matches!(result, Err(ContractError::ShareUnderflow { .. })),
+                    "expected underflow error"
+                );
+            }
+        }
+
+        #[test]
+        fn config_validation_enforces_bounds(
+            update_wait_period in 0u64..=2 * MAX_UPDATE_WAIT_PERIOD,
+            max_share_delta in decimal(),
+        ) {
+            let config = Config {
+                update_wait_period,
+                max_share_delta,
+                min_share: Decimal::zero(),
+                max_share: Decimal::one(),
+            };
+            let expected = (MIN_UPDATE_WAIT_PERIOD..=MAX_UPDATE_WAIT_PERIOD)
+                .contains(&update_wait_period)
+                && MIN_MAX_SHARE_DELTA <= max_share_delta
+                && max_share_delta <= MAX_MAX_SHARE_DELTA;
+            prop_assert_eq!(config.validate().is_ok(), expected);
+        }
+
+        #[test]
+        fn effective_config_is_never_looser(
+            config in config(),
+            update_wait_period in proptest::option::of(any::<u64>()),
+            max_share_delta in proptest::option::of(decimal()),
+        ) {
+            let update_params = UpdateParams {
+                update_wait_period,
+                max_share_delta,
+            };
+            let effective = config.effective(Some(&update_params));
+            prop_assert!(effective.update_wait_period >= config.update_wait_period);
+            prop_assert!(effective.max_share_delta <= config.max_share_delta);
+            prop_assert_eq!(effective.min_share, config.min_share);
+            prop_assert_eq!(effective.max_share, config.max_share);
+        }
+    }
+
+    #[test]
+    fn only_active_protocols_record_royalty_payments() {
+        let mut storage = MockStorage::new();
+        let collection = Addr::unchecked("collection");
+        let recipient = Addr::unchecked("recipient");
+        let protocol = Addr::unchecked("protocol");
+        let amount = coin(1_000, "ustars");
+        let record = |storage: &mut MockStorage| {
+            record_royalty_payment(
+                storage,
+                &collection,
+                &recipient,
+                &protocol,
+                &amount,
+                Timestamp::from_seconds(0),
+            )
+        };
+
+        assert_eq!(
+            record(&mut storage),
+            Err(ContractError::ProtocolNotRegistered {
+                protocol: protocol.clone(),
+            })
+        );
+
+        let mut registered_protocol = RegisteredProtocol {
+            protocol: protocol.clone(),
+            name: "Protocol".to_string(),
+            url: "https://example.com".to_string(),
+            max_share: Decimal::percent(10),
+            active: false,
+        };
+        registered_protocol.save(&mut storage).unwrap();
+        assert_eq!(
+            record(&mut storage),
+            Err(ContractError::ProtocolInactive {
+                protocol: protocol.clone(),
+            })
+        );
+        assert!(COLLECTION_ROYALTY_TOTALS.is_empty(&storage));
+
+        registered_protocol.active = true;
+        registered_protocol.save(&mut storage).unwrap();
+        record(&mut storage).unwrap();
+        assert_eq!(
+            PROTOCOL_ROYALTY_TOTALS
+                .load(&storage, (protocol.clone(), "ustars".to_string()))
+                .unwrap(),
+            Uint128::new(1_000)
+        );
+    }
+}
@@ -1,8 +1,14 @@
 use crate::error::ContractError;
 use crate::msg::SudoMsg;
-use crate::state::Config;
+use crate::state::{
+    append_royalty_history, royalty_defaults, royalty_protocols, Config, RegisteredProtocol,
+    RoyaltyAction, RoyaltyDefault, RoyaltyEntry, RoyaltyLocks, RoyaltyProtocol, RoyaltyProtocolKey,
+    FROZEN_COLLECTIONS, REGISTERED_PROTOCOLS, ROYALTY_DEFAULT_METADATA, ROYALTY_DEFAULT_PROMOTIONS,
+    ROYALTY_DEFAULT_PROPOSALS, ROYALTY_LOCKS, ROYALTY_PROTOCOL_METADATA,
+    ROYALTY_PROTOCOL_PROMOTIONS, ROYALTY_PROTOCOL_PROPOSALS,
+};
 
-use cosmwasm_std::{DepsMut, Env, Event};
+use cosmwasm_std::{attr, ensure, Addr, Decimal, DepsMut, Env, Event};
 use sg_std::Response;
 
 #[cfg(not(feature = "library"))]
@@ -10,8 +16,70 @@ use cosmwasm_std::entry_point;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    let api = deps.api;
+
     match msg {
         SudoMsg::UpdateConfig { config } => sudo_update_config(deps, env, config),
+        SudoMsg::RegisterProtocol {
+            protocol,
+            name,
+            url,
+            max_share,
+            active,
+        } => sudo_register_protocol(
+            deps,
+            env,
+            api.addr_validate(&protocol)?,
+            name,
+            url,
+            max_share,
+            active,
+        ),
+        SudoMsg::RemoveProtocol { protocol } => {
+            sudo_remove_protocol(deps, env, api.addr_validate(&protocol)?)
+        }
+        SudoMsg::ForceSetCollectionRoyaltyDefault {
+            collection,
+            recipient,
+            share,
+        } => sudo_force_set_collection_royalty_default(
+            deps,
+            env,
+            api.addr_validate(&collection)?,
+            api.addr_validate(&recipient)?,
+            share,
+        ),
+        SudoMsg::ForceSetCollectionRoyaltyProtocol {
+            collection,
+            protocol,
+            recipient,
+            share,
+        } => sudo_force_set_collection_royalty_protocol(
+            deps,
+            env,
+            api.addr_validate(&collection)?,
+            api.addr_validate(&protocol)?,
+            api.addr_validate(&recipient)?,
+            share,
+        ),
+        SudoMsg::RemoveCollectionRoyaltyDefault { collection } => {
+            sudo_remove_collection_royalty_default(deps, env, api.addr_validate(&collection)?)
+        }
+        SudoMsg::RemoveCollectionRoyaltyProtocol {
+            collection,
+            protocol,
+        } => sudo_remove_collection_royalty_protocol(
+            deps,
+            env,
+            api.addr_validate(&collection)?,
+            api.addr_validate(&protocol)?,
+        ),
+        SudoMsg::FreezeCollectionRoyalty { collection } => {
+            sudo_freeze_collection_royalty(deps, env, api.addr_validate(&collection)?)
+        }
+        SudoMsg::UnfreezeCollectionRoyalty { collection } => {
+            sudo_unfreeze_collection_royalty(deps, env, api.addr_validate(&collection)?)
+        }
     }
 }
 
@@ -26,7 +94,299 @@ pub fn sudo_update_config(
     response = response.add_event(
         Event::new("update-config")
             .add_attribute("update_wait_period", config.update_wait_period.to_string())
-            .add_attribute("max_share_delta", config.max_share_delta.to_string()),
+            .add_attribute("max_share_delta", config.max_share_delta.to_string())
+            .add_attribute("min_share", config.min_share.to_string())
+            .add_attribute("max_share", config.max_share.to_string()),
+    );
+
+    Ok(response)
+}
+
+pub fn sudo_register_protocol(
+    deps: DepsMut,
+    _env: Env,
+    protocol: Addr,
+    name: String,
+    url: String,
+    max_share: Decimal,
+    active: bool,
+) -> Result<Response, ContractError> {
+    let registered_protocol = RegisteredProtocol {
+        protocol,
+        name,
+        url,
+        max_share,
+        active,
+    };
+    registered_protocol.save(deps.storage)?;
+
+    let mut response = Response::new();
+    response = response.add_event(
+        Event::new("register-protocol")
+            .add_attribute("protocol", registered_protocol.protocol.to_string())
+            .add_attribute("name", registered_protocol.name)
+            .add_attribute("url", registered_protocol.url)
+            .add_attribute("max_share", registered_protocol.max_share.to_string())
+            .add_attribute("active", registered_protocol.active.to_string()),
+    );
+
+    Ok(response)
+}
+
+pub fn sudo_remove_protocol(
+    deps: DepsMut,
+    _env: Env,
+    protocol: Addr,
+) -> Result<Response, ContractError> {
+    ensure!(
+        REGISTERED_PROTOCOLS.has(deps.storage, protocol.clone()),
+        ContractError::ProtocolNotRegistered {
+            protocol: protocol.clone(),
+        }
+    );
+    REGISTERED_PROTOCOLS.remove(deps.storage, protocol.clone());
+
+    let mut response = Response::new();
+    response = response
+        .add_event(Event::new("remove-protocol").add_attribute("protocol", protocol.to_string()));
+
+    Ok(response)
+}
+
+pub fn sudo_force_set_collection_royalty_default(
+    deps: DepsMut,
+    env: Env,
+    collection: Addr,
+    recipient: Addr,
+    share: Decimal,
+) -> Result<Response, ContractError> {
+    let previous_default = royalty_defaults().may_load(deps.storage, collection.clone())?;
+    let previous = previous_default
+        .as_ref()
+        .map(|royalty_default| royalty_default.royalty_entry.clone());
+
+    let royalty_entry = RoyaltyEntry {
+        recipient: recipient.clone(),
+        share,
+        updated: Some(env.block.time),
+        denom_shares: vec![],
+    };
+    royalty_entry.validate()?;
+
+    append_royalty_history(
+        deps.storage,
+        &collection,
+        None,
+        RoyaltyAction::GovernanceSet,
+        previous.as_ref(),
+        Some(&royalty_entry),
+        &env.contract.address,
+        env.block.time,
+    )?;
+
+    royalty_defaults().save(
+        deps.storage,
+        collection.clone(),
+        &RoyaltyDefault {
+            collection: collection.clone(),
+            royalty_entry,
+            // The collection's opted-in update params survive governance changes
+            update_params: previous_default
+                .and_then(|royalty_default| royalty_default.update_params),
+        },
+    )?;
+    ROYALTY_DEFAULT_PROPOSALS.remove(deps.storage, collection.clone());
+
+    let mut response = Response::new();
+    response = response.add_event(
+        Event::new("governance-set-collection-royalty-default").add_attributes(vec![
+            attr("collection", collection.to_string()),
+            attr("recipient", recipient.to_string()),
+            attr("share", share.to_string()),
+            attr("updated", env.block.time.to_string()),
+        ]),
+    );
+
+    Ok(response)
+}
+
+pub fn sudo_force_set_collection_royalty_protocol(
+    deps: DepsMut,
+    env: Env,
+    collection: Addr,
+    protocol: Addr,
+    recipient: Addr,
+    share: Decimal,
+) -> Result<Response, ContractError> {
+    let royalty_protocol_key: RoyaltyProtocolKey = (collection.clone(), protocol.clone());
+    let previous = royalty_protocols()
+        .may_load(deps.storage, royalty_protocol_key.clone())?
+        .map(|royalty_protocol| royalty_protocol.royalty_entry);
+
+    let royalty_entry = RoyaltyEntry {
+        recipient: recipient.clone(),
+        share,
+        updated: Some(env.block.time),
+        denom_shares: vec![],
+    };
+    royalty_entry.validate()?;
+
+    append_royalty_history(
+        deps.storage,
+        &collection,
+        Some(&protocol),
+        RoyaltyAction::GovernanceSet,
+        previous.as_ref(),
+        Some(&royalty_entry),
+        &env.contract.address,
+        env.block.time,
+    )?;
+
+    royalty_protocols().save(
+        deps.storage,
+        royalty_protocol_key.clone(),
+        &RoyaltyProtocol {
+            collection: collection.clone(),
+            protocol: protocol.clone(),
+            royalty_entry,
+        },
+    )?;
+    ROYALTY_PROTOCOL_PROPOSALS.remove(deps.storage, royalty_protocol_key);
+
+    let mut response = Response::new();
+    response = response.add_event(
+        Event::new("governance-set-collection-royalty-protocol").add_attributes(vec![
+            attr("collection", collection.to_string()),
+            attr("protocol", protocol.to_string()),
+            attr("recipient", recipient.to_string()),
+            attr("share", share.to_string()),
+            attr("updated", env.block.time.to_string()),
+        ]),
+    );
+
+    Ok(response)
+}
+
+pub fn sudo_remove_collection_royalty_default(
+    deps: DepsMut,
+    env: Env,
+    collection: Addr,
+) -> Result<Response, ContractError> {
+    let royalty_default = royalty_defaults()
+        .load(deps.storage, collection.clone())
+        .map_err(|_| ContractError::RoyaltyNotFound {
+            collection: collection.clone(),
+            protocol: None,
+        })?;
+
+    append_royalty_history(
+        deps.storage,
+        &collection,
+        None,
+        RoyaltyAction::GovernanceRemove,
+        Some(&royalty_default.royalty_entry),
+        None,
+        &env.contract.address,
+        env.block.time,
+    )?;
+
+    royalty_defaults().remove(deps.storage, collection.clone())?;
+    ROYALTY_DEFAULT_PROPOSALS.remove(deps.storage, collection.clone());
+    ROYALTY_DEFAULT_METADATA.remove(deps.storage, collection.clone());
+    ROYALTY_DEFAULT_PROMOTIONS.remove(deps.storage, collection.clone());
+
+    // Promotions and locks of the removed default must not carry over to a later default
+    let mut royalty_locks = RoyaltyLocks::load(deps.storage, &collection)?;
+    royalty_locks.default = None;
+    if royalty_locks.is_empty() {
+        ROYALTY_LOCKS.remove(deps.storage, collection.clone());
+    } else {
+        ROYALTY_LOCKS.save(deps.storage, collection.clone(), &royalty_locks)?;
+    }
+
+    let mut response = Response::new();
+    response = response.add_event(
+        Event::new("governance-remove-collection-royalty-default")
+            .add_attribute("collection", collection.to_string()),
+    );
+
+    Ok(response)
+}
+
+pub fn sudo_remove_collection_royalty_protocol(
+    deps: DepsMut,
+    env: Env,
+    collection: Addr,
+    protocol: Addr,
+) -> Result<Response, ContractError> {
+    let royalty_protocol_key: RoyaltyProtocolKey = (collection.clone(), protocol.clone());
+    let royalty_protocol = royalty_protocols()
+        .load(deps.storage, royalty_protocol_key.clone())
+        .map_err(|_| ContractError::RoyaltyNotFound {
+            collection: collection.clone(),
+            protocol: Some(protocol.clone()),
+        })?;
+
+    append_royalty_history(
+        deps.storage,
+        &collection,
+        Some(&protocol),
+        RoyaltyAction::GovernanceRemove,
+        Some(&royalty_protocol.royalty_entry),
+        None,
+        &env.contract.address,
+        env.block.time,
+    )?;
+
+    royalty_protocols().remove(deps.storage, royalty_protocol_key.clone())?;
+    ROYALTY_PROTOCOL_PROPOSALS.remove(deps.storage, royalty_protocol_key.clone());
+    ROYALTY_PROTOCOL_METADATA.remove(deps.storage, royalty_protocol_key.clone());
+    ROYALTY_PROTOCOL_PROMOTIONS.remove(deps.storage, royalty_protocol_key);
+
+    let mut response = Response::new();
+    response = response.add_event(
+        Event::new("governance-remove-collection-royalty-protocol")
+            .add_attribute("collection", collection.to_string())
+            .add_attribute("protocol", protocol.to_string()),
+    );
+
+    Ok(response)
+}
+
+pub fn sudo_freeze_collection_royalty(
+    deps: DepsMut,
+    env: Env,
+    collection: Addr,
+) -> Result<Response, ContractError> {
+    FROZEN_COLLECTIONS.save(deps.storage, collection.clone(), &env.block.time)?;
+
+    let mut response = Response::new();
+    response = response.add_event(
+        Event::new("governance-freeze-collection-royalty")
+            .add_attribute("collection", collection.to_string())
+            .add_attribute("frozen", env.block.time.to_string()),
+    );
+
+    Ok(response)
+}
+
+pub fn sudo_unfreeze_collection_royalty(
+    deps: DepsMut,
+    _env: Env,
+    collection: Addr,
+) -> Result<Response, ContractError> {
+    ensure!(
+        FROZEN_COLLECTIONS.has(deps.storage, collection.clone()),
+        ContractError::CollectionNotFrozen {
+            collection: collection.clone(),
+        }
+    );
+    FROZEN_COLLECTIONS.remove(deps.storage, collection.clone());
+
+    let mut response = Response::new();
+    response = response.add_event(
+        Event::new("governance-unfreeze-collection-royalty")
+            .add_attribute("collection", collection.to_string()),
     );
 
     Ok(response)
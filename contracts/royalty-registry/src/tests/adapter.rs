@@ -0,0 +1,127 @@
+use crate::{
+    adapter::CollectionAdapter,
+    msg::{ExecuteMsg, QueryMsg},
+    state::RoyaltyDefault,
+    tests::setup::{
+        artist, creator, default_config, execute_msg, initialize_collection_royalty, query_msg,
+        seller, setup_collection, setup_collection_with_tokens, setup_royalty_registry,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::{Addr, Decimal};
+use cw_multi_test::AppResponse;
+use sg_multi_test::StargazeApp;
+
+fn try_initialize_collection_royalty(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::InitializeCollectionRoyalty {
+            collection: collection.to_string(),
+        },
+    )
+}
+
+#[test]
+fn try_initialize_cw2981_collection_royalty() {
+    let mut app = StargazeApp::default();
+    let royalty_registry = setup_royalty_registry(&mut app, default_config());
+    let collection = setup_collection(
+        &mut app,
+        CollectionAdapter::Cw2981,
+        Some(Decimal::permille(75)),
+    );
+
+    initialize_collection_royalty(&mut app, &royalty_registry, &collection);
+    let royalty_default: Option<RoyaltyDefault> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::CollectionRoyaltyDefault {
+            collection: collection.to_string(),
+        },
+    );
+    let royalty_entry = royalty_default.unwrap().royalty_entry;
+    assert_eq!(royalty_entry.recipient, artist());
+    assert_eq!(royalty_entry.share, Decimal::permille(75));
+
+    // CW2981 collections are administered by their wasm admin
+    let err = execute_msg(
+        &mut app,
+        &seller(),
+        &royalty_registry,
+        &ExecuteMsg::UpdateCollectionRoyaltyDefault {
+            collection: collection.to_string(),
+            recipient: Some(seller().to_string()),
+            share: None,
+            share_delta: None,
+            decrement: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotCollectionAdmin {
+            sender: seller(),
+            admin: creator(),
+        }
+    );
+}
+
+#[test]
+fn try_initialize_cw2981_collection_royalty_without_tokens() {
+    let mut app = StargazeApp::default();
+    let royalty_registry = setup_royalty_registry(&mut app, default_config());
+    let collection = setup_collection_with_tokens(
+        &mut app,
+        CollectionAdapter::Cw2981,
+        Some(Decimal::percent(5)),
+        vec![],
+    );
+
+    let err =
+        try_initialize_collection_royalty(&mut app, &creator(), &royalty_registry, &collection)
+            .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CollectionHasNoTokens {
+            collection: collection.clone(),
+        }
+    );
+}
+
+#[test]
+fn try_initialize_cw721_collection_royalty() {
+    let mut app = StargazeApp::default();
+    let royalty_registry = setup_royalty_registry(&mut app, default_config());
+    let collection = setup_collection(&mut app, CollectionAdapter::Cw721, None);
+
+    // Plain cw721 collections have no royalty to initialize from, but can be set directly
+    let err =
+        try_initialize_collection_royalty(&mut app, &creator(), &royalty_registry, &collection)
+            .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CollectionRoyaltyNotFound {
+            collection: collection.clone(),
+        }
+    );
+
+    execute_msg(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &ExecuteMsg::SetCollectionRoyaltyDefault {
+            collection: collection.to_string(),
+            recipient: artist().to_string(),
+            share: Decimal::percent(5),
+        },
+    )
+    .unwrap();
+}
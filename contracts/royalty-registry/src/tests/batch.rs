@@ -0,0 +1,116 @@
+use crate::{
+    adapter::CollectionAdapter,
+    constants::MAX_BATCH_SIZE,
+    msg::{QueryMsg, RoyaltyOperation, RoyaltyPaymentResponse},
+    tests::setup::{
+        batch_collection_royalty, creator, initialize_collection_royalty, query_msg,
+        royalty_default, setup, setup_collection,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::{Addr, Decimal};
+
+fn decrement_default(collection: &Addr) -> RoyaltyOperation {
+    RoyaltyOperation::UpdateDefault {
+        collection: collection.to_string(),
+        recipient: None,
+        share: None,
+        share_delta: Some(Decimal::percent(1)),
+        decrement: Some(true),
+    }
+}
+
+#[test]
+fn try_batch_collection_royalty() {
+    let (mut app, royalty_registry, collection) = setup();
+    let other_collection = setup_collection(
+        &mut app,
+        CollectionAdapter::Sg721,
+        Some(Decimal::percent(5)),
+    );
+    initialize_collection_royalty(&mut app, &royalty_registry, &collection);
+    initialize_collection_royalty(&mut app, &royalty_registry, &other_collection);
+
+    batch_collection_royalty(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        vec![
+            decrement_default(&collection),
+            decrement_default(&other_collection),
+        ],
+    )
+    .unwrap();
+
+    let royalty_payment_responses: Vec<RoyaltyPaymentResponse> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::RoyaltyPayments {
+            collections: vec![other_collection.to_string(), collection.to_string()],
+            protocol: None,
+            denom: None,
+        },
+    );
+    assert_eq!(
+        royalty_payment_responses
+            .into_iter()
+            .map(|response| {
+                let royalty_default = response.royalty_default.unwrap();
+                (
+                    royalty_default.collection,
+                    royalty_default.royalty_entry.share,
+                )
+            })
+            .collect::<Vec<_>>(),
+        vec![
+            (other_collection, Decimal::percent(4)),
+            (collection, Decimal::percent(4)),
+        ]
+    );
+}
+
+#[test]
+fn try_batch_collection_royalty_is_atomic() {
+    let (mut app, royalty_registry, collection) = setup();
+    let uninitialized_collection = setup_collection(
+        &mut app,
+        CollectionAdapter::Sg721,
+        Some(Decimal::percent(5)),
+    );
+    initialize_collection_royalty(&mut app, &royalty_registry, &collection);
+
+    let err = batch_collection_royalty(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        vec![
+            decrement_default(&collection),
+            decrement_default(&uninitialized_collection),
+        ],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RoyaltyNotFound {
+            collection: uninitialized_collection,
+            protocol: None,
+        }
+    );
+    assert_eq!(
+        royalty_default(&app, &royalty_registry, &collection)
+            .royalty_entry
+            .share,
+        Decimal::percent(5)
+    );
+
+    let err =
+        batch_collection_royalty(&mut app, &creator(), &royalty_registry, vec![]).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidBatchSize {
+            size: 0,
+            max_batch_size: MAX_BATCH_SIZE,
+        }
+    );
+}
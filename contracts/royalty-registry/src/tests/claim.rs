@@ -0,0 +1,88 @@
+use crate::{
+    msg::SudoMsg,
+    tests::setup::{
+        artist, claim_collection_royalty, collection_creator, creator, grant_collection_manager,
+        new_creator, set_collection_creator, setup, setup_with_royalty, sudo_msg,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Decimal;
+
+#[test]
+fn try_claim_collection_royalty() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    set_collection_creator(&mut app, &collection, &new_creator());
+
+    let response = collection_creator(&app, &royalty_registry, &collection);
+    assert_eq!(response.recorded_creator, Some(creator()));
+    assert_eq!(response.changed, Some(true));
+
+    let err =
+        claim_collection_royalty(&mut app, &creator(), &royalty_registry, &collection).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotCollectionAdmin {
+            sender: creator(),
+            admin: new_creator(),
+        }
+    );
+
+    claim_collection_royalty(&mut app, &new_creator(), &royalty_registry, &collection).unwrap();
+    let response = collection_creator(&app, &royalty_registry, &collection);
+    assert_eq!(response.recorded_creator, Some(new_creator()));
+    assert_eq!(response.changed, Some(false));
+
+    let err = claim_collection_royalty(&mut app, &new_creator(), &royalty_registry, &collection)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CollectionAdminUnchanged {
+            collection: collection.clone(),
+        }
+    );
+}
+
+#[test]
+fn try_claim_collection_royalty_without_recorded_creator() {
+    let (mut app, royalty_registry, collection) = setup();
+
+    // Royalty defaults set by governance do not record the collection admin
+    sudo_msg(
+        &mut app,
+        &royalty_registry,
+        &SudoMsg::ForceSetCollectionRoyaltyDefault {
+            collection: collection.to_string(),
+            recipient: artist().to_string(),
+            share: Decimal::percent(5),
+        },
+    )
+    .unwrap();
+    set_collection_creator(&mut app, &collection, &new_creator());
+
+    let response = collection_creator(&app, &royalty_registry, &collection);
+    assert_eq!(response.recorded_creator, None);
+    assert_eq!(response.changed, None);
+
+    let err = claim_collection_royalty(&mut app, &new_creator(), &royalty_registry, &collection)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CollectionAdminNotRecorded {
+            collection: collection.clone(),
+        }
+    );
+
+    // The admin is recorded once it acts on the collection
+    grant_collection_manager(
+        &mut app,
+        &new_creator(),
+        &royalty_registry,
+        &collection,
+        None,
+    )
+    .unwrap();
+    let response = collection_creator(&app, &royalty_registry, &collection);
+    assert_eq!(response.recorded_creator, Some(new_creator()));
+    assert_eq!(response.changed, Some(false));
+}
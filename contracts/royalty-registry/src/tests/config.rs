@@ -0,0 +1,154 @@
+use crate::{
+    constants::{
+        MAX_MAX_SHARE_DELTA, MAX_UPDATE_WAIT_PERIOD, MIN_MAX_SHARE_DELTA, MIN_UPDATE_WAIT_PERIOD,
+    },
+    msg::{ConfigBoundsResponse, QueryMsg, SudoMsg},
+    state::Config,
+    tests::setup::{
+        default_config, find_attribute, find_event, instantiate_royalty_registry, query_msg, setup,
+        sudo_msg,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Decimal;
+use sg_multi_test::StargazeApp;
+
+#[test]
+fn try_instantiate_with_out_of_bounds_config() {
+    let mut app = StargazeApp::default();
+
+    let err = instantiate_royalty_registry(
+        &mut app,
+        Config {
+            update_wait_period: 0,
+            ..default_config()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UpdateWaitPeriodOutOfRange {
+            update_wait_period: 0,
+            min: MIN_UPDATE_WAIT_PERIOD,
+            max: MAX_UPDATE_WAIT_PERIOD,
+        }
+    );
+
+    let err = instantiate_royalty_registry(
+        &mut app,
+        Config {
+            max_share_delta: Decimal::zero(),
+            ..default_config()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MaxShareDeltaOutOfRange {
+            max_share_delta: Decimal::zero(),
+            min: MIN_MAX_SHARE_DELTA,
+            max: MAX_MAX_SHARE_DELTA,
+        }
+    );
+
+    let err = instantiate_royalty_registry(
+        &mut app,
+        Config {
+            min_share: Decimal::percent(60),
+            ..default_config()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidShareBounds {
+            min_share: Decimal::percent(60),
+            max_share: Decimal::percent(50),
+        }
+    );
+}
+
+#[test]
+fn try_update_config_with_out_of_bounds_config() {
+    let (mut app, royalty_registry, _) = setup();
+
+    let err = sudo_msg(
+        &mut app,
+        &royalty_registry,
+        &SudoMsg::UpdateConfig {
+            config: Config {
+                update_wait_period: MAX_UPDATE_WAIT_PERIOD + 1,
+                ..default_config()
+            },
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UpdateWaitPeriodOutOfRange {
+            update_wait_period: MAX_UPDATE_WAIT_PERIOD + 1,
+            min: MIN_UPDATE_WAIT_PERIOD,
+            max: MAX_UPDATE_WAIT_PERIOD,
+        }
+    );
+
+    let err = sudo_msg(
+        &mut app,
+        &royalty_registry,
+        &SudoMsg::UpdateConfig {
+            config: Config {
+                max_share_delta: Decimal::percent(60),
+                ..default_config()
+            },
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MaxShareDeltaOutOfRange {
+            max_share_delta: Decimal::percent(60),
+            min: MIN_MAX_SHARE_DELTA,
+            max: MAX_MAX_SHARE_DELTA,
+        }
+    );
+
+    let config: Config = query_msg(&app, &royalty_registry, &QueryMsg::Config {});
+    assert_eq!(config, default_config());
+
+    // Values on the bounds are accepted
+    let response = sudo_msg(
+        &mut app,
+        &royalty_registry,
+        &SudoMsg::UpdateConfig {
+            config: Config {
+                update_wait_period: MIN_UPDATE_WAIT_PERIOD,
+                max_share_delta: MAX_MAX_SHARE_DELTA,
+                ..default_config()
+            },
+        },
+    )
+    .unwrap();
+    let event = find_event(&response, "wasm-update-config").unwrap();
+    assert_eq!(
+        find_attribute(event, "update_wait_period"),
+        Some(MIN_UPDATE_WAIT_PERIOD.to_string())
+    );
+}
+
+#[test]
+fn try_query_config_bounds() {
+    let (app, royalty_registry, _) = setup();
+
+    let config_bounds: ConfigBoundsResponse =
+        query_msg(&app, &royalty_registry, &QueryMsg::ConfigBounds {});
+    assert_eq!(
+        config_bounds,
+        ConfigBoundsResponse {
+            min_update_wait_period: MIN_UPDATE_WAIT_PERIOD,
+            max_update_wait_period: MAX_UPDATE_WAIT_PERIOD,
+            min_max_share_delta: MIN_MAX_SHARE_DELTA,
+            max_max_share_delta: MAX_MAX_SHARE_DELTA,
+        }
+    );
+}
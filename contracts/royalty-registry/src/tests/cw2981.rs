@@ -0,0 +1,76 @@
+use crate::{
+    adapter::{CheckRoyaltiesResponse, RoyaltiesInfoResponse},
+    msg::QueryMsg,
+    tests::setup::{
+        artist, initialize_collection_royalty, protocol, query_msg, register_protocol,
+        royalty_info, setup,
+    },
+};
+
+use cosmwasm_std::{Decimal, Uint128};
+
+#[test]
+fn try_cw2981_royalty_queries() {
+    let (mut app, royalty_registry, collection) = setup();
+
+    let check_royalties: CheckRoyaltiesResponse = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::CheckRoyalties {
+            collection: collection.to_string(),
+            protocol: None,
+        },
+    );
+    assert!(!check_royalties.royalty_payments);
+    assert_eq!(
+        royalty_info(&app, &royalty_registry, collection.as_str(), None).unwrap(),
+        RoyaltiesInfoResponse {
+            address: String::new(),
+            royalty_amount: Uint128::zero(),
+        }
+    );
+
+    initialize_collection_royalty(&mut app, &royalty_registry, &collection);
+    register_protocol(
+        &mut app,
+        &royalty_registry,
+        &protocol(),
+        Decimal::percent(3),
+    );
+
+    let check_royalties: CheckRoyaltiesResponse = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::CheckRoyalties {
+            collection: collection.to_string(),
+            protocol: None,
+        },
+    );
+    assert!(check_royalties.royalty_payments);
+    assert_eq!(
+        royalty_info(&app, &royalty_registry, collection.as_str(), None).unwrap(),
+        RoyaltiesInfoResponse {
+            address: artist().to_string(),
+            royalty_amount: Uint128::new(50),
+        }
+    );
+
+    // The royalty is capped at the max share honored by the protocol
+    assert_eq!(
+        royalty_info(
+            &app,
+            &royalty_registry,
+            collection.as_str(),
+            Some(&protocol())
+        )
+        .unwrap()
+        .royalty_amount,
+        Uint128::new(30)
+    );
+}
+
+#[test]
+fn try_cw2981_royalty_info_for_invalid_collection() {
+    let (app, royalty_registry, _) = setup();
+    assert!(royalty_info(&app, &royalty_registry, "INVALID", None).is_err());
+}
@@ -0,0 +1,181 @@
+use crate::{
+    adapter::CollectionAdapter,
+    msg::{DistributionReceipt, ProtocolFee, QueryMsg},
+    state::RoyaltyDefault,
+    tests::setup::{
+        artist, balance, claim_royalties, distribute_sale, find_attribute, find_event, protocol,
+        query_msg, seller, setup, setup_collection, setup_fair_burn, setup_with_royalty,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::{coin, from_json, Coin, Decimal, Uint128};
+use sg_std::NATIVE_DENOM;
+
+#[test]
+fn try_distribute_sale_pays_royalty() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+
+    let response = distribute_sale(
+        &mut app,
+        &royalty_registry,
+        &collection,
+        None,
+        None,
+        false,
+        coin(1_000_000, NATIVE_DENOM),
+    )
+    .unwrap();
+
+    assert_eq!(balance(&app, &artist()), Uint128::new(50_000));
+    assert_eq!(balance(&app, &seller()), Uint128::new(950_000));
+
+    let receipt: DistributionReceipt = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(receipt.royalty_recipient, Some(artist()));
+    assert_eq!(receipt.royalty, coin(50_000, NATIVE_DENOM));
+    assert!(!receipt.royalty_escrowed);
+    assert_eq!(receipt.seller_proceeds, coin(950_000, NATIVE_DENOM));
+}
+
+#[test]
+fn try_distribute_sale_forwards_protocol_fee() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    let fair_burn = setup_fair_burn(&mut app);
+
+    let response = distribute_sale(
+        &mut app,
+        &royalty_registry,
+        &collection,
+        None,
+        Some(ProtocolFee {
+            fair_burn: fair_burn.to_string(),
+            fee_percent: Decimal::percent(2),
+            recipient: None,
+        }),
+        false,
+        coin(1_000_000, NATIVE_DENOM),
+    )
+    .unwrap();
+
+    let event = find_event(&response, "wasm-fair-burn").unwrap();
+    assert_eq!(
+        find_attribute(event, "burn_amount"),
+        Some("10000".to_string())
+    );
+    assert_eq!(balance(&app, &artist()), Uint128::new(50_000));
+    assert_eq!(balance(&app, &seller()), Uint128::new(930_000));
+
+    let err = distribute_sale(
+        &mut app,
+        &royalty_registry,
+        &collection,
+        None,
+        Some(ProtocolFee {
+            fair_burn: fair_burn.to_string(),
+            fee_percent: Decimal::percent(101),
+            recipient: None,
+        }),
+        false,
+        coin(1_000_000, NATIVE_DENOM),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidProtocolFee {
+            fee_percent: Decimal::percent(101)
+        }
+    );
+}
+
+#[test]
+fn try_distribute_sale_escrows_royalty() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+
+    distribute_sale(
+        &mut app,
+        &royalty_registry,
+        &collection,
+        None,
+        None,
+        true,
+        coin(1_000_000, NATIVE_DENOM),
+    )
+    .unwrap();
+
+    assert_eq!(balance(&app, &artist()), Uint128::zero());
+    assert_eq!(balance(&app, &royalty_registry), Uint128::new(50_000));
+    let royalty_balance: Coin = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::RoyaltyBalance {
+            recipient: artist().to_string(),
+            denom: NATIVE_DENOM.to_string(),
+        },
+    );
+    assert_eq!(royalty_balance, coin(50_000, NATIVE_DENOM));
+
+    claim_royalties(&mut app, &artist(), &royalty_registry, None).unwrap();
+    assert_eq!(balance(&app, &artist()), Uint128::new(50_000));
+
+    let err = claim_royalties(&mut app, &artist(), &royalty_registry, None).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NothingToClaim {
+            recipient: artist()
+        }
+    );
+}
+
+#[test]
+fn try_distribute_sale_initializes_collection() {
+    let (mut app, royalty_registry, collection) = setup();
+
+    let response = distribute_sale(
+        &mut app,
+        &royalty_registry,
+        &collection,
+        None,
+        None,
+        false,
+        coin(1_000_000, NATIVE_DENOM),
+    )
+    .unwrap();
+
+    assert!(find_event(&response, "wasm-initialize-collection-royalty").is_some());
+    assert_eq!(balance(&app, &artist()), Uint128::new(50_000));
+    assert_eq!(balance(&app, &seller()), Uint128::new(950_000));
+
+    let royalty_default: Option<RoyaltyDefault> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::CollectionRoyaltyDefault {
+            collection: collection.to_string(),
+        },
+    );
+    assert_eq!(
+        royalty_default.unwrap().royalty_entry.share,
+        Decimal::percent(5)
+    );
+}
+
+#[test]
+fn try_distribute_sale_without_collection_royalty() {
+    let (mut app, royalty_registry, _) = setup();
+    let collection = setup_collection(&mut app, CollectionAdapter::Cw721, None);
+
+    let response = distribute_sale(
+        &mut app,
+        &royalty_registry,
+        &collection,
+        None,
+        None,
+        false,
+        coin(1_000_000, NATIVE_DENOM),
+    )
+    .unwrap();
+
+    let receipt: DistributionReceipt = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(receipt.royalty_recipient, None);
+    assert_eq!(balance(&app, &seller()), Uint128::new(1_000_000));
+    assert_eq!(balance(&app, &protocol()), Uint128::zero());
+}
@@ -0,0 +1,138 @@
+use crate::{
+    msg::ExecuteMsg,
+    tests::setup::{
+        artist, creator, execute_msg, protocol, register_protocol, royalty_default, seller,
+        setup_with_royalty, update_royalty_default, UPDATE_WAIT_PERIOD,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Decimal;
+
+#[test]
+fn try_structured_royalty_errors() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    register_protocol(
+        &mut app,
+        &royalty_registry,
+        &protocol(),
+        Decimal::percent(10),
+    );
+
+    let err = execute_msg(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &ExecuteMsg::SetCollectionRoyaltyDefault {
+            collection: collection.to_string(),
+            recipient: artist().to_string(),
+            share: Decimal::percent(5),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RoyaltyAlreadyInitialized {
+            collection: collection.clone(),
+            protocol: None,
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "RoyaltyAlreadyInitialized: royalty for collection {} is already initialized",
+            collection
+        )
+    );
+
+    let err = execute_msg(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &ExecuteMsg::UpdateCollectionRoyaltyProtocol {
+            collection: collection.to_string(),
+            protocol: protocol().to_string(),
+            recipient: Some(seller().to_string()),
+            share: None,
+            share_delta: None,
+            decrement: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RoyaltyNotFound {
+            collection: collection.clone(),
+            protocol: Some(protocol()),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "RoyaltyNotFound: royalty for collection {} and protocol {} does not exist",
+            collection,
+            protocol()
+        )
+    );
+
+    let err = execute_msg(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &ExecuteMsg::SetCollectionRoyaltyProtocol {
+            collection: collection.to_string(),
+            protocol: protocol().to_string(),
+            recipient: seller().to_string(),
+            share: Decimal::percent(60),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ShareOutOfRange {
+            share: Decimal::percent(60),
+            min_share: Decimal::zero(),
+            max_share: Decimal::percent(50),
+        }
+    );
+}
+
+#[test]
+fn try_cooldown_active_error() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+
+    update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        false,
+    )
+    .unwrap();
+    let royalty_default = royalty_default(&app, &royalty_registry, &collection);
+    assert_eq!(royalty_default.royalty_entry.share, Decimal::percent(6));
+
+    let err = update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        false,
+    )
+    .unwrap_err();
+    let until = royalty_default
+        .royalty_entry
+        .updated
+        .unwrap()
+        .plus_seconds(UPDATE_WAIT_PERIOD);
+    assert_eq!(err, ContractError::CooldownActive { until });
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "CooldownActive: royalty entry cannot be updated until {}",
+            until
+        )
+    );
+}
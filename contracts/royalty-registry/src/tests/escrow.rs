@@ -0,0 +1,85 @@
+use crate::{
+    tests::setup::{
+        artist, balance, claim_royalties, distribute_sale, find_event, royalty_balances,
+        setup_with_royalty,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::coin;
+use sg_std::NATIVE_DENOM;
+
+const OTHER_DENOM: &str = "uatom";
+
+#[test]
+fn try_claim_escrowed_royalties_by_denom() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    for sale in [coin(1_000_000, NATIVE_DENOM), coin(2_000_000, OTHER_DENOM)] {
+        distribute_sale(
+            &mut app,
+            &royalty_registry,
+            &collection,
+            None,
+            None,
+            true,
+            sale,
+        )
+        .unwrap();
+    }
+
+    assert_eq!(
+        royalty_balances(&app, &royalty_registry, &artist()),
+        vec![coin(100_000, OTHER_DENOM), coin(50_000, NATIVE_DENOM)]
+    );
+
+    let response = claim_royalties(
+        &mut app,
+        &artist(),
+        &royalty_registry,
+        Some(vec![OTHER_DENOM.to_string()]),
+    )
+    .unwrap();
+    assert!(find_event(&response, "wasm-claim-royalties").is_some());
+    assert_eq!(
+        app.wrap()
+            .query_balance(artist(), OTHER_DENOM)
+            .unwrap()
+            .amount
+            .u128(),
+        100_000
+    );
+    assert_eq!(balance(&app, &artist()).u128(), 0);
+    assert_eq!(
+        royalty_balances(&app, &royalty_registry, &artist()),
+        vec![coin(50_000, NATIVE_DENOM)]
+    );
+}
+
+#[test]
+fn try_claim_royalties_without_balance() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    distribute_sale(
+        &mut app,
+        &royalty_registry,
+        &collection,
+        None,
+        None,
+        true,
+        coin(1_000_000, NATIVE_DENOM),
+    )
+    .unwrap();
+
+    let err = claim_royalties(
+        &mut app,
+        &artist(),
+        &royalty_registry,
+        Some(vec![OTHER_DENOM.to_string()]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NothingToClaim {
+            recipient: artist()
+        }
+    );
+}
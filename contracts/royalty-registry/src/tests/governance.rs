@@ -0,0 +1,202 @@
+use crate::{
+    msg::{ExecuteMsg, QueryMsg, RoyaltyPaymentResponse, SudoMsg},
+    state::{RoyaltyAction, RoyaltyDefault, RoyaltyHistoryEntry, RoyaltyLock, RoyaltyLocks},
+    tests::setup::{
+        creator, execute_msg, find_event, initialize_collection_royalty, query_msg,
+        royalty_default, seller, set_royalty_promotion, setup_with_royalty, sudo_msg,
+        update_royalty_default,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Decimal;
+
+#[test]
+fn try_governance_force_set_and_remove_royalty_default() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        false,
+    )
+    .unwrap();
+
+    // Governance bypasses the cooldown and `max_share_delta`
+    let response = sudo_msg(
+        &mut app,
+        &royalty_registry,
+        &SudoMsg::ForceSetCollectionRoyaltyDefault {
+            collection: collection.to_string(),
+            recipient: seller().to_string(),
+            share: Decimal::percent(20),
+        },
+    )
+    .unwrap();
+    assert!(find_event(&response, "wasm-governance-set-collection-royalty-default").is_some());
+
+    let royalty_entry = royalty_default(&app, &royalty_registry, &collection).royalty_entry;
+    assert_eq!(royalty_entry.recipient, seller());
+    assert_eq!(royalty_entry.share, Decimal::percent(20));
+
+    sudo_msg(
+        &mut app,
+        &royalty_registry,
+        &SudoMsg::RemoveCollectionRoyaltyDefault {
+            collection: collection.to_string(),
+        },
+    )
+    .unwrap();
+    let removed: Option<RoyaltyDefault> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::CollectionRoyaltyDefault {
+            collection: collection.to_string(),
+        },
+    );
+    assert_eq!(removed, None);
+
+    let history: Vec<RoyaltyHistoryEntry> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::RoyaltyHistory {
+            collection: collection.to_string(),
+            query_options: None,
+        },
+    );
+    assert_eq!(
+        history
+            .into_iter()
+            .map(|history_entry| history_entry.action)
+            .collect::<Vec<_>>(),
+        vec![
+            RoyaltyAction::Initialize,
+            RoyaltyAction::Update,
+            RoyaltyAction::GovernanceSet,
+            RoyaltyAction::GovernanceRemove,
+        ]
+    );
+}
+
+#[test]
+fn try_governance_remove_royalty_default_clears_promotions_and_locks() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    let end = app.block_info().time.plus_seconds(3_600);
+    set_royalty_promotion(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        None,
+        Decimal::zero(),
+        end,
+    )
+    .unwrap();
+    execute_msg(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &ExecuteMsg::LockCollectionRoyalty {
+            collection: collection.to_string(),
+            default: Some(RoyaltyLock::Full),
+            protocols: Some(RoyaltyLock::IncreasesOnly),
+        },
+    )
+    .unwrap();
+
+    sudo_msg(
+        &mut app,
+        &royalty_registry,
+        &SudoMsg::RemoveCollectionRoyaltyDefault {
+            collection: collection.to_string(),
+        },
+    )
+    .unwrap();
+    initialize_collection_royalty(&mut app, &royalty_registry, &collection);
+
+    // Only the lock on protocol entries remains
+    let royalty_payment: RoyaltyPaymentResponse = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::RoyaltyPayment {
+            collection: collection.to_string(),
+            protocol: None,
+            denom: None,
+        },
+    );
+    assert_eq!(
+        royalty_payment.royalty_default.unwrap().royalty_entry.share,
+        Decimal::percent(5)
+    );
+    assert!(royalty_payment.royalty_promotions.is_empty());
+    assert_eq!(
+        royalty_payment.royalty_locks,
+        RoyaltyLocks {
+            default: None,
+            protocols: Some(RoyaltyLock::IncreasesOnly),
+        }
+    );
+    update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        false,
+    )
+    .unwrap();
+}
+
+#[test]
+fn try_governance_freeze_collection_royalty() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+
+    sudo_msg(
+        &mut app,
+        &royalty_registry,
+        &SudoMsg::FreezeCollectionRoyalty {
+            collection: collection.to_string(),
+        },
+    )
+    .unwrap();
+
+    let err = update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        true,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CollectionFrozen {
+            collection: collection.clone(),
+        }
+    );
+
+    let unfreeze = SudoMsg::UnfreezeCollectionRoyalty {
+        collection: collection.to_string(),
+    };
+    sudo_msg(&mut app, &royalty_registry, &unfreeze).unwrap();
+    let err = sudo_msg(&mut app, &royalty_registry, &unfreeze).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CollectionNotFrozen {
+            collection: collection.clone(),
+        }
+    );
+
+    update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        true,
+    )
+    .unwrap();
+}
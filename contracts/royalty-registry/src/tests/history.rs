@@ -0,0 +1,86 @@
+use crate::{
+    state::RoyaltyAction,
+    tests::setup::{
+        advance_time, creator, royalty_history, setup_with_royalty, update_royalty_default,
+        UPDATE_WAIT_PERIOD,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Decimal;
+use sg_index_query::{QueryBound, QueryOptions};
+
+#[test]
+fn try_royalty_history() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    advance_time(&mut app, UPDATE_WAIT_PERIOD);
+    update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(2),
+        false,
+    )
+    .unwrap();
+
+    let history = royalty_history(&app, &royalty_registry, &collection, None);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].action, RoyaltyAction::Initialize);
+    assert_eq!(history[0].previous, None);
+    assert_eq!(history[1].action, RoyaltyAction::Update);
+    assert_eq!(history[1].actor, creator());
+    assert_eq!(history[1].timestamp, app.block_info().time);
+    assert_eq!(
+        history[1].previous.as_ref().unwrap().share,
+        Decimal::percent(5)
+    );
+    assert_eq!(
+        history[1].current.as_ref().unwrap().share,
+        Decimal::percent(7)
+    );
+
+    // History is paginated by id
+    let page = royalty_history(
+        &app,
+        &royalty_registry,
+        &collection,
+        Some(QueryOptions {
+            descending: None,
+            limit: Some(1),
+            min: Some(QueryBound::Exclusive(history[0].id)),
+            max: None,
+        }),
+    );
+    assert_eq!(page, vec![history[1].clone()]);
+}
+
+#[test]
+fn try_royalty_history_of_failed_update() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(2),
+        false,
+    )
+    .unwrap();
+
+    // Updates rejected during the cooldown are not recorded
+    let err = update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(2),
+        false,
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::CooldownActive { .. }));
+
+    let history = royalty_history(&app, &royalty_registry, &collection, None);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[1].action, RoyaltyAction::Update);
+}
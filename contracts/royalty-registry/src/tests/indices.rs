@@ -0,0 +1,85 @@
+use crate::{
+    adapter::CollectionAdapter,
+    msg::{ExecuteMsg, QueryMsg},
+    state::RoyaltyDefault,
+    tests::setup::{
+        artist, creator, execute_msg, find_attribute, find_event, fund_account,
+        initialize_collection_royalty, query_msg, setup, setup_collection,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::{coin, Decimal};
+use cw_multi_test::Executor;
+use cw_utils::PaymentError;
+use sg_std::NATIVE_DENOM;
+
+#[test]
+fn try_rebuild_royalty_default_indices() {
+    let (mut app, royalty_registry, collection) = setup();
+    let other_collection = setup_collection(
+        &mut app,
+        CollectionAdapter::Sg721,
+        Some(Decimal::percent(5)),
+    );
+    initialize_collection_royalty(&mut app, &royalty_registry, &collection);
+    initialize_collection_royalty(&mut app, &royalty_registry, &other_collection);
+
+    let mut start_after = None;
+    let mut rebuilt = 0;
+    loop {
+        let response = execute_msg(
+            &mut app,
+            &creator(),
+            &royalty_registry,
+            &ExecuteMsg::RebuildRoyaltyDefaultIndices {
+                start_after: start_after.clone(),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+        let event = find_event(&response, "wasm-rebuild-royalty-default-indices").unwrap();
+        let count: usize = find_attribute(event, "count").unwrap().parse().unwrap();
+        if count == 0 {
+            break;
+        }
+        assert_eq!(count, 1);
+        rebuilt += count;
+        start_after = find_attribute(event, "last");
+    }
+    assert_eq!(rebuilt, 2);
+
+    let royalty_defaults: Vec<RoyaltyDefault> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::RoyaltyDefaultsByRecipient {
+            recipient: artist().to_string(),
+            query_options: None,
+        },
+    );
+    assert_eq!(royalty_defaults.len(), 2);
+}
+
+#[test]
+fn try_rebuild_royalty_protocol_indices_with_funds() {
+    let (mut app, royalty_registry, _) = setup();
+    fund_account(&mut app, &creator(), vec![coin(1_000, NATIVE_DENOM)]).unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            creator(),
+            royalty_registry.clone(),
+            &ExecuteMsg::RebuildRoyaltyProtocolIndices {
+                start_after: None,
+                limit: None,
+            },
+            &[coin(1_000, NATIVE_DENOM)],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::PaymentError(PaymentError::NonPayable {})
+    );
+}
@@ -0,0 +1,94 @@
+use crate::{
+    msg::{ExecuteMsg, ResolvedRoyaltyEntry, RoyaltySource},
+    resolve_royalty_entry,
+    tests::setup::{
+        artist, creator, execute_msg, find_event, protocol, register_protocol, seller, setup,
+        setup_with_royalty,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::{
+    testing::{MockApi, MockStorage},
+    Addr, Decimal, Deps, QuerierWrapper,
+};
+use sg_multi_test::StargazeApp;
+
+fn resolve(
+    app: &StargazeApp,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    protocol: Option<&Addr>,
+) -> Result<Option<ResolvedRoyaltyEntry>, ContractError> {
+    let storage = MockStorage::new();
+    let api = MockApi::default();
+    let deps = Deps {
+        storage: &storage,
+        api: &api,
+        querier: QuerierWrapper::new(app),
+    };
+    resolve_royalty_entry(deps, royalty_registry, collection, protocol, None)
+}
+
+#[test]
+fn try_resolve_royalty_entry_provenance() {
+    let (mut app, royalty_registry, collection) = setup();
+    register_protocol(
+        &mut app,
+        &royalty_registry,
+        &protocol(),
+        Decimal::percent(10),
+    );
+
+    let resolved = resolve(&app, &royalty_registry, &collection, None)
+        .unwrap()
+        .unwrap();
+    assert_eq!(resolved.source, RoyaltySource::CollectionFallback);
+    assert_eq!(resolved.royalty_entry.recipient, artist());
+
+    // Initialization is idempotent
+    let initialize = ExecuteMsg::InitializeCollectionRoyalty {
+        collection: collection.to_string(),
+    };
+    let response = execute_msg(&mut app, &seller(), &royalty_registry, &initialize).unwrap();
+    assert!(find_event(&response, "wasm-initialize-collection-royalty").is_some());
+    let response = execute_msg(&mut app, &seller(), &royalty_registry, &initialize).unwrap();
+    assert!(find_event(&response, "wasm-initialize-collection-royalty").is_none());
+
+    let resolved = resolve(&app, &royalty_registry, &collection, Some(&protocol()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(resolved.source, RoyaltySource::RegistryDefault);
+
+    execute_msg(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &ExecuteMsg::SetCollectionRoyaltyProtocol {
+            collection: collection.to_string(),
+            protocol: protocol().to_string(),
+            recipient: seller().to_string(),
+            share: Decimal::percent(2),
+        },
+    )
+    .unwrap();
+    let resolved = resolve(&app, &royalty_registry, &collection, Some(&protocol()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(resolved.source, RoyaltySource::RegistryProtocol);
+    assert_eq!(resolved.royalty_entry.recipient, seller());
+}
+
+#[test]
+fn try_resolve_royalty_entry_for_non_collection() {
+    let (app, royalty_registry, _collection) = setup_with_royalty();
+
+    // The registry is not a collection contract, and has no royalty default
+    let err = resolve(&app, &royalty_registry, &royalty_registry, None).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnsupportedCollection {
+            collection: royalty_registry.clone(),
+        }
+    );
+}
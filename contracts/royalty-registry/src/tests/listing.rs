@@ -0,0 +1,103 @@
+use crate::{
+    adapter::CollectionAdapter,
+    msg::QueryMsg,
+    state::{RoyaltyDefault, RoyaltyProtocol},
+    tests::setup::{
+        creator, initialize_collection_royalty, protocol, query_msg, register_protocol,
+        set_royalty_protocol, setup, setup_collection,
+    },
+};
+
+use cosmwasm_std::{Decimal, StdResult};
+use sg_index_query::QueryOptions;
+
+#[test]
+fn try_list_royalty_entries() {
+    let (mut app, royalty_registry, collection) = setup();
+    let other_collection = setup_collection(
+        &mut app,
+        CollectionAdapter::Sg721,
+        Some(Decimal::percent(5)),
+    );
+    register_protocol(
+        &mut app,
+        &royalty_registry,
+        &protocol(),
+        Decimal::percent(10),
+    );
+    for collection in [&collection, &other_collection] {
+        initialize_collection_royalty(&mut app, &royalty_registry, collection);
+        set_royalty_protocol(
+            &mut app,
+            &creator(),
+            &royalty_registry,
+            collection,
+            Decimal::percent(3),
+        )
+        .unwrap();
+    }
+
+    let royalty_defaults: Vec<RoyaltyDefault> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::AllRoyaltyDefaults {
+            query_options: Some(QueryOptions {
+                descending: Some(true),
+                limit: None,
+                min: None,
+                max: None,
+            }),
+        },
+    );
+    let mut collections = vec![collection.clone(), other_collection.clone()];
+    collections.sort();
+    collections.reverse();
+    assert_eq!(
+        royalty_defaults
+            .into_iter()
+            .map(|royalty_default| royalty_default.collection)
+            .collect::<Vec<_>>(),
+        collections
+    );
+
+    let royalty_protocols: Vec<RoyaltyProtocol> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::AllRoyaltyProtocols {
+            query_options: Some(QueryOptions {
+                descending: None,
+                limit: Some(1),
+                min: None,
+                max: None,
+            }),
+        },
+    );
+    assert_eq!(royalty_protocols.len(), 1);
+
+    let royalty_protocols: Vec<RoyaltyProtocol> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::RoyaltyProtocolsByProtocol {
+            protocol: protocol().to_string(),
+            query_options: None,
+        },
+    );
+    assert_eq!(royalty_protocols.len(), 2);
+    assert!(royalty_protocols
+        .iter()
+        .all(|royalty_protocol| royalty_protocol.protocol == protocol()));
+}
+
+#[test]
+fn try_list_royalty_protocols_by_invalid_protocol() {
+    let (app, royalty_registry, _) = setup();
+
+    let result: StdResult<Vec<RoyaltyProtocol>> = app.wrap().query_wasm_smart(
+        &royalty_registry,
+        &QueryMsg::RoyaltyProtocolsByProtocol {
+            protocol: "INVALID".to_string(),
+            query_options: None,
+        },
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,111 @@
+use crate::{
+    msg::{QueryMsg, RoyaltyPaymentResponse},
+    state::{RoyaltyLock, RoyaltyLocks},
+    tests::setup::{
+        creator, lock_royalty_default, query_msg, seller, setup_with_royalty,
+        update_royalty_default,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Decimal;
+
+#[test]
+fn try_lock_royalty_default_increases() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    lock_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        RoyaltyLock::IncreasesOnly,
+    )
+    .unwrap();
+
+    let royalty_payment_response: RoyaltyPaymentResponse = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::RoyaltyPayment {
+            collection: collection.to_string(),
+            protocol: None,
+            denom: None,
+        },
+    );
+    assert_eq!(
+        royalty_payment_response.royalty_locks,
+        RoyaltyLocks {
+            default: Some(RoyaltyLock::IncreasesOnly),
+            protocols: None,
+        }
+    );
+
+    let err = update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RoyaltyLocked {
+            lock: RoyaltyLock::IncreasesOnly,
+        }
+    );
+
+    update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        true,
+    )
+    .unwrap();
+}
+
+#[test]
+fn try_loosen_royalty_lock() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+
+    let err = lock_royalty_default(
+        &mut app,
+        &seller(),
+        &royalty_registry,
+        &collection,
+        RoyaltyLock::Full,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotCollectionAdmin {
+            sender: seller(),
+            admin: creator(),
+        }
+    );
+
+    lock_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        RoyaltyLock::Full,
+    )
+    .unwrap();
+    let err = lock_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        RoyaltyLock::IncreasesOnly,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RoyaltyLockLoosened {
+            lock: RoyaltyLock::IncreasesOnly,
+        }
+    );
+}
@@ -0,0 +1,131 @@
+use crate::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::CollectionManager,
+    tests::setup::{
+        advance_time, creator, execute_msg, find_event, grant_collection_manager, manager,
+        query_msg, royalty_default, seller, setup_with_royalty, update_royalty_default,
+        UPDATE_WAIT_PERIOD,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Decimal;
+use cw_utils::Expiration;
+
+#[test]
+fn try_collection_manager_updates_royalty() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+
+    let expires = Expiration::AtTime(app.block_info().time.plus_seconds(2 * UPDATE_WAIT_PERIOD));
+    let response = grant_collection_manager(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Some(expires),
+    )
+    .unwrap();
+    assert!(find_event(&response, "wasm-grant-collection-manager").is_some());
+
+    let collection_managers: Vec<CollectionManager> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::CollectionManagers {
+            collection: collection.to_string(),
+            query_options: None,
+        },
+    );
+    assert_eq!(
+        collection_managers,
+        vec![CollectionManager {
+            collection: collection.clone(),
+            manager: manager(),
+            expires,
+        }]
+    );
+
+    advance_time(&mut app, UPDATE_WAIT_PERIOD);
+    update_royalty_default(
+        &mut app,
+        &manager(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        true,
+    )
+    .unwrap();
+    assert_eq!(
+        royalty_default(&app, &royalty_registry, &collection)
+            .royalty_entry
+            .share,
+        Decimal::percent(4)
+    );
+
+    // Managers lose their permissions once expired
+    advance_time(&mut app, UPDATE_WAIT_PERIOD);
+    let err = update_royalty_default(
+        &mut app,
+        &manager(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        true,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotCollectionAdmin {
+            sender: manager(),
+            admin: creator(),
+        }
+    );
+}
+
+#[test]
+fn try_grant_collection_manager_unauthorized() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+
+    let err = grant_collection_manager(&mut app, &seller(), &royalty_registry, &collection, None)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotCollectionAdmin {
+            sender: seller(),
+            admin: creator(),
+        }
+    );
+
+    let expires = Expiration::AtTime(app.block_info().time);
+    let err = grant_collection_manager(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Some(expires),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ManagerExpired { expires });
+}
+
+#[test]
+fn try_revoke_unknown_collection_manager() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+
+    let err = execute_msg(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &ExecuteMsg::RevokeCollectionManager {
+            collection: collection.to_string(),
+            manager: manager().to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ManagerNotFound {
+            collection: collection.clone(),
+            manager: manager(),
+        }
+    );
+}
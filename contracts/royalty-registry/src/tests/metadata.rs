@@ -0,0 +1,164 @@
+use crate::{
+    constants::{MAX_METADATA_LABEL_LENGTH, MAX_METADATA_URL_LENGTH},
+    msg::ExecuteMsg,
+    state::{RoyaltyMetadata, RoyaltyRole},
+    tests::setup::{
+        artist, creator, execute_msg, find_attribute, find_event, protocol, register_protocol,
+        royalty_details, seller, set_royalty_metadata, setup_with_royalty,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Decimal;
+
+#[test]
+fn try_set_royalty_metadata() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    register_protocol(
+        &mut app,
+        &royalty_registry,
+        &protocol(),
+        Decimal::percent(10),
+    );
+    execute_msg(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &ExecuteMsg::SetCollectionRoyaltyProtocol {
+            collection: collection.to_string(),
+            protocol: protocol().to_string(),
+            recipient: seller().to_string(),
+            share: Decimal::percent(2),
+        },
+    )
+    .unwrap();
+
+    let default_metadata = RoyaltyMetadata {
+        label: Some("Artist".to_string()),
+        role: Some(RoyaltyRole::Artist),
+        url: Some("https://example.com/artist".to_string()),
+    };
+    let response = set_royalty_metadata(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        None,
+        Some(default_metadata.clone()),
+    )
+    .unwrap();
+    let event = find_event(&response, "wasm-set-collection-royalty-metadata").unwrap();
+    assert_eq!(find_attribute(event, "label"), Some("Artist".to_string()));
+
+    let protocol_metadata = RoyaltyMetadata {
+        label: Some("Treasury".to_string()),
+        role: Some(RoyaltyRole::Treasury),
+        url: None,
+    };
+    set_royalty_metadata(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Some(&protocol()),
+        Some(protocol_metadata.clone()),
+    )
+    .unwrap();
+
+    let details = royalty_details(&app, &royalty_registry, &collection, Some(&protocol()));
+    assert_eq!(
+        details.royalty_default.unwrap().royalty_entry.recipient,
+        artist()
+    );
+    assert_eq!(details.default_metadata, Some(default_metadata));
+    assert_eq!(
+        details.royalty_protocol.unwrap().royalty_entry.recipient,
+        seller()
+    );
+    assert_eq!(details.protocol_metadata, Some(protocol_metadata));
+
+    // Metadata is removed when none is given
+    set_royalty_metadata(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        None,
+        None,
+    )
+    .unwrap();
+    let details = royalty_details(&app, &royalty_registry, &collection, None);
+    assert!(details.royalty_default.is_some());
+    assert_eq!(details.default_metadata, None);
+    assert_eq!(details.royalty_protocol, None);
+}
+
+#[test]
+fn try_set_royalty_metadata_invalid() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+
+    let err = set_royalty_metadata(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        None,
+        Some(RoyaltyMetadata {
+            label: Some("".to_string()),
+            role: None,
+            url: None,
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidRoyaltyLabel {
+            max_length: MAX_METADATA_LABEL_LENGTH
+        }
+    );
+
+    let err = set_royalty_metadata(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        None,
+        Some(RoyaltyMetadata {
+            label: None,
+            role: None,
+            url: Some("http://example.com".to_string()),
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidRoyaltyUrl {
+            max_length: MAX_METADATA_URL_LENGTH
+        }
+    );
+
+    // Protocol entries must exist before metadata can be attached
+    let err = set_royalty_metadata(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Some(&protocol()),
+        Some(RoyaltyMetadata {
+            label: Some("Treasury".to_string()),
+            role: None,
+            url: None,
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RoyaltyNotFound {
+            collection: collection.clone(),
+            protocol: Some(protocol()),
+        }
+    );
+
+    let details = royalty_details(&app, &royalty_registry, &collection, None);
+    assert_eq!(details.default_metadata, None);
+}
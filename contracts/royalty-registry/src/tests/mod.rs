@@ -0,0 +1,25 @@
+mod adapter;
+mod batch;
+mod claim;
+mod config;
+mod cw2981;
+mod distribute_sale;
+mod errors;
+mod escrow;
+mod governance;
+mod history;
+mod indices;
+mod initialize;
+mod listing;
+mod lock;
+mod manager;
+mod metadata;
+mod promotion;
+mod proposal;
+mod protocol;
+mod resolve;
+mod royalty_payment;
+mod royalty_totals;
+mod setup;
+mod sync;
+mod update_params;
@@ -0,0 +1,114 @@
+use crate::{
+    msg::{QueryMsg, RoyaltyPaymentResponse, SudoMsg},
+    state::Config,
+    tests::setup::{
+        creator, default_config, protocol, query_msg, register_protocol, set_royalty_promotion,
+        setup_with_royalty, sudo_msg,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Decimal;
+
+#[test]
+fn try_stacked_promotions_use_the_lowest_share() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    register_protocol(
+        &mut app,
+        &royalty_registry,
+        &protocol(),
+        Decimal::percent(10),
+    );
+
+    let end = app.block_info().time.plus_seconds(3_600);
+    set_royalty_promotion(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        None,
+        Decimal::zero(),
+        end,
+    )
+    .unwrap();
+    set_royalty_promotion(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Some(&protocol()),
+        Decimal::percent(3),
+        end,
+    )
+    .unwrap();
+
+    let royalty_payment: RoyaltyPaymentResponse = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::RoyaltyPayment {
+            collection: collection.to_string(),
+            protocol: Some(protocol().to_string()),
+            denom: None,
+        },
+    );
+    assert_eq!(
+        royalty_payment.royalty_default.unwrap().royalty_entry.share,
+        Decimal::zero()
+    );
+    assert_eq!(
+        royalty_payment
+            .royalty_protocol
+            .unwrap()
+            .royalty_entry
+            .share,
+        Decimal::zero()
+    );
+    assert_eq!(royalty_payment.royalty_promotions.len(), 2);
+}
+
+#[test]
+fn try_promotion_share_must_be_within_config_bounds() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    sudo_msg(
+        &mut app,
+        &royalty_registry,
+        &SudoMsg::UpdateConfig {
+            config: Config {
+                min_share: Decimal::percent(1),
+                ..default_config()
+            },
+        },
+    )
+    .unwrap();
+
+    let end = app.block_info().time.plus_seconds(3_600);
+    let err = set_royalty_promotion(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        None,
+        Decimal::zero(),
+        end,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ShareOutOfRange {
+            share: Decimal::zero(),
+            min_share: Decimal::percent(1),
+            max_share: Decimal::percent(50),
+        }
+    );
+
+    set_royalty_promotion(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        None,
+        Decimal::percent(1),
+        end,
+    )
+    .unwrap();
+}
@@ -0,0 +1,96 @@
+use crate::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::RoyaltyProposal,
+    tests::setup::{
+        advance_time, apply_royalty_default, artist, creator, execute_msg, propose_royalty_default,
+        query_msg, royalty_default, setup_with_royalty, update_royalty_default, UPDATE_WAIT_PERIOD,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Decimal;
+
+#[test]
+fn try_apply_royalty_default_proposal() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    propose_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(2),
+        false,
+    )
+    .unwrap();
+
+    // The proposal stores the absolute entry it will apply
+    let royalty_proposal: Option<RoyaltyProposal> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::CollectionRoyaltyDefaultProposal {
+            collection: collection.to_string(),
+        },
+    );
+    let royalty_entry = royalty_proposal.unwrap().royalty_entry;
+    assert_eq!(royalty_entry.recipient, artist());
+    assert_eq!(royalty_entry.share, Decimal::percent(7));
+
+    let err =
+        apply_royalty_default(&mut app, &creator(), &royalty_registry, &collection).unwrap_err();
+    assert!(matches!(err, ContractError::ProposalNotReady { .. }));
+
+    advance_time(&mut app, UPDATE_WAIT_PERIOD);
+    apply_royalty_default(&mut app, &creator(), &royalty_registry, &collection).unwrap();
+
+    assert_eq!(
+        royalty_default(&app, &royalty_registry, &collection)
+            .royalty_entry
+            .share,
+        Decimal::percent(7)
+    );
+}
+
+#[test]
+fn try_update_royalty_default_with_pending_proposal() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    propose_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(2),
+        false,
+    )
+    .unwrap();
+    advance_time(&mut app, UPDATE_WAIT_PERIOD);
+
+    let err = update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        true,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ProposalPending {
+            collection: collection.clone(),
+            protocol: None,
+        }
+    );
+
+    execute_msg(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &ExecuteMsg::CancelCollectionRoyaltyDefault {
+            collection: collection.to_string(),
+        },
+    )
+    .unwrap();
+    let err =
+        apply_royalty_default(&mut app, &creator(), &royalty_registry, &collection).unwrap_err();
+    assert!(matches!(err, ContractError::ProposalNotFound { .. }));
+}
@@ -0,0 +1,186 @@
+use crate::{
+    msg::{QueryMsg, SudoMsg},
+    state::RegisteredProtocol,
+    tests::setup::{
+        advance_time, artist, creator, protocol, query_msg, register_protocol, royalty_protocol,
+        set_royalty_protocol, setup_with_royalty, sudo_msg, update_royalty_protocol,
+        UPDATE_WAIT_PERIOD,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Decimal;
+
+#[test]
+fn try_registered_protocol_caps_royalty_entries() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+
+    // Collections can only opt in to registered protocols
+    let err = set_royalty_protocol(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(5),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ProtocolNotRegistered {
+            protocol: protocol(),
+        }
+    );
+
+    register_protocol(
+        &mut app,
+        &royalty_registry,
+        &protocol(),
+        Decimal::percent(10),
+    );
+    let registered_protocol: Option<RegisteredProtocol> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::RegisteredProtocol {
+            protocol: protocol().to_string(),
+        },
+    );
+    assert_eq!(registered_protocol.unwrap().max_share, Decimal::percent(10));
+
+    let err = set_royalty_protocol(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(12),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ShareOutOfRange {
+            share: Decimal::percent(12),
+            min_share: Decimal::zero(),
+            max_share: Decimal::percent(10),
+        }
+    );
+
+    set_royalty_protocol(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(8),
+    )
+    .unwrap();
+    assert_eq!(
+        royalty_protocol(&app, &royalty_registry, &collection)
+            .royalty_entry
+            .share,
+        Decimal::percent(8)
+    );
+
+    // Inactive protocols do not accept share increases
+    sudo_msg(
+        &mut app,
+        &royalty_registry,
+        &SudoMsg::RegisterProtocol {
+            protocol: protocol().to_string(),
+            name: "Protocol".to_string(),
+            url: "https://example.com".to_string(),
+            max_share: Decimal::percent(10),
+            active: false,
+        },
+    )
+    .unwrap();
+    advance_time(&mut app, UPDATE_WAIT_PERIOD);
+    let err = update_royalty_protocol(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ProtocolInactive {
+            protocol: protocol(),
+        }
+    );
+}
+
+#[test]
+fn try_update_unregistered_protocol_entry() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+
+    // Entries set before protocols were registered have no registration
+    sudo_msg(
+        &mut app,
+        &royalty_registry,
+        &SudoMsg::ForceSetCollectionRoyaltyProtocol {
+            collection: collection.to_string(),
+            protocol: protocol().to_string(),
+            recipient: artist().to_string(),
+            share: Decimal::percent(4),
+        },
+    )
+    .unwrap();
+    advance_time(&mut app, UPDATE_WAIT_PERIOD);
+
+    update_royalty_protocol(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        true,
+    )
+    .unwrap();
+    assert_eq!(
+        royalty_protocol(&app, &royalty_registry, &collection)
+            .royalty_entry
+            .share,
+        Decimal::percent(3)
+    );
+    advance_time(&mut app, UPDATE_WAIT_PERIOD);
+
+    let err = update_royalty_protocol(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ProtocolNotRegistered {
+            protocol: protocol(),
+        }
+    );
+
+    register_protocol(
+        &mut app,
+        &royalty_registry,
+        &protocol(),
+        Decimal::percent(3),
+    );
+    let err = update_royalty_protocol(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ShareOutOfRange {
+            share: Decimal::percent(4),
+            min_share: Decimal::zero(),
+            max_share: Decimal::percent(3),
+        }
+    );
+}
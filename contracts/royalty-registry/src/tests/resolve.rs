@@ -0,0 +1,133 @@
+use crate::{
+    msg::{CollectionRoyaltyLocks, QueryMsg, RoyaltyPaymentResponse, RoyaltySource},
+    resolve::{royalty_payment, select_royalty_entry, RoyaltySnapshot},
+    state::{RegisteredProtocol, RoyaltyDefault, RoyaltyLock, RoyaltyPromotion, RoyaltyProtocol},
+    tests::setup::{
+        creator, lock_royalty_default, protocol, query_msg, register_protocol, seller,
+        set_royalty_promotion, setup_with_royalty,
+    },
+};
+
+use cosmwasm_std::{Addr, Decimal};
+use sg_multi_test::StargazeApp;
+
+/// Builds a snapshot of the registry from its list queries.
+fn snapshot(app: &StargazeApp, royalty_registry: &Addr) -> RoyaltySnapshot {
+    let mut royalty_promotions: Vec<RoyaltyPromotion> = query_msg(
+        app,
+        royalty_registry,
+        &QueryMsg::AllRoyaltyDefaultPromotions {
+            query_options: None,
+        },
+    );
+    royalty_promotions.extend(query_msg::<Vec<RoyaltyPromotion>>(
+        app,
+        royalty_registry,
+        &QueryMsg::AllRoyaltyProtocolPromotions {
+            query_options: None,
+        },
+    ));
+
+    RoyaltySnapshot {
+        royalty_defaults: query_msg::<Vec<RoyaltyDefault>>(
+            app,
+            royalty_registry,
+            &QueryMsg::AllRoyaltyDefaults {
+                query_options: None,
+            },
+        ),
+        royalty_protocols: query_msg::<Vec<RoyaltyProtocol>>(
+            app,
+            royalty_registry,
+            &QueryMsg::AllRoyaltyProtocols {
+                query_options: None,
+            },
+        ),
+        registered_protocols: query_msg::<Vec<RegisteredProtocol>>(
+            app,
+            royalty_registry,
+            &QueryMsg::RegisteredProtocols {
+                query_options: None,
+            },
+        ),
+        royalty_promotions,
+        royalty_locks: query_msg::<Vec<CollectionRoyaltyLocks>>(
+            app,
+            royalty_registry,
+            &QueryMsg::AllRoyaltyLocks {
+                query_options: None,
+            },
+        ),
+        block_time: Some(app.block_info().time),
+    }
+}
+
+#[test]
+fn try_resolve_royalty_from_snapshot() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    register_protocol(
+        &mut app,
+        &royalty_registry,
+        &protocol(),
+        Decimal::percent(10),
+    );
+    let end = app.block_info().time.plus_seconds(3_600);
+    set_royalty_promotion(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Some(&protocol()),
+        Decimal::percent(3),
+        end,
+    )
+    .unwrap();
+    lock_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        RoyaltyLock::IncreasesOnly,
+    )
+    .unwrap();
+
+    let royalty_payment_response: RoyaltyPaymentResponse = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::RoyaltyPayment {
+            collection: collection.to_string(),
+            protocol: Some(protocol().to_string()),
+            denom: None,
+        },
+    );
+
+    let snapshot = snapshot(&app, &royalty_registry);
+    let block_time = snapshot.block_time.unwrap();
+    assert_eq!(
+        royalty_payment(
+            snapshot.payment_state(&collection, Some(&protocol())),
+            block_time,
+            None,
+        ),
+        royalty_payment_response
+    );
+
+    let resolved_royalty_entry = snapshot
+        .resolve(&collection, Some(&protocol()), None, block_time)
+        .unwrap();
+    assert_eq!(
+        Some(resolved_royalty_entry.clone()),
+        select_royalty_entry(royalty_payment_response)
+    );
+    assert_eq!(
+        resolved_royalty_entry.source,
+        RoyaltySource::RegistryProtocol
+    );
+    assert_eq!(
+        resolved_royalty_entry.royalty_entry.share,
+        Decimal::percent(3)
+    );
+
+    // Collections that are not in the snapshot resolve to no royalty
+    assert_eq!(snapshot.resolve(&seller(), None, None, block_time), None);
+}
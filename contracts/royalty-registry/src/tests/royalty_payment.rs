@@ -0,0 +1,100 @@
+use crate::{
+    fetch_royalty_entry, fetch_royalty_entry_for_denom,
+    msg::{ExecuteMsg, QueryMsg},
+    tests::setup::{creator, execute_msg, setup_with_royalty},
+    ContractError,
+};
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Timestamp};
+
+/// The royalty types as defined before denom shares, update params, locks and
+/// promotions were added, which reject unknown fields.
+#[cw_serde]
+struct LegacyRoyaltyEntry {
+    recipient: Addr,
+    share: Decimal,
+    updated: Option<Timestamp>,
+}
+
+#[cw_serde]
+struct LegacyRoyaltyDefault {
+    collection: Addr,
+    royalty_entry: LegacyRoyaltyEntry,
+}
+
+#[cw_serde]
+struct LegacyRoyaltyPaymentResponse {
+    royalty_default: Option<LegacyRoyaltyDefault>,
+    royalty_protocol: Option<LegacyRoyaltyDefault>,
+}
+
+#[test]
+fn try_legacy_royalty_payment_response() {
+    let (app, royalty_registry, collection) = setup_with_royalty();
+
+    let royalty_payment: LegacyRoyaltyPaymentResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &royalty_registry,
+            &QueryMsg::RoyaltyPayment {
+                collection: collection.to_string(),
+                protocol: None,
+                denom: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        royalty_payment.royalty_default.unwrap().royalty_entry.share,
+        Decimal::percent(5)
+    );
+}
+
+#[test]
+fn try_fetch_royalty_entry_for_denom() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+
+    execute_msg(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &ExecuteMsg::SetCollectionRoyaltyDenomShare {
+            collection: collection.to_string(),
+            protocol: None,
+            denom: "uatom".to_string(),
+            share: Some(Decimal::percent(3)),
+        },
+    )
+    .unwrap();
+
+    let royalty_entry = fetch_royalty_entry_for_denom(
+        &app.wrap(),
+        &royalty_registry,
+        &collection,
+        None,
+        Some("uatom"),
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(royalty_entry.share, Decimal::percent(3));
+
+    // Fetching without a denom ignores denom shares
+    let royalty_entry = fetch_royalty_entry(&app.wrap(), &royalty_registry, &collection, None)
+        .unwrap()
+        .unwrap();
+    assert_eq!(royalty_entry.share, Decimal::percent(5));
+
+    let err = execute_msg(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &ExecuteMsg::SetCollectionRoyaltyDenomShare {
+            collection: collection.to_string(),
+            protocol: None,
+            denom: "uosmo".to_string(),
+            share: Some(Decimal::percent(10)),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::CooldownActive { .. }));
+}
@@ -0,0 +1,195 @@
+use crate::{
+    constants::ROYALTY_STATS_PERIOD,
+    msg::QueryMsg,
+    state::RoyaltyPeriod,
+    tests::setup::{
+        artist, collection_royalty_totals, distribute_sale, find_event, protocol, query_msg,
+        record_royalty_payment, register_protocol, seller, setup, setup_with_royalty,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::{coin, Coin, Decimal, Timestamp};
+use sg_std::NATIVE_DENOM;
+
+#[test]
+fn try_record_royalty_payment() {
+    let (mut app, royalty_registry, collection) = setup();
+    register_protocol(
+        &mut app,
+        &royalty_registry,
+        &protocol(),
+        Decimal::percent(10),
+    );
+
+    let response = record_royalty_payment(
+        &mut app,
+        &protocol(),
+        &royalty_registry,
+        &collection,
+        coin(1_000, NATIVE_DENOM),
+    )
+    .unwrap();
+    assert!(find_event(&response, "wasm-record-royalty-payment").is_some());
+    record_royalty_payment(
+        &mut app,
+        &protocol(),
+        &royalty_registry,
+        &collection,
+        coin(500, NATIVE_DENOM),
+    )
+    .unwrap();
+    record_royalty_payment(
+        &mut app,
+        &protocol(),
+        &royalty_registry,
+        &collection,
+        coin(200, "uatom"),
+    )
+    .unwrap();
+
+    let expected = vec![coin(200, "uatom"), coin(1_500, NATIVE_DENOM)];
+    let collection_totals = collection_royalty_totals(&app, &royalty_registry, &collection);
+    assert_eq!(collection_totals, expected);
+    let recipient_totals: Vec<Coin> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::RecipientRoyaltyTotals {
+            recipient: artist().to_string(),
+            query_options: None,
+        },
+    );
+    assert_eq!(recipient_totals, expected);
+    let protocol_totals: Vec<Coin> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::ProtocolRoyaltyTotals {
+            protocol: protocol().to_string(),
+            query_options: None,
+        },
+    );
+    assert_eq!(protocol_totals, expected);
+
+    let block_time = app.block_info().time;
+    let royalty_periods: Vec<RoyaltyPeriod> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::CollectionRoyaltyPeriods {
+            collection: collection.to_string(),
+            query_options: None,
+        },
+    );
+    assert_eq!(royalty_periods.len(), 1);
+    assert_eq!(
+        royalty_periods[0].period_start,
+        Timestamp::from_seconds(block_time.seconds() - block_time.seconds() % ROYALTY_STATS_PERIOD)
+    );
+    assert_eq!(
+        royalty_periods[0].amounts,
+        vec![coin(1_500, NATIVE_DENOM), coin(200, "uatom")]
+    );
+}
+
+#[test]
+fn try_record_royalty_payment_invalid() {
+    let (mut app, royalty_registry, collection) = setup();
+    register_protocol(
+        &mut app,
+        &royalty_registry,
+        &protocol(),
+        Decimal::percent(10),
+    );
+
+    // Only registered protocols can record payments
+    let err = record_royalty_payment(
+        &mut app,
+        &seller(),
+        &royalty_registry,
+        &collection,
+        coin(1_000, NATIVE_DENOM),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ProtocolNotRegistered { protocol: seller() }
+    );
+
+    let err = record_royalty_payment(
+        &mut app,
+        &protocol(),
+        &royalty_registry,
+        &collection,
+        coin(0, NATIVE_DENOM),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ZeroRoyaltyPayment {});
+
+    let collection_totals = collection_royalty_totals(&app, &royalty_registry, &collection);
+    assert!(collection_totals.is_empty());
+}
+
+#[test]
+fn try_distribute_sale_records_royalty_payment() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    let sale = coin(1_000_000, NATIVE_DENOM);
+
+    // Sales are not recorded for unregistered protocols
+    distribute_sale(
+        &mut app,
+        &royalty_registry,
+        &collection,
+        Some(&protocol()),
+        None,
+        false,
+        sale.clone(),
+    )
+    .unwrap();
+    assert!(collection_royalty_totals(&app, &royalty_registry, &collection).is_empty());
+
+    // Nor when the sender does not distribute on its own behalf
+    register_protocol(
+        &mut app,
+        &royalty_registry,
+        &protocol(),
+        Decimal::percent(10),
+    );
+    register_protocol(&mut app, &royalty_registry, &seller(), Decimal::percent(10));
+    for claimed_protocol in [None, Some(&seller())] {
+        distribute_sale(
+            &mut app,
+            &royalty_registry,
+            &collection,
+            claimed_protocol,
+            None,
+            false,
+            sale.clone(),
+        )
+        .unwrap();
+    }
+    assert!(collection_royalty_totals(&app, &royalty_registry, &collection).is_empty());
+
+    distribute_sale(
+        &mut app,
+        &royalty_registry,
+        &collection,
+        Some(&protocol()),
+        None,
+        false,
+        sale,
+    )
+    .unwrap();
+    let expected = vec![coin(50_000, NATIVE_DENOM)];
+    assert_eq!(
+        collection_royalty_totals(&app, &royalty_registry, &collection),
+        expected
+    );
+    let protocol_totals: Vec<Coin> = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::ProtocolRoyaltyTotals {
+            protocol: protocol().to_string(),
+            query_options: None,
+        },
+    );
+    assert_eq!(protocol_totals, expected);
+}
@@ -0,0 +1,854 @@
+use crate::{
+    adapter::{CheckRoyaltiesResponse, CollectionAdapter, Cw2981QueryMsg, RoyaltiesInfoResponse},
+    execute::execute,
+    instantiate::instantiate,
+    msg::{
+        CollectionCreatorResponse, ExecuteMsg, InstantiateMsg, ProtocolFee, QueryMsg,
+        RoyaltyDetailsResponse, RoyaltyOperation, SudoMsg,
+    },
+    query::query,
+    state::{
+        Config, RoyaltyDefault, RoyaltyHistoryEntry, RoyaltyLock, RoyaltyMetadata, RoyaltyProtocol,
+        UpdateParams,
+    },
+    sudo::sudo,
+    ContractError,
+};
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Coin, Decimal, Deps, DepsMut, Env, Event, MessageInfo, StdError,
+    StdResult, Timestamp, Uint128,
+};
+use cw721::{ContractInfoResponse, TokensResponse};
+use cw_multi_test::{
+    AppResponse, BankSudo, Contract, ContractWrapper, Executor, SudoMsg as CwSudoMsg, WasmSudo,
+};
+use cw_storage_plus::Item;
+use cw_utils::Expiration;
+use sg721::RoyaltyInfoResponse;
+use sg721_base::msg::CollectionInfoResponse;
+use sg_index_query::QueryOptions;
+use sg_multi_test::StargazeApp;
+use sg_std::{Response, StargazeMsgWrapper, NATIVE_DENOM};
+
+pub const UPDATE_WAIT_PERIOD: u64 = 86_400;
+
+pub fn creator() -> Addr {
+    Addr::unchecked("creator")
+}
+
+pub fn artist() -> Addr {
+    Addr::unchecked("artist")
+}
+
+pub fn protocol() -> Addr {
+    Addr::unchecked("protocol")
+}
+
+pub fn seller() -> Addr {
+    Addr::unchecked("seller")
+}
+
+pub fn manager() -> Addr {
+    Addr::unchecked("manager")
+}
+
+pub fn new_creator() -> Addr {
+    Addr::unchecked("new_creator")
+}
+
+pub fn default_config() -> Config {
+    Config {
+        update_wait_period: UPDATE_WAIT_PERIOD,
+        max_share_delta: Decimal::percent(2),
+        min_share: Decimal::zero(),
+        max_share: Decimal::percent(50),
+    }
+}
+
+fn royalty_registry_contract() -> Box<dyn Contract<StargazeMsgWrapper>> {
+    let contract = ContractWrapper::new(execute, instantiate, query).with_sudo(sudo);
+    Box::new(contract)
+}
+
+fn fair_burn_contract() -> Box<dyn Contract<StargazeMsgWrapper>> {
+    let contract = ContractWrapper::new(
+        stargaze_fair_burn::execute::execute,
+        stargaze_fair_burn::instantiate::instantiate,
+        stargaze_fair_burn::query::query,
+    );
+    Box::new(contract)
+}
+
+/// A collection contract that answers the queries of the given adapter, with a royalty
+/// and creator that can be changed by anyone.
+#[cw_serde]
+pub struct MockCollection {
+    pub adapter: CollectionAdapter,
+    pub creator: String,
+    pub royalty: Option<RoyaltyInfoResponse>,
+    pub tokens: Vec<String>,
+}
+
+#[cw_serde]
+pub enum MockCollectionExecuteMsg {
+    SetRoyalty {
+        royalty: Option<RoyaltyInfoResponse>,
+    },
+    SetCreator {
+        creator: String,
+    },
+}
+
+#[cw_serde]
+pub enum MockCollectionQueryMsg {
+    CollectionInfo {},
+    ContractInfo {},
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    Extension {
+        msg: Cw2981QueryMsg,
+    },
+}
+
+const MOCK_COLLECTION: Item<MockCollection> = Item::new("mock_collection");
+
+fn mock_collection_instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: MockCollection,
+) -> StdResult<Response> {
+    MOCK_COLLECTION.save(deps.storage, &msg)?;
+    Ok(Response::new())
+}
+
+fn mock_collection_execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: MockCollectionExecuteMsg,
+) -> StdResult<Response> {
+    let mut mock_collection = MOCK_COLLECTION.load(deps.storage)?;
+    match msg {
+        MockCollectionExecuteMsg::SetRoyalty { royalty } => mock_collection.royalty = royalty,
+        MockCollectionExecuteMsg::SetCreator { creator } => mock_collection.creator = creator,
+    }
+    MOCK_COLLECTION.save(deps.storage, &mock_collection)?;
+    Ok(Response::new())
+}
+
+fn mock_collection_query(deps: Deps, _env: Env, msg: MockCollectionQueryMsg) -> StdResult<Binary> {
+    let mock_collection = MOCK_COLLECTION.load(deps.storage)?;
+    let unsupported = || Err(StdError::generic_err("unsupported query"));
+
+    match (&mock_collection.adapter, msg) {
+        (CollectionAdapter::Sg721, MockCollectionQueryMsg::CollectionInfo {}) => {
+            to_json_binary(&CollectionInfoResponse {
+                creator: mock_collection.creator,
+                description: "Mock collection".to_string(),
+                image: "https://example.com/image.png".to_string(),
+                external_link: None,
+                explicit_content: None,
+                start_trading_time: None,
+                royalty_info: mock_collection.royalty,
+            })
+        }
+        (CollectionAdapter::Cw2981, MockCollectionQueryMsg::Extension { msg }) => match msg {
+            Cw2981QueryMsg::CheckRoyalties {} => to_json_binary(&CheckRoyaltiesResponse {
+                royalty_payments: mock_collection.royalty.is_some(),
+            }),
+            Cw2981QueryMsg::RoyaltyInfo { sale_price, .. } => {
+                let royalty_info = mock_collection.royalty.unwrap_or(RoyaltyInfoResponse {
+                    payment_address: String::new(),
+                    share: Decimal::zero(),
+                });
+                to_json_binary(&RoyaltiesInfoResponse {
+                    address: royalty_info.payment_address,
+                    royalty_amount: sale_price.mul_floor(royalty_info.share),
+                })
+            }
+        },
+        (_, MockCollectionQueryMsg::ContractInfo {}) => to_json_binary(&ContractInfoResponse {
+            name: "Mock collection".to_string(),
+            symbol: "MOCK".to_string(),
+        }),
+        (_, MockCollectionQueryMsg::AllTokens { limit, .. }) => to_json_binary(&TokensResponse {
+            tokens: mock_collection
+                .tokens
+                .into_iter()
+                .take(limit.unwrap_or(10) as usize)
+                .collect(),
+        }),
+        _ => unsupported(),
+    }
+}
+
+fn mock_collection_contract() -> Box<dyn Contract<StargazeMsgWrapper>> {
+    let contract = ContractWrapper::new(
+        mock_collection_execute,
+        mock_collection_instantiate,
+        mock_collection_query,
+    );
+    Box::new(contract)
+}
+
+pub fn fund_account(app: &mut StargazeApp, addr: &Addr, balances: Vec<Coin>) -> StdResult<()> {
+    app.sudo(CwSudoMsg::Bank({
+        BankSudo::Mint {
+            to_address: addr.to_string(),
+            amount: balances,
+        }
+    }))
+    .unwrap();
+
+    Ok(())
+}
+
+pub fn find_event<'a>(response: &'a AppResponse, event_type: &'a str) -> Option<&'a Event> {
+    response.events.iter().find(|event| event.ty == event_type)
+}
+
+pub fn find_attribute(event: &Event, key: &str) -> Option<String> {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.clone())
+}
+
+pub fn balance(app: &StargazeApp, addr: &Addr) -> Uint128 {
+    app.wrap().query_balance(addr, NATIVE_DENOM).unwrap().amount
+}
+
+pub fn advance_time(app: &mut StargazeApp, seconds: u64) {
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(seconds);
+        block.height += seconds / 5;
+    });
+}
+
+/// Instantiates the royalty registry with the given config.
+pub fn instantiate_royalty_registry(
+    app: &mut StargazeApp,
+    config: Config,
+) -> Result<Addr, ContractError> {
+    let royalty_registry_id = app.store_code(royalty_registry_contract());
+    app.instantiate_contract(
+        royalty_registry_id,
+        creator(),
+        &InstantiateMsg { config },
+        &[],
+        "RoyaltyRegistry",
+        None,
+    )
+    .map_err(|err| err.downcast().unwrap())
+}
+
+pub fn setup_royalty_registry(app: &mut StargazeApp, config: Config) -> Addr {
+    instantiate_royalty_registry(app, config).unwrap()
+}
+
+pub fn setup_fair_burn(app: &mut StargazeApp) -> Addr {
+    let fair_burn_id = app.store_code(fair_burn_contract());
+    app.instantiate_contract(
+        fair_burn_id,
+        creator(),
+        &stargaze_fair_burn::msg::InstantiateMsg {
+            fee_bps: 5000,
+            fee_manager: "fee_manager".to_string(),
+        },
+        &[],
+        "FairBurn",
+        None,
+    )
+    .unwrap()
+}
+
+/// Instantiates a collection administered by the creator, with a royalty paid to the
+/// artist if a share is given.
+pub fn setup_collection(
+    app: &mut StargazeApp,
+    adapter: CollectionAdapter,
+    share: Option<Decimal>,
+) -> Addr {
+    setup_collection_with_tokens(app, adapter, share, vec!["1".to_string()])
+}
+
+pub fn setup_collection_with_tokens(
+    app: &mut StargazeApp,
+    adapter: CollectionAdapter,
+    share: Option<Decimal>,
+    tokens: Vec<String>,
+) -> Addr {
+    let collection_id = app.store_code(mock_collection_contract());
+    app.instantiate_contract(
+        collection_id,
+        creator(),
+        &MockCollection {
+            adapter,
+            creator: creator().to_string(),
+            royalty: share.map(|share| RoyaltyInfoResponse {
+                payment_address: artist().to_string(),
+                share,
+            }),
+            tokens,
+        },
+        &[],
+        "Collection",
+        Some(creator().to_string()),
+    )
+    .unwrap()
+}
+
+/// Instantiates the royalty registry and an sg721 collection with a 5% royalty.
+pub fn setup() -> (StargazeApp, Addr, Addr) {
+    let mut app = StargazeApp::default();
+    let royalty_registry = setup_royalty_registry(&mut app, default_config());
+    let collection = setup_collection(
+        &mut app,
+        CollectionAdapter::Sg721,
+        Some(Decimal::percent(5)),
+    );
+    (app, royalty_registry, collection)
+}
+
+/// Runs [`setup`] and initializes the royalty default of the collection.
+pub fn setup_with_royalty() -> (StargazeApp, Addr, Addr) {
+    let (mut app, royalty_registry, collection) = setup();
+    initialize_collection_royalty(&mut app, &royalty_registry, &collection);
+    (app, royalty_registry, collection)
+}
+
+/// Transfers the mock collection to a new creator.
+pub fn set_collection_creator(app: &mut StargazeApp, collection: &Addr, admin: &Addr) {
+    app.execute_contract(
+        creator(),
+        collection.clone(),
+        &MockCollectionExecuteMsg::SetCreator {
+            creator: admin.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+/// Sets the royalty of the mock collection, paid to the artist.
+pub fn set_collection_royalty(app: &mut StargazeApp, collection: &Addr, share: Decimal) {
+    app.execute_contract(
+        creator(),
+        collection.clone(),
+        &MockCollectionExecuteMsg::SetRoyalty {
+            royalty: Some(RoyaltyInfoResponse {
+                payment_address: artist().to_string(),
+                share,
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+pub fn execute_msg(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    msg: &ExecuteMsg,
+) -> Result<AppResponse, ContractError> {
+    app.execute_contract(sender.clone(), royalty_registry.clone(), msg, &[])
+        .map_err(|err| err.downcast().unwrap())
+}
+
+pub fn sudo_msg(
+    app: &mut StargazeApp,
+    royalty_registry: &Addr,
+    msg: &SudoMsg,
+) -> Result<AppResponse, ContractError> {
+    app.sudo(CwSudoMsg::Wasm(WasmSudo {
+        contract_addr: royalty_registry.clone(),
+        msg: to_json_binary(msg).unwrap(),
+    }))
+    .map_err(|err| err.downcast().unwrap())
+}
+
+pub fn query_msg<T: serde::de::DeserializeOwned>(
+    app: &StargazeApp,
+    royalty_registry: &Addr,
+    msg: &QueryMsg,
+) -> T {
+    app.wrap().query_wasm_smart(royalty_registry, msg).unwrap()
+}
+
+pub fn initialize_collection_royalty(
+    app: &mut StargazeApp,
+    royalty_registry: &Addr,
+    collection: &Addr,
+) {
+    execute_msg(
+        app,
+        &creator(),
+        royalty_registry,
+        &ExecuteMsg::InitializeCollectionRoyalty {
+            collection: collection.to_string(),
+        },
+    )
+    .unwrap();
+}
+
+pub fn register_protocol(
+    app: &mut StargazeApp,
+    royalty_registry: &Addr,
+    protocol: &Addr,
+    max_share: Decimal,
+) {
+    sudo_msg(
+        app,
+        royalty_registry,
+        &SudoMsg::RegisterProtocol {
+            protocol: protocol.to_string(),
+            name: "Protocol".to_string(),
+            url: "https://example.com".to_string(),
+            max_share,
+            active: true,
+        },
+    )
+    .unwrap();
+}
+
+pub fn update_royalty_default(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    share_delta: Decimal,
+    decrement: bool,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::UpdateCollectionRoyaltyDefault {
+            collection: collection.to_string(),
+            recipient: None,
+            share: None,
+            share_delta: Some(share_delta),
+            decrement: Some(decrement),
+        },
+    )
+}
+
+pub fn royalty_default(
+    app: &StargazeApp,
+    royalty_registry: &Addr,
+    collection: &Addr,
+) -> RoyaltyDefault {
+    let royalty_default: Option<RoyaltyDefault> = query_msg(
+        app,
+        royalty_registry,
+        &QueryMsg::CollectionRoyaltyDefault {
+            collection: collection.to_string(),
+        },
+    );
+    royalty_default.unwrap()
+}
+
+/// Sets the protocol entry of the collection, paid to the artist.
+pub fn set_royalty_protocol(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    share: Decimal,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::SetCollectionRoyaltyProtocol {
+            collection: collection.to_string(),
+            protocol: protocol().to_string(),
+            recipient: artist().to_string(),
+            share,
+        },
+    )
+}
+
+pub fn update_royalty_protocol(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    share_delta: Decimal,
+    decrement: bool,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::UpdateCollectionRoyaltyProtocol {
+            collection: collection.to_string(),
+            protocol: protocol().to_string(),
+            recipient: None,
+            share: None,
+            share_delta: Some(share_delta),
+            decrement: Some(decrement),
+        },
+    )
+}
+
+pub fn royalty_protocol(
+    app: &StargazeApp,
+    royalty_registry: &Addr,
+    collection: &Addr,
+) -> RoyaltyProtocol {
+    let royalty_protocol: Option<RoyaltyProtocol> = query_msg(
+        app,
+        royalty_registry,
+        &QueryMsg::CollectionRoyaltyProtocol {
+            collection: collection.to_string(),
+            protocol: protocol().to_string(),
+        },
+    );
+    royalty_protocol.unwrap()
+}
+
+pub fn propose_royalty_default(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    share_delta: Decimal,
+    decrement: bool,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::ProposeCollectionRoyaltyDefault {
+            collection: collection.to_string(),
+            recipient: None,
+            share: None,
+            share_delta: Some(share_delta),
+            decrement: Some(decrement),
+        },
+    )
+}
+
+pub fn apply_royalty_default(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::ApplyCollectionRoyaltyDefault {
+            collection: collection.to_string(),
+        },
+    )
+}
+
+/// Sets a promotion starting at the current block time.
+pub fn set_royalty_promotion(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    protocol: Option<&Addr>,
+    share: Decimal,
+    end: Timestamp,
+) -> Result<AppResponse, ContractError> {
+    let start = app.block_info().time;
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::SetCollectionRoyaltyPromotion {
+            collection: collection.to_string(),
+            protocol: protocol.map(|protocol| protocol.to_string()),
+            share,
+            start,
+            end,
+        },
+    )
+}
+
+pub fn set_royalty_update_params(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    update_params: UpdateParams,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::SetCollectionRoyaltyUpdateParams {
+            collection: collection.to_string(),
+            update_params,
+        },
+    )
+}
+
+pub fn lock_royalty_default(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    lock: RoyaltyLock,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::LockCollectionRoyalty {
+            collection: collection.to_string(),
+            default: Some(lock),
+            protocols: None,
+        },
+    )
+}
+
+pub fn set_royalty_metadata(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    protocol: Option<&Addr>,
+    metadata: Option<RoyaltyMetadata>,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::SetCollectionRoyaltyMetadata {
+            collection: collection.to_string(),
+            protocol: protocol.map(|protocol| protocol.to_string()),
+            metadata,
+        },
+    )
+}
+
+pub fn royalty_details(
+    app: &StargazeApp,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    protocol: Option<&Addr>,
+) -> RoyaltyDetailsResponse {
+    query_msg(
+        app,
+        royalty_registry,
+        &QueryMsg::CollectionRoyaltyDetails {
+            collection: collection.to_string(),
+            protocol: protocol.map(|protocol| protocol.to_string()),
+        },
+    )
+}
+
+/// Grants the manager management of the collection.
+pub fn grant_collection_manager(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    expires: Option<Expiration>,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::GrantCollectionManager {
+            collection: collection.to_string(),
+            manager: manager().to_string(),
+            expires,
+        },
+    )
+}
+
+/// Claims the collection, paying the royalty default to the seller.
+pub fn claim_collection_royalty(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::ClaimCollectionRoyalty {
+            collection: collection.to_string(),
+            recipient: Some(seller().to_string()),
+        },
+    )
+}
+
+pub fn collection_creator(
+    app: &StargazeApp,
+    royalty_registry: &Addr,
+    collection: &Addr,
+) -> CollectionCreatorResponse {
+    query_msg(
+        app,
+        royalty_registry,
+        &QueryMsg::CollectionCreator {
+            collection: collection.to_string(),
+        },
+    )
+}
+
+pub fn sync_collection_royalty(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::SyncCollectionRoyalty {
+            collection: collection.to_string(),
+        },
+    )
+}
+
+pub fn batch_collection_royalty(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    operations: Vec<RoyaltyOperation>,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::BatchCollectionRoyalty { operations },
+    )
+}
+
+pub fn royalty_history(
+    app: &StargazeApp,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    query_options: Option<QueryOptions<u64>>,
+) -> Vec<RoyaltyHistoryEntry> {
+    query_msg(
+        app,
+        royalty_registry,
+        &QueryMsg::RoyaltyHistory {
+            collection: collection.to_string(),
+            query_options,
+        },
+    )
+}
+
+/// Queries the CW2981 royalty of a sale of token "1" for 1000.
+pub fn royalty_info(
+    app: &StargazeApp,
+    royalty_registry: &Addr,
+    collection: &str,
+    protocol: Option<&Addr>,
+) -> StdResult<RoyaltiesInfoResponse> {
+    app.wrap().query_wasm_smart(
+        royalty_registry,
+        &QueryMsg::RoyaltyInfo {
+            collection: collection.to_string(),
+            protocol: protocol.map(|protocol| protocol.to_string()),
+            token_id: "1".to_string(),
+            sale_price: Uint128::new(1_000),
+        },
+    )
+}
+
+/// Records a royalty payment to the artist.
+pub fn record_royalty_payment(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    amount: Coin,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::RecordRoyaltyPayment {
+            collection: collection.to_string(),
+            recipient: artist().to_string(),
+            amount,
+        },
+    )
+}
+
+pub fn collection_royalty_totals(
+    app: &StargazeApp,
+    royalty_registry: &Addr,
+    collection: &Addr,
+) -> Vec<Coin> {
+    query_msg(
+        app,
+        royalty_registry,
+        &QueryMsg::CollectionRoyaltyTotals {
+            collection: collection.to_string(),
+            query_options: None,
+        },
+    )
+}
+
+pub fn claim_royalties(
+    app: &mut StargazeApp,
+    sender: &Addr,
+    royalty_registry: &Addr,
+    denoms: Option<Vec<String>>,
+) -> Result<AppResponse, ContractError> {
+    execute_msg(
+        app,
+        sender,
+        royalty_registry,
+        &ExecuteMsg::ClaimRoyalties { denoms },
+    )
+}
+
+pub fn royalty_balances(app: &StargazeApp, royalty_registry: &Addr, recipient: &Addr) -> Vec<Coin> {
+    query_msg(
+        app,
+        royalty_registry,
+        &QueryMsg::RoyaltyBalances {
+            recipient: recipient.to_string(),
+            query_options: None,
+        },
+    )
+}
+
+/// Distributes a sale by the protocol on behalf of the seller.
+pub fn distribute_sale(
+    app: &mut StargazeApp,
+    royalty_registry: &Addr,
+    collection: &Addr,
+    protocol: Option<&Addr>,
+    protocol_fee: Option<ProtocolFee>,
+    escrow_royalty: bool,
+    sale: Coin,
+) -> Result<AppResponse, ContractError> {
+    fund_account(app, &self::protocol(), vec![sale.clone()]).unwrap();
+    app.execute_contract(
+        self::protocol(),
+        royalty_registry.clone(),
+        &ExecuteMsg::DistributeSale {
+            collection: collection.to_string(),
+            protocol: protocol.map(|protocol| protocol.to_string()),
+            seller: seller().to_string(),
+            protocol_fee,
+            escrow_royalty: Some(escrow_royalty),
+        },
+        &[sale],
+    )
+    .map_err(|err| err.downcast().unwrap())
+}
@@ -0,0 +1,66 @@
+use crate::{
+    msg::{QueryMsg, RoyaltySyncResponse},
+    state::RoyaltyAction,
+    tests::setup::{
+        advance_time, creator, query_msg, royalty_default, royalty_history, seller,
+        set_collection_royalty, setup_with_royalty, sync_collection_royalty, UPDATE_WAIT_PERIOD,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Decimal;
+
+#[test]
+fn try_sync_collection_royalty() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    set_collection_royalty(&mut app, &collection, Decimal::percent(10));
+    advance_time(&mut app, UPDATE_WAIT_PERIOD);
+
+    let response: RoyaltySyncResponse = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::CollectionRoyaltySync {
+            collection: collection.to_string(),
+        },
+    );
+    assert!(!response.in_sync);
+
+    // The share moves towards the collection royalty by at most `max_share_delta`
+    sync_collection_royalty(&mut app, &creator(), &royalty_registry, &collection).unwrap();
+    let response: RoyaltySyncResponse = query_msg(
+        &app,
+        &royalty_registry,
+        &QueryMsg::CollectionRoyaltySync {
+            collection: collection.to_string(),
+        },
+    );
+    assert_eq!(
+        response.royalty_default.unwrap().royalty_entry.share,
+        Decimal::percent(7)
+    );
+    assert!(!response.in_sync);
+}
+
+#[test]
+fn try_sync_collection_royalty_by_anyone() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    set_collection_royalty(&mut app, &collection, Decimal::percent(10));
+    advance_time(&mut app, UPDATE_WAIT_PERIOD);
+
+    // Syncing is permissionless, and is recorded with the sender as actor
+    sync_collection_royalty(&mut app, &seller(), &royalty_registry, &collection).unwrap();
+    assert_eq!(
+        royalty_default(&app, &royalty_registry, &collection)
+            .royalty_entry
+            .share,
+        Decimal::percent(7)
+    );
+    let history = royalty_history(&app, &royalty_registry, &collection, None);
+    assert_eq!(history.last().unwrap().action, RoyaltyAction::Sync);
+    assert_eq!(history.last().unwrap().actor, seller());
+
+    // The cooldown still applies to syncs by anyone
+    let err =
+        sync_collection_royalty(&mut app, &seller(), &royalty_registry, &collection).unwrap_err();
+    assert!(matches!(err, ContractError::CooldownActive { .. }));
+}
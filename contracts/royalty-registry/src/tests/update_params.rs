@@ -0,0 +1,245 @@
+use crate::{
+    constants::{MAX_UPDATE_WAIT_PERIOD, MIN_MAX_SHARE_DELTA},
+    state::UpdateParams,
+    tests::setup::{
+        advance_time, creator, protocol, register_protocol, royalty_default, royalty_protocol,
+        seller, set_royalty_protocol, set_royalty_update_params, setup_with_royalty,
+        update_royalty_default, update_royalty_protocol, UPDATE_WAIT_PERIOD,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Decimal;
+
+#[test]
+fn try_update_params_tighten_royalty_updates() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+    register_protocol(
+        &mut app,
+        &royalty_registry,
+        &protocol(),
+        Decimal::percent(10),
+    );
+    set_royalty_protocol(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(3),
+    )
+    .unwrap();
+
+    let update_params = UpdateParams {
+        update_wait_period: Some(2 * UPDATE_WAIT_PERIOD),
+        max_share_delta: Some(Decimal::percent(1)),
+    };
+    set_royalty_update_params(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        update_params.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        royalty_default(&app, &royalty_registry, &collection).update_params,
+        Some(update_params)
+    );
+
+    let updated = app.block_info().time;
+    update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        false,
+    )
+    .unwrap();
+
+    // The global `update_wait_period` is not enough for either entry
+    advance_time(&mut app, UPDATE_WAIT_PERIOD);
+    let until = updated.plus_seconds(2 * UPDATE_WAIT_PERIOD);
+    let err = update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::CooldownActive { until });
+    let err = update_royalty_protocol(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::CooldownActive { until });
+
+    // Nor is the global `max_share_delta`
+    advance_time(&mut app, UPDATE_WAIT_PERIOD);
+    let err = update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(2),
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ShareDeltaExceeded {
+            delta: Decimal::percent(2),
+            max_share_delta: Decimal::percent(1),
+        }
+    );
+    let err = update_royalty_protocol(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(2),
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ShareDeltaExceeded {
+            delta: Decimal::percent(2),
+            max_share_delta: Decimal::percent(1),
+        }
+    );
+
+    update_royalty_default(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        false,
+    )
+    .unwrap();
+    update_royalty_protocol(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        Decimal::percent(1),
+        false,
+    )
+    .unwrap();
+    assert_eq!(
+        royalty_default(&app, &royalty_registry, &collection)
+            .royalty_entry
+            .share,
+        Decimal::percent(7)
+    );
+    assert_eq!(
+        royalty_protocol(&app, &royalty_registry, &collection)
+            .royalty_entry
+            .share,
+        Decimal::percent(4)
+    );
+}
+
+#[test]
+fn try_update_params_invalid() {
+    let (mut app, royalty_registry, collection) = setup_with_royalty();
+
+    let err = set_royalty_update_params(
+        &mut app,
+        &seller(),
+        &royalty_registry,
+        &collection,
+        UpdateParams {
+            update_wait_period: Some(2 * UPDATE_WAIT_PERIOD),
+            max_share_delta: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotCollectionAdmin {
+            sender: seller(),
+            admin: creator(),
+        }
+    );
+
+    // Params cannot be looser than the global config
+    let err = set_royalty_update_params(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        UpdateParams {
+            update_wait_period: Some(UPDATE_WAIT_PERIOD - 1),
+            max_share_delta: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UpdateWaitPeriodOutOfRange {
+            update_wait_period: UPDATE_WAIT_PERIOD - 1,
+            min: UPDATE_WAIT_PERIOD,
+            max: MAX_UPDATE_WAIT_PERIOD,
+        }
+    );
+    let err = set_royalty_update_params(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        UpdateParams {
+            update_wait_period: None,
+            max_share_delta: Some(Decimal::percent(3)),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MaxShareDeltaOutOfRange {
+            max_share_delta: Decimal::percent(3),
+            min: MIN_MAX_SHARE_DELTA,
+            max: Decimal::percent(2),
+        }
+    );
+
+    // Nor looser than the params already set for the collection
+    set_royalty_update_params(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        UpdateParams {
+            update_wait_period: None,
+            max_share_delta: Some(Decimal::percent(1)),
+        },
+    )
+    .unwrap();
+    let err = set_royalty_update_params(
+        &mut app,
+        &creator(),
+        &royalty_registry,
+        &collection,
+        UpdateParams {
+            update_wait_period: None,
+            max_share_delta: Some(Decimal::percent(2)),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MaxShareDeltaOutOfRange {
+            max_share_delta: Decimal::percent(2),
+            min: MIN_MAX_SHARE_DELTA,
+            max: Decimal::percent(1),
+        }
+    );
+}
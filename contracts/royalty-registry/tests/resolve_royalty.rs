@@ -0,0 +1,92 @@
+use std::{env, fs, path::PathBuf, process::Command};
+
+const SNAPSHOT: &str = r#"{
+    "royalty_defaults": [
+        {
+            "collection": "collection",
+            "royalty_entry": {
+                "recipient": "artist",
+                "share": "0.05",
+                "updated": null,
+                "denom_shares": [{ "denom": "uatom", "share": "0.08" }]
+            }
+        }
+    ],
+    "registered_protocols": [
+        {
+            "protocol": "protocol",
+            "name": "Protocol",
+            "url": "https://example.com",
+            "max_share": "0.06",
+            "active": true
+        }
+    ],
+    "royalty_promotions": [
+        {
+            "collection": "collection",
+            "protocol": null,
+            "share": "0.02",
+            "start": "1000000000000",
+            "end": "2000000000000"
+        }
+    ],
+    "block_time": "3000000000000"
+}"#;
+
+fn write_snapshot(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("{}-{}.json", name, std::process::id()));
+    fs::write(&path, SNAPSHOT).unwrap();
+    path
+}
+
+fn resolve_royalty(args: &[&str]) -> (bool, String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_resolve_royalty"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn try_resolve_royalty() {
+    let snapshot = write_snapshot("try_resolve_royalty");
+    let snapshot = snapshot.to_str().unwrap();
+
+    // The promotion has ended at the time of the snapshot, and the denom share is capped at
+    // the protocol max share
+    let (success, stdout, _) = resolve_royalty(&[
+        snapshot,
+        "collection",
+        "--protocol",
+        "protocol",
+        "--denom",
+        "uatom",
+    ]);
+    assert!(success);
+    assert!(stdout.contains(r#""share":"0.06""#));
+    assert!(stdout.contains(r#""source":"registry_default""#));
+
+    // The promotion is active at the given time
+    let (success, stdout, _) = resolve_royalty(&[snapshot, "collection", "--time", "1500"]);
+    assert!(success);
+    assert!(stdout.contains(r#""share":"0.02""#));
+
+    let (success, stdout, _) = resolve_royalty(&[snapshot, "other_collection"]);
+    assert!(success);
+    assert_eq!(stdout.trim(), "null");
+}
+
+#[test]
+fn try_resolve_royalty_with_invalid_args() {
+    let (success, _, stderr) = resolve_royalty(&["snapshot.json"]);
+    assert!(!success);
+    assert!(stderr.starts_with("Usage: resolve_royalty"));
+
+    let (success, _, stderr) = resolve_royalty(&["missing-snapshot.json", "collection"]);
+    assert!(!success);
+    assert!(stderr.starts_with("Failed to read missing-snapshot.json"));
+}
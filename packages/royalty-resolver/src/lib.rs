@@ -0,0 +1,266 @@
+//! The royalty resolution rules of the Stargaze royalty registry, over plain state.
+//!
+//! Addresses are strings, shares are the atomics of an 18 decimal fixed point number
+//! (as `cosmwasm_std::Decimal::atomics`) and times are in nanoseconds. The crate only
+//! relies on `core` and `alloc`, so that callers convert their own types at the boundary.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use core::cmp::min;
+
+/// A royalty entry of the registry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoyaltyEntry {
+    pub recipient: String,
+    pub share: u128,
+    /// Shares that override `share` for sales settled in a specific denom
+    pub denom_shares: Vec<DenomShare>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DenomShare {
+    pub denom: String,
+    pub share: u128,
+}
+
+impl RoyaltyEntry {
+    /// Returns the share for sales settled in the given denom.
+    pub fn share_for_denom(&self, denom: &str) -> u128 {
+        self.denom_shares
+            .iter()
+            .find(|denom_share| denom_share.denom == denom)
+            .map_or(self.share, |denom_share| denom_share.share)
+    }
+
+    /// Caps the share at the maximum share honored by the protocol.
+    fn cap(mut self, max_share: Option<u128>) -> Self {
+        if let Some(max_share) = max_share {
+            self.share = min(self.share, max_share);
+        }
+        self
+    }
+}
+
+/// A temporary royalty share that overrides a royalty entry between `start` and `end`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoyaltyPromotion {
+    pub share: u128,
+    /// The time at which the promotion begins, inclusive
+    pub start: u64,
+    /// The time at which the promotion ends, exclusive
+    pub end: u64,
+}
+
+impl RoyaltyPromotion {
+    pub fn is_active(&self, block_time: u64) -> bool {
+        self.start <= block_time && block_time < self.end
+    }
+
+    /// Overrides the share of the royalty entry, never increasing it.
+    pub fn apply(&self, royalty_entry: &mut RoyaltyEntry) {
+        royalty_entry.share = min(royalty_entry.share, self.share);
+    }
+}
+
+/// The registry state that determines the royalty of a sale of a collection.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoyaltyPaymentState {
+    pub royalty_default: Option<RoyaltyEntry>,
+    /// Whether the sale is on a protocol
+    pub on_protocol: bool,
+    /// The protocol entry, if the sale is on a protocol
+    pub royalty_protocol: Option<RoyaltyEntry>,
+    pub default_promotion: Option<RoyaltyPromotion>,
+    /// The promotion for the protocol, if the sale is on a protocol
+    pub protocol_promotion: Option<RoyaltyPromotion>,
+}
+
+/// The royalty entries for a sale, with denom shares and active promotions applied.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoyaltyPayment {
+    pub royalty_default: Option<RoyaltyEntry>,
+    pub royalty_protocol: Option<RoyaltyEntry>,
+    /// Whether the protocol entry was derived from the royalty default by a protocol promotion
+    pub protocol_from_default: bool,
+    pub default_promotion_applied: bool,
+    pub protocol_promotion_applied: bool,
+}
+
+/// Where a resolved royalty entry came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoyaltySource {
+    /// The protocol entry set on the registry
+    RegistryProtocol,
+    /// The royalty default set on the registry
+    RegistryDefault,
+    /// The royalty set on the collection contract, for collections not yet initialized
+    /// on the registry
+    CollectionFallback,
+}
+
+/// Resolves the royalty entries for a sale at `block_time`, applying the share for the
+/// sale `denom` if given, and any active promotions.
+pub fn royalty_payment(
+    state: RoyaltyPaymentState,
+    block_time: u64,
+    denom: Option<&str>,
+) -> RoyaltyPayment {
+    let RoyaltyPaymentState {
+        mut royalty_default,
+        on_protocol,
+        mut royalty_protocol,
+        default_promotion,
+        protocol_promotion,
+    } = state;
+
+    if let Some(denom) = denom {
+        for royalty_entry in royalty_default
+            .iter_mut()
+            .chain(royalty_protocol.iter_mut())
+        {
+            royalty_entry.share = royalty_entry.share_for_denom(denom);
+        }
+    }
+
+    let mut royalty_payment = RoyaltyPayment::default();
+
+    let default_promotion =
+        default_promotion.filter(|royalty_promotion| royalty_promotion.is_active(block_time));
+    if let (Some(royalty_promotion), Some(royalty_default)) =
+        (default_promotion, royalty_default.as_mut())
+    {
+        royalty_promotion.apply(royalty_default);
+        royalty_payment.default_promotion_applied = true;
+    }
+
+    let protocol_promotion =
+        protocol_promotion.filter(|royalty_promotion| royalty_promotion.is_active(block_time));
+    if let (true, Some(royalty_promotion)) = (on_protocol, protocol_promotion) {
+        // A protocol promotion without a protocol entry overrides the royalty default,
+        // with any default promotion already applied, for sales on the protocol
+        if royalty_protocol.is_none() && royalty_default.is_some() {
+            royalty_protocol = royalty_default.clone();
+            royalty_payment.protocol_from_default = true;
+        }
+        if let Some(royalty_protocol) = royalty_protocol.as_mut() {
+            royalty_promotion.apply(royalty_protocol);
+            royalty_payment.protocol_promotion_applied = true;
+        }
+    }
+
+    royalty_payment.royalty_default = royalty_default;
+    royalty_payment.royalty_protocol = royalty_protocol;
+    royalty_payment
+}
+
+/// Selects the protocol entry over the default entry, capped at the protocol max share.
+pub fn select_royalty_entry(
+    royalty_default: Option<RoyaltyEntry>,
+    royalty_protocol: Option<RoyaltyEntry>,
+    protocol_max_share: Option<u128>,
+) -> Option<(RoyaltyEntry, RoyaltySource)> {
+    royalty_protocol
+        .map(|royalty_entry| (royalty_entry, RoyaltySource::RegistryProtocol))
+        .or(royalty_default.map(|royalty_entry| (royalty_entry, RoyaltySource::RegistryDefault)))
+        .map(|(royalty_entry, source)| (royalty_entry.cap(protocol_max_share), source))
+}
+
+/// Resolves the royalty set on the collection contract, for collections not yet
+/// initialized on the registry.
+pub fn collection_fallback(
+    royalty_entry: RoyaltyEntry,
+    protocol_max_share: Option<u128>,
+) -> (RoyaltyEntry, RoyaltySource) {
+    (
+        royalty_entry.cap(protocol_max_share),
+        RoyaltySource::CollectionFallback,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{string::ToString, vec};
+
+    /// One percent, in the atomics of an 18 decimal fixed point number.
+    const PERCENT: u128 = 10_000_000_000_000_000;
+
+    fn royalty_entry(recipient: &str, share: u128) -> RoyaltyEntry {
+        RoyaltyEntry {
+            recipient: recipient.to_string(),
+            share,
+            denom_shares: vec![],
+        }
+    }
+
+    #[test]
+    fn denom_share_overrides_share() {
+        let mut royalty_default = royalty_entry("artist", 5 * PERCENT);
+        royalty_default.denom_shares.push(DenomShare {
+            denom: "uatom".to_string(),
+            share: 3 * PERCENT,
+        });
+        let state = RoyaltyPaymentState {
+            royalty_default: Some(royalty_default),
+            ..RoyaltyPaymentState::default()
+        };
+
+        let share = |denom| {
+            royalty_payment(state.clone(), 0, denom)
+                .royalty_default
+                .unwrap()
+                .share
+        };
+        assert_eq!(share(Some("uatom")), 3 * PERCENT);
+        assert_eq!(share(Some("ustars")), 5 * PERCENT);
+        assert_eq!(share(None), 5 * PERCENT);
+    }
+
+    #[test]
+    fn protocol_entry_takes_precedence_over_default() {
+        let royalty_default = royalty_entry("artist", 5 * PERCENT);
+        let royalty_protocol = royalty_entry("treasury", 3 * PERCENT);
+
+        assert_eq!(
+            select_royalty_entry(
+                Some(royalty_default.clone()),
+                Some(royalty_protocol.clone()),
+                None
+            ),
+            Some((royalty_protocol, RoyaltySource::RegistryProtocol))
+        );
+
+        // The default applies without a protocol entry, capped at the protocol max share
+        assert_eq!(
+            select_royalty_entry(Some(royalty_default), None, Some(2 * PERCENT)),
+            Some((
+                royalty_entry("artist", 2 * PERCENT),
+                RoyaltySource::RegistryDefault
+            ))
+        );
+    }
+
+    #[test]
+    fn missing_entries_resolve_to_none() {
+        let royalty_payment = royalty_payment(
+            RoyaltyPaymentState {
+                on_protocol: true,
+                protocol_promotion: Some(RoyaltyPromotion {
+                    share: 0,
+                    start: 0,
+                    end: 10,
+                }),
+                ..RoyaltyPaymentState::default()
+            },
+            5,
+            Some("uatom"),
+        );
+        assert_eq!(royalty_payment, RoyaltyPayment::default());
+
+        assert_eq!(select_royalty_entry(None, None, Some(2 * PERCENT)), None);
+    }
+}
@@ -3,7 +3,9 @@ MSG=$(cat <<EOF
 {
   "config": {
     "update_wait_period": 86400,
-    "max_share_delta": "0.01"
+    "max_share_delta": "0.01",
+    "min_share": "0",
+    "max_share": "1"
   }
 }
 EOF
@@ -2,8 +2,10 @@ CODE_ID=2753
 MSG=$(cat <<EOF
 {
   "config": {
-    "update_wait_period": 30,
-    "max_share_delta": "0.02"
+    "update_wait_period": 60,
+    "max_share_delta": "0.02",
+    "min_share": "0",
+    "max_share": "1"
   }
 }
 EOF
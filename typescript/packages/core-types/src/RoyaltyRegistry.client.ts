@@ -5,16 +5,24 @@
 */
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
-import { Coin, StdFee } from "@cosmjs/amino";
-import { Decimal, InstantiateMsg, Config, ExecuteMsg, QueryMsg, QueryBoundForString, QueryOptionsForString, NullableRoyaltyDefault, Addr, Timestamp, Uint64, RoyaltyDefault, RoyaltyEntry, NullableRoyaltyProtocol, RoyaltyProtocol, RoyaltyPaymentResponse, ArrayOfRoyaltyProtocol } from "./RoyaltyRegistry.types";
+import { StdFee } from "@cosmjs/amino";
+import { Decimal, InstantiateMsg, Config, ExecuteMsg, Timestamp, Uint64, RoyaltyLock, RoyaltyRole, RoyaltyOperation, Uint128, Expiration, UpdateParams, RoyaltyMetadata, ProtocolFee, Coin, QueryMsg, QueryBoundForString, QueryBoundForTupleOfStringAndString, QueryBoundForUint64, QueryOptionsForString, QueryOptionsForTupleOfStringAndString, QueryOptionsForUint64, Addr, ArrayOfRoyaltyPromotion, RoyaltyPromotion, ArrayOfRoyaltyDefault, RoyaltyDefault, RoyaltyEntry, DenomShare, ArrayOfCollectionRoyaltyLocks, CollectionRoyaltyLocks, RoyaltyLocks, ArrayOfRoyaltyProtocol, RoyaltyProtocol, CheckRoyaltiesResponse, CollectionCreatorResponse, NullableCollectionManager, CollectionManager, ArrayOfCollectionManager, NullableRoyaltyDefault, NullableRoyaltyProposal, RoyaltyProposal, RoyaltyDetailsResponse, Boolean, ArrayOfRoyaltyPeriod, RoyaltyPeriod, NullableRoyaltyPromotion, NullableRoyaltyProtocol, RoyaltySyncResponse, ArrayOfCoin, ConfigBoundsResponse, NullableRegisteredProtocol, RegisteredProtocol, ArrayOfRegisteredProtocol, RoyaltyAction, ArrayOfRoyaltyHistoryEntry, RoyaltyHistoryEntry, RoyaltiesInfoResponse, RoyaltyPaymentResponse, ArrayOfRoyaltyPaymentResponse } from "./RoyaltyRegistry.types";
 export interface RoyaltyRegistryReadOnlyInterface {
   contractAddress: string;
   config: () => Promise<Config>;
+  configBounds: () => Promise<ConfigBoundsResponse>;
   collectionRoyaltyDefault: ({
     collection
   }: {
     collection: string;
   }) => Promise<NullableRoyaltyDefault>;
+  collectionRoyaltyDetails: ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol?: string;
+  }) => Promise<RoyaltyDetailsResponse>;
   collectionRoyaltyProtocol: ({
     collection,
     protocol
@@ -31,11 +39,198 @@ export interface RoyaltyRegistryReadOnlyInterface {
   }) => Promise<ArrayOfRoyaltyProtocol>;
   royaltyPayment: ({
     collection,
+    denom,
     protocol
   }: {
     collection: string;
+    denom?: string;
     protocol?: string;
   }) => Promise<RoyaltyPaymentResponse>;
+  royaltyInfo: ({
+    collection,
+    protocol,
+    salePrice,
+    tokenId
+  }: {
+    collection: string;
+    protocol?: string;
+    salePrice: Uint128;
+    tokenId: string;
+  }) => Promise<RoyaltiesInfoResponse>;
+  checkRoyalties: ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol?: string;
+  }) => Promise<CheckRoyaltiesResponse>;
+  collectionCreator: ({
+    collection
+  }: {
+    collection: string;
+  }) => Promise<CollectionCreatorResponse>;
+  collectionRoyaltySync: ({
+    collection
+  }: {
+    collection: string;
+  }) => Promise<RoyaltySyncResponse>;
+  royaltyPayments: ({
+    collections,
+    denom,
+    protocol
+  }: {
+    collections: string[];
+    denom?: string;
+    protocol?: string;
+  }) => Promise<ArrayOfRoyaltyPaymentResponse>;
+  allRoyaltyDefaults: ({
+    queryOptions
+  }: {
+    queryOptions?: QueryOptionsForString;
+  }) => Promise<ArrayOfRoyaltyDefault>;
+  allRoyaltyProtocols: ({
+    queryOptions
+  }: {
+    queryOptions?: QueryOptionsForTupleOfStringAndString;
+  }) => Promise<ArrayOfRoyaltyProtocol>;
+  royaltyProtocolsByProtocol: ({
+    protocol,
+    queryOptions
+  }: {
+    protocol: string;
+    queryOptions?: QueryOptionsForString;
+  }) => Promise<ArrayOfRoyaltyProtocol>;
+  royaltyDefaultsByRecipient: ({
+    queryOptions,
+    recipient
+  }: {
+    queryOptions?: QueryOptionsForString;
+    recipient: string;
+  }) => Promise<ArrayOfRoyaltyDefault>;
+  royaltyProtocolsByRecipient: ({
+    queryOptions,
+    recipient
+  }: {
+    queryOptions?: QueryOptionsForTupleOfStringAndString;
+    recipient: string;
+  }) => Promise<ArrayOfRoyaltyProtocol>;
+  collectionRoyaltyLocks: ({
+    collection
+  }: {
+    collection: string;
+  }) => Promise<RoyaltyLocks>;
+  allRoyaltyLocks: ({
+    queryOptions
+  }: {
+    queryOptions?: QueryOptionsForString;
+  }) => Promise<ArrayOfCollectionRoyaltyLocks>;
+  collectionRoyaltyFrozen: ({
+    collection
+  }: {
+    collection: string;
+  }) => Promise<Boolean>;
+  royaltyHistory: ({
+    collection,
+    queryOptions
+  }: {
+    collection: string;
+    queryOptions?: QueryOptionsForUint64;
+  }) => Promise<ArrayOfRoyaltyHistoryEntry>;
+  collectionRoyaltyDefaultProposal: ({
+    collection
+  }: {
+    collection: string;
+  }) => Promise<NullableRoyaltyProposal>;
+  collectionRoyaltyProtocolProposal: ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol: string;
+  }) => Promise<NullableRoyaltyProposal>;
+  royaltyBalance: ({
+    denom,
+    recipient
+  }: {
+    denom: string;
+    recipient: string;
+  }) => Promise<Coin>;
+  royaltyBalances: ({
+    queryOptions,
+    recipient
+  }: {
+    queryOptions?: QueryOptionsForString;
+    recipient: string;
+  }) => Promise<ArrayOfCoin>;
+  collectionRoyaltyTotals: ({
+    collection,
+    queryOptions
+  }: {
+    collection: string;
+    queryOptions?: QueryOptionsForString;
+  }) => Promise<ArrayOfCoin>;
+  recipientRoyaltyTotals: ({
+    queryOptions,
+    recipient
+  }: {
+    queryOptions?: QueryOptionsForString;
+    recipient: string;
+  }) => Promise<ArrayOfCoin>;
+  protocolRoyaltyTotals: ({
+    protocol,
+    queryOptions
+  }: {
+    protocol: string;
+    queryOptions?: QueryOptionsForString;
+  }) => Promise<ArrayOfCoin>;
+  collectionRoyaltyPeriods: ({
+    collection,
+    queryOptions
+  }: {
+    collection: string;
+    queryOptions?: QueryOptionsForUint64;
+  }) => Promise<ArrayOfRoyaltyPeriod>;
+  collectionRoyaltyPromotion: ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol?: string;
+  }) => Promise<NullableRoyaltyPromotion>;
+  allRoyaltyDefaultPromotions: ({
+    queryOptions
+  }: {
+    queryOptions?: QueryOptionsForString;
+  }) => Promise<ArrayOfRoyaltyPromotion>;
+  allRoyaltyProtocolPromotions: ({
+    queryOptions
+  }: {
+    queryOptions?: QueryOptionsForTupleOfStringAndString;
+  }) => Promise<ArrayOfRoyaltyPromotion>;
+  registeredProtocol: ({
+    protocol
+  }: {
+    protocol: string;
+  }) => Promise<NullableRegisteredProtocol>;
+  registeredProtocols: ({
+    queryOptions
+  }: {
+    queryOptions?: QueryOptionsForString;
+  }) => Promise<ArrayOfRegisteredProtocol>;
+  collectionManager: ({
+    collection,
+    manager
+  }: {
+    collection: string;
+    manager: string;
+  }) => Promise<NullableCollectionManager>;
+  collectionManagers: ({
+    collection,
+    queryOptions
+  }: {
+    collection: string;
+    queryOptions?: QueryOptionsForString;
+  }) => Promise<ArrayOfCollectionManager>;
 }
 export class RoyaltyRegistryQueryClient implements RoyaltyRegistryReadOnlyInterface {
   client: CosmWasmClient;
@@ -45,10 +240,41 @@ export class RoyaltyRegistryQueryClient implements RoyaltyRegistryReadOnlyInterf
     this.client = client;
     this.contractAddress = contractAddress;
     this.config = this.config.bind(this);
+    this.configBounds = this.configBounds.bind(this);
     this.collectionRoyaltyDefault = this.collectionRoyaltyDefault.bind(this);
+    this.collectionRoyaltyDetails = this.collectionRoyaltyDetails.bind(this);
     this.collectionRoyaltyProtocol = this.collectionRoyaltyProtocol.bind(this);
     this.royaltyProtocolByCollection = this.royaltyProtocolByCollection.bind(this);
     this.royaltyPayment = this.royaltyPayment.bind(this);
+    this.royaltyInfo = this.royaltyInfo.bind(this);
+    this.checkRoyalties = this.checkRoyalties.bind(this);
+    this.collectionCreator = this.collectionCreator.bind(this);
+    this.collectionRoyaltySync = this.collectionRoyaltySync.bind(this);
+    this.royaltyPayments = this.royaltyPayments.bind(this);
+    this.allRoyaltyDefaults = this.allRoyaltyDefaults.bind(this);
+    this.allRoyaltyProtocols = this.allRoyaltyProtocols.bind(this);
+    this.royaltyProtocolsByProtocol = this.royaltyProtocolsByProtocol.bind(this);
+    this.royaltyDefaultsByRecipient = this.royaltyDefaultsByRecipient.bind(this);
+    this.royaltyProtocolsByRecipient = this.royaltyProtocolsByRecipient.bind(this);
+    this.collectionRoyaltyLocks = this.collectionRoyaltyLocks.bind(this);
+    this.allRoyaltyLocks = this.allRoyaltyLocks.bind(this);
+    this.collectionRoyaltyFrozen = this.collectionRoyaltyFrozen.bind(this);
+    this.royaltyHistory = this.royaltyHistory.bind(this);
+    this.collectionRoyaltyDefaultProposal = this.collectionRoyaltyDefaultProposal.bind(this);
+    this.collectionRoyaltyProtocolProposal = this.collectionRoyaltyProtocolProposal.bind(this);
+    this.royaltyBalance = this.royaltyBalance.bind(this);
+    this.royaltyBalances = this.royaltyBalances.bind(this);
+    this.collectionRoyaltyTotals = this.collectionRoyaltyTotals.bind(this);
+    this.recipientRoyaltyTotals = this.recipientRoyaltyTotals.bind(this);
+    this.protocolRoyaltyTotals = this.protocolRoyaltyTotals.bind(this);
+    this.collectionRoyaltyPeriods = this.collectionRoyaltyPeriods.bind(this);
+    this.collectionRoyaltyPromotion = this.collectionRoyaltyPromotion.bind(this);
+    this.allRoyaltyDefaultPromotions = this.allRoyaltyDefaultPromotions.bind(this);
+    this.allRoyaltyProtocolPromotions = this.allRoyaltyProtocolPromotions.bind(this);
+    this.registeredProtocol = this.registeredProtocol.bind(this);
+    this.registeredProtocols = this.registeredProtocols.bind(this);
+    this.collectionManager = this.collectionManager.bind(this);
+    this.collectionManagers = this.collectionManagers.bind(this);
   }
 
   config = async (): Promise<Config> => {
@@ -56,6 +282,11 @@ export class RoyaltyRegistryQueryClient implements RoyaltyRegistryReadOnlyInterf
       config: {}
     });
   };
+  configBounds = async (): Promise<ConfigBoundsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      config_bounds: {}
+    });
+  };
   collectionRoyaltyDefault = async ({
     collection
   }: {
@@ -67,6 +298,20 @@ export class RoyaltyRegistryQueryClient implements RoyaltyRegistryReadOnlyInterf
       }
     });
   };
+  collectionRoyaltyDetails = async ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol?: string;
+  }): Promise<RoyaltyDetailsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      collection_royalty_details: {
+        collection,
+        protocol
+      }
+    });
+  };
   collectionRoyaltyProtocol = async ({
     collection,
     protocol
@@ -97,18 +342,400 @@ export class RoyaltyRegistryQueryClient implements RoyaltyRegistryReadOnlyInterf
   };
   royaltyPayment = async ({
     collection,
+    denom,
     protocol
   }: {
     collection: string;
+    denom?: string;
     protocol?: string;
   }): Promise<RoyaltyPaymentResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
       royalty_payment: {
+        collection,
+        denom,
+        protocol
+      }
+    });
+  };
+  royaltyInfo = async ({
+    collection,
+    protocol,
+    salePrice,
+    tokenId
+  }: {
+    collection: string;
+    protocol?: string;
+    salePrice: Uint128;
+    tokenId: string;
+  }): Promise<RoyaltiesInfoResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      royalty_info: {
+        collection,
+        protocol,
+        sale_price: salePrice,
+        token_id: tokenId
+      }
+    });
+  };
+  checkRoyalties = async ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol?: string;
+  }): Promise<CheckRoyaltiesResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      check_royalties: {
+        collection,
+        protocol
+      }
+    });
+  };
+  collectionCreator = async ({
+    collection
+  }: {
+    collection: string;
+  }): Promise<CollectionCreatorResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      collection_creator: {
+        collection
+      }
+    });
+  };
+  collectionRoyaltySync = async ({
+    collection
+  }: {
+    collection: string;
+  }): Promise<RoyaltySyncResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      collection_royalty_sync: {
+        collection
+      }
+    });
+  };
+  royaltyPayments = async ({
+    collections,
+    denom,
+    protocol
+  }: {
+    collections: string[];
+    denom?: string;
+    protocol?: string;
+  }): Promise<ArrayOfRoyaltyPaymentResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      royalty_payments: {
+        collections,
+        denom,
+        protocol
+      }
+    });
+  };
+  allRoyaltyDefaults = async ({
+    queryOptions
+  }: {
+    queryOptions?: QueryOptionsForString;
+  }): Promise<ArrayOfRoyaltyDefault> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      all_royalty_defaults: {
+        query_options: queryOptions
+      }
+    });
+  };
+  allRoyaltyProtocols = async ({
+    queryOptions
+  }: {
+    queryOptions?: QueryOptionsForTupleOfStringAndString;
+  }): Promise<ArrayOfRoyaltyProtocol> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      all_royalty_protocols: {
+        query_options: queryOptions
+      }
+    });
+  };
+  royaltyProtocolsByProtocol = async ({
+    protocol,
+    queryOptions
+  }: {
+    protocol: string;
+    queryOptions?: QueryOptionsForString;
+  }): Promise<ArrayOfRoyaltyProtocol> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      royalty_protocols_by_protocol: {
+        protocol,
+        query_options: queryOptions
+      }
+    });
+  };
+  royaltyDefaultsByRecipient = async ({
+    queryOptions,
+    recipient
+  }: {
+    queryOptions?: QueryOptionsForString;
+    recipient: string;
+  }): Promise<ArrayOfRoyaltyDefault> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      royalty_defaults_by_recipient: {
+        query_options: queryOptions,
+        recipient
+      }
+    });
+  };
+  royaltyProtocolsByRecipient = async ({
+    queryOptions,
+    recipient
+  }: {
+    queryOptions?: QueryOptionsForTupleOfStringAndString;
+    recipient: string;
+  }): Promise<ArrayOfRoyaltyProtocol> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      royalty_protocols_by_recipient: {
+        query_options: queryOptions,
+        recipient
+      }
+    });
+  };
+  collectionRoyaltyLocks = async ({
+    collection
+  }: {
+    collection: string;
+  }): Promise<RoyaltyLocks> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      collection_royalty_locks: {
+        collection
+      }
+    });
+  };
+  allRoyaltyLocks = async ({
+    queryOptions
+  }: {
+    queryOptions?: QueryOptionsForString;
+  }): Promise<ArrayOfCollectionRoyaltyLocks> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      all_royalty_locks: {
+        query_options: queryOptions
+      }
+    });
+  };
+  collectionRoyaltyFrozen = async ({
+    collection
+  }: {
+    collection: string;
+  }): Promise<Boolean> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      collection_royalty_frozen: {
+        collection
+      }
+    });
+  };
+  royaltyHistory = async ({
+    collection,
+    queryOptions
+  }: {
+    collection: string;
+    queryOptions?: QueryOptionsForUint64;
+  }): Promise<ArrayOfRoyaltyHistoryEntry> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      royalty_history: {
+        collection,
+        query_options: queryOptions
+      }
+    });
+  };
+  collectionRoyaltyDefaultProposal = async ({
+    collection
+  }: {
+    collection: string;
+  }): Promise<NullableRoyaltyProposal> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      collection_royalty_default_proposal: {
+        collection
+      }
+    });
+  };
+  collectionRoyaltyProtocolProposal = async ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol: string;
+  }): Promise<NullableRoyaltyProposal> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      collection_royalty_protocol_proposal: {
+        collection,
+        protocol
+      }
+    });
+  };
+  royaltyBalance = async ({
+    denom,
+    recipient
+  }: {
+    denom: string;
+    recipient: string;
+  }): Promise<Coin> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      royalty_balance: {
+        denom,
+        recipient
+      }
+    });
+  };
+  royaltyBalances = async ({
+    queryOptions,
+    recipient
+  }: {
+    queryOptions?: QueryOptionsForString;
+    recipient: string;
+  }): Promise<ArrayOfCoin> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      royalty_balances: {
+        query_options: queryOptions,
+        recipient
+      }
+    });
+  };
+  collectionRoyaltyTotals = async ({
+    collection,
+    queryOptions
+  }: {
+    collection: string;
+    queryOptions?: QueryOptionsForString;
+  }): Promise<ArrayOfCoin> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      collection_royalty_totals: {
+        collection,
+        query_options: queryOptions
+      }
+    });
+  };
+  recipientRoyaltyTotals = async ({
+    queryOptions,
+    recipient
+  }: {
+    queryOptions?: QueryOptionsForString;
+    recipient: string;
+  }): Promise<ArrayOfCoin> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      recipient_royalty_totals: {
+        query_options: queryOptions,
+        recipient
+      }
+    });
+  };
+  protocolRoyaltyTotals = async ({
+    protocol,
+    queryOptions
+  }: {
+    protocol: string;
+    queryOptions?: QueryOptionsForString;
+  }): Promise<ArrayOfCoin> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      protocol_royalty_totals: {
+        protocol,
+        query_options: queryOptions
+      }
+    });
+  };
+  collectionRoyaltyPeriods = async ({
+    collection,
+    queryOptions
+  }: {
+    collection: string;
+    queryOptions?: QueryOptionsForUint64;
+  }): Promise<ArrayOfRoyaltyPeriod> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      collection_royalty_periods: {
+        collection,
+        query_options: queryOptions
+      }
+    });
+  };
+  collectionRoyaltyPromotion = async ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol?: string;
+  }): Promise<NullableRoyaltyPromotion> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      collection_royalty_promotion: {
         collection,
         protocol
       }
     });
   };
+  allRoyaltyDefaultPromotions = async ({
+    queryOptions
+  }: {
+    queryOptions?: QueryOptionsForString;
+  }): Promise<ArrayOfRoyaltyPromotion> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      all_royalty_default_promotions: {
+        query_options: queryOptions
+      }
+    });
+  };
+  allRoyaltyProtocolPromotions = async ({
+    queryOptions
+  }: {
+    queryOptions?: QueryOptionsForTupleOfStringAndString;
+  }): Promise<ArrayOfRoyaltyPromotion> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      all_royalty_protocol_promotions: {
+        query_options: queryOptions
+      }
+    });
+  };
+  registeredProtocol = async ({
+    protocol
+  }: {
+    protocol: string;
+  }): Promise<NullableRegisteredProtocol> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      registered_protocol: {
+        protocol
+      }
+    });
+  };
+  registeredProtocols = async ({
+    queryOptions
+  }: {
+    queryOptions?: QueryOptionsForString;
+  }): Promise<ArrayOfRegisteredProtocol> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      registered_protocols: {
+        query_options: queryOptions
+      }
+    });
+  };
+  collectionManager = async ({
+    collection,
+    manager
+  }: {
+    collection: string;
+    manager: string;
+  }): Promise<NullableCollectionManager> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      collection_manager: {
+        collection,
+        manager
+      }
+    });
+  };
+  collectionManagers = async ({
+    collection,
+    queryOptions
+  }: {
+    collection: string;
+    queryOptions?: QueryOptionsForString;
+  }): Promise<ArrayOfCollectionManager> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      collection_managers: {
+        collection,
+        query_options: queryOptions
+      }
+    });
+  };
 }
 export interface RoyaltyRegistryInterface extends RoyaltyRegistryReadOnlyInterface {
   contractAddress: string;
@@ -118,6 +745,11 @@ export interface RoyaltyRegistryInterface extends RoyaltyRegistryReadOnlyInterfa
   }: {
     collection: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  syncCollectionRoyalty: ({
+    collection
+  }: {
+    collection: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   setCollectionRoyaltyDefault: ({
     collection,
     recipient,
@@ -131,36 +763,217 @@ export interface RoyaltyRegistryInterface extends RoyaltyRegistryReadOnlyInterfa
     collection,
     decrement,
     recipient,
+    share,
+    shareDelta
+  }: {
+    collection: string;
+    decrement?: boolean;
+    recipient?: string;
+    share?: Decimal;
+    shareDelta?: Decimal;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  setCollectionRoyaltyProtocol: ({
+    collection,
+    protocol,
+    recipient,
+    share
+  }: {
+    collection: string;
+    protocol: string;
+    recipient: string;
+    share: Decimal;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updateCollectionRoyaltyProtocol: ({
+    collection,
+    decrement,
+    protocol,
+    recipient,
+    share,
     shareDelta
   }: {
     collection: string;
-    decrement?: boolean;
+    decrement?: boolean;
+    protocol: string;
+    recipient?: string;
+    share?: Decimal;
+    shareDelta?: Decimal;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  proposeCollectionRoyaltyDefault: ({
+    collection,
+    decrement,
+    recipient,
+    share,
+    shareDelta
+  }: {
+    collection: string;
+    decrement?: boolean;
+    recipient?: string;
+    share?: Decimal;
+    shareDelta?: Decimal;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  applyCollectionRoyaltyDefault: ({
+    collection
+  }: {
+    collection: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  cancelCollectionRoyaltyDefault: ({
+    collection
+  }: {
+    collection: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  proposeCollectionRoyaltyProtocol: ({
+    collection,
+    decrement,
+    protocol,
+    recipient,
+    share,
+    shareDelta
+  }: {
+    collection: string;
+    decrement?: boolean;
+    protocol: string;
+    recipient?: string;
+    share?: Decimal;
+    shareDelta?: Decimal;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  applyCollectionRoyaltyProtocol: ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  cancelCollectionRoyaltyProtocol: ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  setCollectionRoyaltyPromotion: ({
+    collection,
+    end,
+    protocol,
+    share,
+    start
+  }: {
+    collection: string;
+    end: Timestamp;
+    protocol?: string;
+    share: Decimal;
+    start: Timestamp;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  removeCollectionRoyaltyPromotion: ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  lockCollectionRoyalty: ({
+    collection,
+    default,
+    protocols
+  }: {
+    collection: string;
+    default?: RoyaltyLock;
+    protocols?: RoyaltyLock;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  setCollectionRoyaltyUpdateParams: ({
+    collection,
+    updateParams
+  }: {
+    collection: string;
+    updateParams: UpdateParams;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  setCollectionRoyaltyMetadata: ({
+    collection,
+    metadata,
+    protocol
+  }: {
+    collection: string;
+    metadata?: RoyaltyMetadata;
+    protocol?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  setCollectionRoyaltyDenomShare: ({
+    collection,
+    denom,
+    protocol,
+    share
+  }: {
+    collection: string;
+    denom: string;
+    protocol?: string;
+    share?: Decimal;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  batchCollectionRoyalty: ({
+    operations
+  }: {
+    operations: RoyaltyOperation[];
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  distributeSale: ({
+    collection,
+    escrowRoyalty,
+    protocol,
+    protocolFee,
+    seller
+  }: {
+    collection: string;
+    escrowRoyalty?: boolean;
+    protocol?: string;
+    protocolFee?: ProtocolFee;
+    seller: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  recordRoyaltyPayment: ({
+    amount,
+    collection,
+    recipient
+  }: {
+    amount: Coin;
+    collection: string;
+    recipient: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  claimRoyalties: ({
+    denoms
+  }: {
+    denoms?: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  claimCollectionRoyalty: ({
+    collection,
+    recipient
+  }: {
+    collection: string;
     recipient?: string;
-    shareDelta?: Decimal;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
-  setCollectionRoyaltyProtocol: ({
+  grantCollectionManager: ({
     collection,
-    protocol,
-    recipient,
-    share
+    expires,
+    manager
   }: {
     collection: string;
-    protocol: string;
-    recipient: string;
-    share: Decimal;
+    expires?: Expiration;
+    manager: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
-  updateCollectionRoyaltyProtocol: ({
+  revokeCollectionManager: ({
     collection,
-    decrement,
-    protocol,
-    recipient,
-    shareDelta
+    manager
   }: {
     collection: string;
-    decrement?: boolean;
-    protocol: string;
-    recipient?: string;
-    shareDelta?: Decimal;
+    manager: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  rebuildRoyaltyDefaultIndices: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  rebuildRoyaltyProtocolIndices: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: [string, string];
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
 }
 export class RoyaltyRegistryClient extends RoyaltyRegistryQueryClient implements RoyaltyRegistryInterface {
@@ -174,10 +987,32 @@ export class RoyaltyRegistryClient extends RoyaltyRegistryQueryClient implements
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.initializeCollectionRoyalty = this.initializeCollectionRoyalty.bind(this);
+    this.syncCollectionRoyalty = this.syncCollectionRoyalty.bind(this);
     this.setCollectionRoyaltyDefault = this.setCollectionRoyaltyDefault.bind(this);
     this.updateCollectionRoyaltyDefault = this.updateCollectionRoyaltyDefault.bind(this);
     this.setCollectionRoyaltyProtocol = this.setCollectionRoyaltyProtocol.bind(this);
     this.updateCollectionRoyaltyProtocol = this.updateCollectionRoyaltyProtocol.bind(this);
+    this.proposeCollectionRoyaltyDefault = this.proposeCollectionRoyaltyDefault.bind(this);
+    this.applyCollectionRoyaltyDefault = this.applyCollectionRoyaltyDefault.bind(this);
+    this.cancelCollectionRoyaltyDefault = this.cancelCollectionRoyaltyDefault.bind(this);
+    this.proposeCollectionRoyaltyProtocol = this.proposeCollectionRoyaltyProtocol.bind(this);
+    this.applyCollectionRoyaltyProtocol = this.applyCollectionRoyaltyProtocol.bind(this);
+    this.cancelCollectionRoyaltyProtocol = this.cancelCollectionRoyaltyProtocol.bind(this);
+    this.setCollectionRoyaltyPromotion = this.setCollectionRoyaltyPromotion.bind(this);
+    this.removeCollectionRoyaltyPromotion = this.removeCollectionRoyaltyPromotion.bind(this);
+    this.lockCollectionRoyalty = this.lockCollectionRoyalty.bind(this);
+    this.setCollectionRoyaltyUpdateParams = this.setCollectionRoyaltyUpdateParams.bind(this);
+    this.setCollectionRoyaltyMetadata = this.setCollectionRoyaltyMetadata.bind(this);
+    this.setCollectionRoyaltyDenomShare = this.setCollectionRoyaltyDenomShare.bind(this);
+    this.batchCollectionRoyalty = this.batchCollectionRoyalty.bind(this);
+    this.distributeSale = this.distributeSale.bind(this);
+    this.recordRoyaltyPayment = this.recordRoyaltyPayment.bind(this);
+    this.claimRoyalties = this.claimRoyalties.bind(this);
+    this.claimCollectionRoyalty = this.claimCollectionRoyalty.bind(this);
+    this.grantCollectionManager = this.grantCollectionManager.bind(this);
+    this.revokeCollectionManager = this.revokeCollectionManager.bind(this);
+    this.rebuildRoyaltyDefaultIndices = this.rebuildRoyaltyDefaultIndices.bind(this);
+    this.rebuildRoyaltyProtocolIndices = this.rebuildRoyaltyProtocolIndices.bind(this);
   }
 
   initializeCollectionRoyalty = async ({
@@ -191,6 +1026,17 @@ export class RoyaltyRegistryClient extends RoyaltyRegistryQueryClient implements
       }
     }, fee, memo, _funds);
   };
+  syncCollectionRoyalty = async ({
+    collection
+  }: {
+    collection: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      sync_collection_royalty: {
+        collection
+      }
+    }, fee, memo, _funds);
+  };
   setCollectionRoyaltyDefault = async ({
     collection,
     recipient,
@@ -212,11 +1058,13 @@ export class RoyaltyRegistryClient extends RoyaltyRegistryQueryClient implements
     collection,
     decrement,
     recipient,
+    share,
     shareDelta
   }: {
     collection: string;
     decrement?: boolean;
     recipient?: string;
+    share?: Decimal;
     shareDelta?: Decimal;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
@@ -224,6 +1072,7 @@ export class RoyaltyRegistryClient extends RoyaltyRegistryQueryClient implements
         collection,
         decrement,
         recipient,
+        share,
         share_delta: shareDelta
       }
     }, fee, memo, _funds);
@@ -253,12 +1102,14 @@ export class RoyaltyRegistryClient extends RoyaltyRegistryQueryClient implements
     decrement,
     protocol,
     recipient,
+    share,
     shareDelta
   }: {
     collection: string;
     decrement?: boolean;
     protocol: string;
     recipient?: string;
+    share?: Decimal;
     shareDelta?: Decimal;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
@@ -267,8 +1118,348 @@ export class RoyaltyRegistryClient extends RoyaltyRegistryQueryClient implements
         decrement,
         protocol,
         recipient,
+        share,
+        share_delta: shareDelta
+      }
+    }, fee, memo, _funds);
+  };
+  proposeCollectionRoyaltyDefault = async ({
+    collection,
+    decrement,
+    recipient,
+    share,
+    shareDelta
+  }: {
+    collection: string;
+    decrement?: boolean;
+    recipient?: string;
+    share?: Decimal;
+    shareDelta?: Decimal;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      propose_collection_royalty_default: {
+        collection,
+        decrement,
+        recipient,
+        share,
+        share_delta: shareDelta
+      }
+    }, fee, memo, _funds);
+  };
+  applyCollectionRoyaltyDefault = async ({
+    collection
+  }: {
+    collection: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      apply_collection_royalty_default: {
+        collection
+      }
+    }, fee, memo, _funds);
+  };
+  cancelCollectionRoyaltyDefault = async ({
+    collection
+  }: {
+    collection: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_collection_royalty_default: {
+        collection
+      }
+    }, fee, memo, _funds);
+  };
+  proposeCollectionRoyaltyProtocol = async ({
+    collection,
+    decrement,
+    protocol,
+    recipient,
+    share,
+    shareDelta
+  }: {
+    collection: string;
+    decrement?: boolean;
+    protocol: string;
+    recipient?: string;
+    share?: Decimal;
+    shareDelta?: Decimal;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      propose_collection_royalty_protocol: {
+        collection,
+        decrement,
+        protocol,
+        recipient,
+        share,
         share_delta: shareDelta
       }
     }, fee, memo, _funds);
   };
+  applyCollectionRoyaltyProtocol = async ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      apply_collection_royalty_protocol: {
+        collection,
+        protocol
+      }
+    }, fee, memo, _funds);
+  };
+  cancelCollectionRoyaltyProtocol = async ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_collection_royalty_protocol: {
+        collection,
+        protocol
+      }
+    }, fee, memo, _funds);
+  };
+  setCollectionRoyaltyPromotion = async ({
+    collection,
+    end,
+    protocol,
+    share,
+    start
+  }: {
+    collection: string;
+    end: Timestamp;
+    protocol?: string;
+    share: Decimal;
+    start: Timestamp;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_collection_royalty_promotion: {
+        collection,
+        end,
+        protocol,
+        share,
+        start
+      }
+    }, fee, memo, _funds);
+  };
+  removeCollectionRoyaltyPromotion = async ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_collection_royalty_promotion: {
+        collection,
+        protocol
+      }
+    }, fee, memo, _funds);
+  };
+  lockCollectionRoyalty = async ({
+    collection,
+    default,
+    protocols
+  }: {
+    collection: string;
+    default?: RoyaltyLock;
+    protocols?: RoyaltyLock;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      lock_collection_royalty: {
+        collection,
+        default,
+        protocols
+      }
+    }, fee, memo, _funds);
+  };
+  setCollectionRoyaltyUpdateParams = async ({
+    collection,
+    updateParams
+  }: {
+    collection: string;
+    updateParams: UpdateParams;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_collection_royalty_update_params: {
+        collection,
+        update_params: updateParams
+      }
+    }, fee, memo, _funds);
+  };
+  setCollectionRoyaltyMetadata = async ({
+    collection,
+    metadata,
+    protocol
+  }: {
+    collection: string;
+    metadata?: RoyaltyMetadata;
+    protocol?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_collection_royalty_metadata: {
+        collection,
+        metadata,
+        protocol
+      }
+    }, fee, memo, _funds);
+  };
+  setCollectionRoyaltyDenomShare = async ({
+    collection,
+    denom,
+    protocol,
+    share
+  }: {
+    collection: string;
+    denom: string;
+    protocol?: string;
+    share?: Decimal;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_collection_royalty_denom_share: {
+        collection,
+        denom,
+        protocol,
+        share
+      }
+    }, fee, memo, _funds);
+  };
+  batchCollectionRoyalty = async ({
+    operations
+  }: {
+    operations: RoyaltyOperation[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      batch_collection_royalty: {
+        operations
+      }
+    }, fee, memo, _funds);
+  };
+  distributeSale = async ({
+    collection,
+    escrowRoyalty,
+    protocol,
+    protocolFee,
+    seller
+  }: {
+    collection: string;
+    escrowRoyalty?: boolean;
+    protocol?: string;
+    protocolFee?: ProtocolFee;
+    seller: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      distribute_sale: {
+        collection,
+        escrow_royalty: escrowRoyalty,
+        protocol,
+        protocol_fee: protocolFee,
+        seller
+      }
+    }, fee, memo, _funds);
+  };
+  recordRoyaltyPayment = async ({
+    amount,
+    collection,
+    recipient
+  }: {
+    amount: Coin;
+    collection: string;
+    recipient: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      record_royalty_payment: {
+        amount,
+        collection,
+        recipient
+      }
+    }, fee, memo, _funds);
+  };
+  claimRoyalties = async ({
+    denoms
+  }: {
+    denoms?: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_royalties: {
+        denoms
+      }
+    }, fee, memo, _funds);
+  };
+  claimCollectionRoyalty = async ({
+    collection,
+    recipient
+  }: {
+    collection: string;
+    recipient?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_collection_royalty: {
+        collection,
+        recipient
+      }
+    }, fee, memo, _funds);
+  };
+  grantCollectionManager = async ({
+    collection,
+    expires,
+    manager
+  }: {
+    collection: string;
+    expires?: Expiration;
+    manager: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      grant_collection_manager: {
+        collection,
+        expires,
+        manager
+      }
+    }, fee, memo, _funds);
+  };
+  revokeCollectionManager = async ({
+    collection,
+    manager
+  }: {
+    collection: string;
+    manager: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      revoke_collection_manager: {
+        collection,
+        manager
+      }
+    }, fee, memo, _funds);
+  };
+  rebuildRoyaltyDefaultIndices = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      rebuild_royalty_default_indices: {
+        limit,
+        start_after: startAfter
+      }
+    }, fee, memo, _funds);
+  };
+  rebuildRoyaltyProtocolIndices = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: [string, string];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      rebuild_royalty_protocol_indices: {
+        limit,
+        start_after: startAfter
+      }
+    }, fee, memo, _funds);
+  };
 }
\ No newline at end of file
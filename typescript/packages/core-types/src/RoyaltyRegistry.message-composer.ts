@@ -4,11 +4,10 @@
 * and run the @cosmwasm/ts-codegen generate command to regenerate this file.
 */
 
-import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { Decimal, InstantiateMsg, Config, ExecuteMsg, QueryMsg, QueryBoundForString, QueryOptionsForString, NullableRoyaltyDefault, Addr, Timestamp, Uint64, RoyaltyDefault, RoyaltyEntry, NullableRoyaltyProtocol, RoyaltyProtocol, RoyaltyPaymentResponse, ArrayOfRoyaltyProtocol } from "./RoyaltyRegistry.types";
+import { Decimal, InstantiateMsg, Config, ExecuteMsg, Timestamp, Uint64, RoyaltyLock, RoyaltyRole, RoyaltyOperation, Uint128, Expiration, UpdateParams, RoyaltyMetadata, ProtocolFee, Coin, QueryMsg, QueryBoundForString, QueryBoundForTupleOfStringAndString, QueryBoundForUint64, QueryOptionsForString, QueryOptionsForTupleOfStringAndString, QueryOptionsForUint64, Addr, ArrayOfRoyaltyPromotion, RoyaltyPromotion, ArrayOfRoyaltyDefault, RoyaltyDefault, RoyaltyEntry, DenomShare, ArrayOfCollectionRoyaltyLocks, CollectionRoyaltyLocks, RoyaltyLocks, ArrayOfRoyaltyProtocol, RoyaltyProtocol, CheckRoyaltiesResponse, CollectionCreatorResponse, NullableCollectionManager, CollectionManager, ArrayOfCollectionManager, NullableRoyaltyDefault, NullableRoyaltyProposal, RoyaltyProposal, RoyaltyDetailsResponse, Boolean, ArrayOfRoyaltyPeriod, RoyaltyPeriod, NullableRoyaltyPromotion, NullableRoyaltyProtocol, RoyaltySyncResponse, ArrayOfCoin, ConfigBoundsResponse, NullableRegisteredProtocol, RegisteredProtocol, ArrayOfRegisteredProtocol, RoyaltyAction, ArrayOfRoyaltyHistoryEntry, RoyaltyHistoryEntry, RoyaltiesInfoResponse, RoyaltyPaymentResponse, ArrayOfRoyaltyPaymentResponse } from "./RoyaltyRegistry.types";
 export interface RoyaltyRegistryMessage {
   contractAddress: string;
   sender: string;
@@ -17,6 +16,11 @@ export interface RoyaltyRegistryMessage {
   }: {
     collection: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  syncCollectionRoyalty: ({
+    collection
+  }: {
+    collection: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   setCollectionRoyaltyDefault: ({
     collection,
     recipient,
@@ -30,11 +34,13 @@ export interface RoyaltyRegistryMessage {
     collection,
     decrement,
     recipient,
+    share,
     shareDelta
   }: {
     collection: string;
     decrement?: boolean;
     recipient?: string;
+    share?: Decimal;
     shareDelta?: Decimal;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   setCollectionRoyaltyProtocol: ({
@@ -53,14 +59,193 @@ export interface RoyaltyRegistryMessage {
     decrement,
     protocol,
     recipient,
+    share,
     shareDelta
   }: {
     collection: string;
     decrement?: boolean;
     protocol: string;
     recipient?: string;
+    share?: Decimal;
+    shareDelta?: Decimal;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  proposeCollectionRoyaltyDefault: ({
+    collection,
+    decrement,
+    recipient,
+    share,
+    shareDelta
+  }: {
+    collection: string;
+    decrement?: boolean;
+    recipient?: string;
+    share?: Decimal;
     shareDelta?: Decimal;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  applyCollectionRoyaltyDefault: ({
+    collection
+  }: {
+    collection: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  cancelCollectionRoyaltyDefault: ({
+    collection
+  }: {
+    collection: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  proposeCollectionRoyaltyProtocol: ({
+    collection,
+    decrement,
+    protocol,
+    recipient,
+    share,
+    shareDelta
+  }: {
+    collection: string;
+    decrement?: boolean;
+    protocol: string;
+    recipient?: string;
+    share?: Decimal;
+    shareDelta?: Decimal;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  applyCollectionRoyaltyProtocol: ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  cancelCollectionRoyaltyProtocol: ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  setCollectionRoyaltyPromotion: ({
+    collection,
+    end,
+    protocol,
+    share,
+    start
+  }: {
+    collection: string;
+    end: Timestamp;
+    protocol?: string;
+    share: Decimal;
+    start: Timestamp;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  removeCollectionRoyaltyPromotion: ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol?: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  lockCollectionRoyalty: ({
+    collection,
+    default,
+    protocols
+  }: {
+    collection: string;
+    default?: RoyaltyLock;
+    protocols?: RoyaltyLock;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  setCollectionRoyaltyUpdateParams: ({
+    collection,
+    updateParams
+  }: {
+    collection: string;
+    updateParams: UpdateParams;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  setCollectionRoyaltyMetadata: ({
+    collection,
+    metadata,
+    protocol
+  }: {
+    collection: string;
+    metadata?: RoyaltyMetadata;
+    protocol?: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  setCollectionRoyaltyDenomShare: ({
+    collection,
+    denom,
+    protocol,
+    share
+  }: {
+    collection: string;
+    denom: string;
+    protocol?: string;
+    share?: Decimal;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  batchCollectionRoyalty: ({
+    operations
+  }: {
+    operations: RoyaltyOperation[];
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  distributeSale: ({
+    collection,
+    escrowRoyalty,
+    protocol,
+    protocolFee,
+    seller
+  }: {
+    collection: string;
+    escrowRoyalty?: boolean;
+    protocol?: string;
+    protocolFee?: ProtocolFee;
+    seller: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  recordRoyaltyPayment: ({
+    amount,
+    collection,
+    recipient
+  }: {
+    amount: Coin;
+    collection: string;
+    recipient: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  claimRoyalties: ({
+    denoms
+  }: {
+    denoms?: string[];
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  claimCollectionRoyalty: ({
+    collection,
+    recipient
+  }: {
+    collection: string;
+    recipient?: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  grantCollectionManager: ({
+    collection,
+    expires,
+    manager
+  }: {
+    collection: string;
+    expires?: Expiration;
+    manager: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revokeCollectionManager: ({
+    collection,
+    manager
+  }: {
+    collection: string;
+    manager: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  rebuildRoyaltyDefaultIndices: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  rebuildRoyaltyProtocolIndices: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: [string, string];
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
 export class RoyaltyRegistryMessageComposer implements RoyaltyRegistryMessage {
   sender: string;
@@ -70,10 +255,32 @@ export class RoyaltyRegistryMessageComposer implements RoyaltyRegistryMessage {
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.initializeCollectionRoyalty = this.initializeCollectionRoyalty.bind(this);
+    this.syncCollectionRoyalty = this.syncCollectionRoyalty.bind(this);
     this.setCollectionRoyaltyDefault = this.setCollectionRoyaltyDefault.bind(this);
     this.updateCollectionRoyaltyDefault = this.updateCollectionRoyaltyDefault.bind(this);
     this.setCollectionRoyaltyProtocol = this.setCollectionRoyaltyProtocol.bind(this);
     this.updateCollectionRoyaltyProtocol = this.updateCollectionRoyaltyProtocol.bind(this);
+    this.proposeCollectionRoyaltyDefault = this.proposeCollectionRoyaltyDefault.bind(this);
+    this.applyCollectionRoyaltyDefault = this.applyCollectionRoyaltyDefault.bind(this);
+    this.cancelCollectionRoyaltyDefault = this.cancelCollectionRoyaltyDefault.bind(this);
+    this.proposeCollectionRoyaltyProtocol = this.proposeCollectionRoyaltyProtocol.bind(this);
+    this.applyCollectionRoyaltyProtocol = this.applyCollectionRoyaltyProtocol.bind(this);
+    this.cancelCollectionRoyaltyProtocol = this.cancelCollectionRoyaltyProtocol.bind(this);
+    this.setCollectionRoyaltyPromotion = this.setCollectionRoyaltyPromotion.bind(this);
+    this.removeCollectionRoyaltyPromotion = this.removeCollectionRoyaltyPromotion.bind(this);
+    this.lockCollectionRoyalty = this.lockCollectionRoyalty.bind(this);
+    this.setCollectionRoyaltyUpdateParams = this.setCollectionRoyaltyUpdateParams.bind(this);
+    this.setCollectionRoyaltyMetadata = this.setCollectionRoyaltyMetadata.bind(this);
+    this.setCollectionRoyaltyDenomShare = this.setCollectionRoyaltyDenomShare.bind(this);
+    this.batchCollectionRoyalty = this.batchCollectionRoyalty.bind(this);
+    this.distributeSale = this.distributeSale.bind(this);
+    this.recordRoyaltyPayment = this.recordRoyaltyPayment.bind(this);
+    this.claimRoyalties = this.claimRoyalties.bind(this);
+    this.claimCollectionRoyalty = this.claimCollectionRoyalty.bind(this);
+    this.grantCollectionManager = this.grantCollectionManager.bind(this);
+    this.revokeCollectionManager = this.revokeCollectionManager.bind(this);
+    this.rebuildRoyaltyDefaultIndices = this.rebuildRoyaltyDefaultIndices.bind(this);
+    this.rebuildRoyaltyProtocolIndices = this.rebuildRoyaltyProtocolIndices.bind(this);
   }
 
   initializeCollectionRoyalty = ({
@@ -95,6 +302,25 @@ export class RoyaltyRegistryMessageComposer implements RoyaltyRegistryMessage {
       })
     };
   };
+  syncCollectionRoyalty = ({
+    collection
+  }: {
+    collection: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          sync_collection_royalty: {
+            collection
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
   setCollectionRoyaltyDefault = ({
     collection,
     recipient,
@@ -124,11 +350,13 @@ export class RoyaltyRegistryMessageComposer implements RoyaltyRegistryMessage {
     collection,
     decrement,
     recipient,
+    share,
     shareDelta
   }: {
     collection: string;
     decrement?: boolean;
     recipient?: string;
+    share?: Decimal;
     shareDelta?: Decimal;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
@@ -141,6 +369,7 @@ export class RoyaltyRegistryMessageComposer implements RoyaltyRegistryMessage {
             collection,
             decrement,
             recipient,
+            share,
             share_delta: shareDelta
           }
         })),
@@ -181,12 +410,14 @@ export class RoyaltyRegistryMessageComposer implements RoyaltyRegistryMessage {
     decrement,
     protocol,
     recipient,
+    share,
     shareDelta
   }: {
     collection: string;
     decrement?: boolean;
     protocol: string;
     recipient?: string;
+    share?: Decimal;
     shareDelta?: Decimal;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
@@ -200,6 +431,110 @@ export class RoyaltyRegistryMessageComposer implements RoyaltyRegistryMessage {
             decrement,
             protocol,
             recipient,
+            share,
+            share_delta: shareDelta
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  proposeCollectionRoyaltyDefault = ({
+    collection,
+    decrement,
+    recipient,
+    share,
+    shareDelta
+  }: {
+    collection: string;
+    decrement?: boolean;
+    recipient?: string;
+    share?: Decimal;
+    shareDelta?: Decimal;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          propose_collection_royalty_default: {
+            collection,
+            decrement,
+            recipient,
+            share,
+            share_delta: shareDelta
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  applyCollectionRoyaltyDefault = ({
+    collection
+  }: {
+    collection: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          apply_collection_royalty_default: {
+            collection
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  cancelCollectionRoyaltyDefault = ({
+    collection
+  }: {
+    collection: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          cancel_collection_royalty_default: {
+            collection
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  proposeCollectionRoyaltyProtocol = ({
+    collection,
+    decrement,
+    protocol,
+    recipient,
+    share,
+    shareDelta
+  }: {
+    collection: string;
+    decrement?: boolean;
+    protocol: string;
+    recipient?: string;
+    share?: Decimal;
+    shareDelta?: Decimal;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          propose_collection_royalty_protocol: {
+            collection,
+            decrement,
+            protocol,
+            recipient,
+            share,
             share_delta: shareDelta
           }
         })),
@@ -207,4 +542,408 @@ export class RoyaltyRegistryMessageComposer implements RoyaltyRegistryMessage {
       })
     };
   };
+  applyCollectionRoyaltyProtocol = ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          apply_collection_royalty_protocol: {
+            collection,
+            protocol
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  cancelCollectionRoyaltyProtocol = ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          cancel_collection_royalty_protocol: {
+            collection,
+            protocol
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  setCollectionRoyaltyPromotion = ({
+    collection,
+    end,
+    protocol,
+    share,
+    start
+  }: {
+    collection: string;
+    end: Timestamp;
+    protocol?: string;
+    share: Decimal;
+    start: Timestamp;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_collection_royalty_promotion: {
+            collection,
+            end,
+            protocol,
+            share,
+            start
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  removeCollectionRoyaltyPromotion = ({
+    collection,
+    protocol
+  }: {
+    collection: string;
+    protocol?: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          remove_collection_royalty_promotion: {
+            collection,
+            protocol
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  lockCollectionRoyalty = ({
+    collection,
+    default,
+    protocols
+  }: {
+    collection: string;
+    default?: RoyaltyLock;
+    protocols?: RoyaltyLock;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          lock_collection_royalty: {
+            collection,
+            default,
+            protocols
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  setCollectionRoyaltyUpdateParams = ({
+    collection,
+    updateParams
+  }: {
+    collection: string;
+    updateParams: UpdateParams;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_collection_royalty_update_params: {
+            collection,
+            update_params: updateParams
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  setCollectionRoyaltyMetadata = ({
+    collection,
+    metadata,
+    protocol
+  }: {
+    collection: string;
+    metadata?: RoyaltyMetadata;
+    protocol?: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_collection_royalty_metadata: {
+            collection,
+            metadata,
+            protocol
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  setCollectionRoyaltyDenomShare = ({
+    collection,
+    denom,
+    protocol,
+    share
+  }: {
+    collection: string;
+    denom: string;
+    protocol?: string;
+    share?: Decimal;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_collection_royalty_denom_share: {
+            collection,
+            denom,
+            protocol,
+            share
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  batchCollectionRoyalty = ({
+    operations
+  }: {
+    operations: RoyaltyOperation[];
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          batch_collection_royalty: {
+            operations
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  distributeSale = ({
+    collection,
+    escrowRoyalty,
+    protocol,
+    protocolFee,
+    seller
+  }: {
+    collection: string;
+    escrowRoyalty?: boolean;
+    protocol?: string;
+    protocolFee?: ProtocolFee;
+    seller: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          distribute_sale: {
+            collection,
+            escrow_royalty: escrowRoyalty,
+            protocol,
+            protocol_fee: protocolFee,
+            seller
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  recordRoyaltyPayment = ({
+    amount,
+    collection,
+    recipient
+  }: {
+    amount: Coin;
+    collection: string;
+    recipient: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          record_royalty_payment: {
+            amount,
+            collection,
+            recipient
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  claimRoyalties = ({
+    denoms
+  }: {
+    denoms?: string[];
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          claim_royalties: {
+            denoms
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  claimCollectionRoyalty = ({
+    collection,
+    recipient
+  }: {
+    collection: string;
+    recipient?: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          claim_collection_royalty: {
+            collection,
+            recipient
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  grantCollectionManager = ({
+    collection,
+    expires,
+    manager
+  }: {
+    collection: string;
+    expires?: Expiration;
+    manager: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          grant_collection_manager: {
+            collection,
+            expires,
+            manager
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  revokeCollectionManager = ({
+    collection,
+    manager
+  }: {
+    collection: string;
+    manager: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          revoke_collection_manager: {
+            collection,
+            manager
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  rebuildRoyaltyDefaultIndices = ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          rebuild_royalty_default_indices: {
+            limit,
+            start_after: startAfter
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  rebuildRoyaltyProtocolIndices = ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: [string, string];
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          rebuild_royalty_protocol_indices: {
+            limit,
+            start_after: startAfter
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
 }
\ No newline at end of file
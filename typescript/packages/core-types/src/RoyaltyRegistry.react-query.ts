@@ -5,7 +5,7 @@
 */
 
 import { UseQueryOptions, useQuery } from "react-query";
-import { Decimal, InstantiateMsg, Config, ExecuteMsg, QueryMsg, QueryBoundForString, QueryOptionsForString, NullableRoyaltyDefault, Addr, Timestamp, Uint64, RoyaltyDefault, RoyaltyEntry, NullableRoyaltyProtocol, RoyaltyProtocol, RoyaltyPaymentResponse, ArrayOfRoyaltyProtocol } from "./RoyaltyRegistry.types";
+import { Decimal, InstantiateMsg, Config, ExecuteMsg, Timestamp, Uint64, RoyaltyLock, RoyaltyRole, RoyaltyOperation, Uint128, Expiration, UpdateParams, RoyaltyMetadata, ProtocolFee, Coin, QueryMsg, QueryBoundForString, QueryBoundForTupleOfStringAndString, QueryBoundForUint64, QueryOptionsForString, QueryOptionsForTupleOfStringAndString, QueryOptionsForUint64, Addr, ArrayOfRoyaltyPromotion, RoyaltyPromotion, ArrayOfRoyaltyDefault, RoyaltyDefault, RoyaltyEntry, DenomShare, ArrayOfCollectionRoyaltyLocks, CollectionRoyaltyLocks, RoyaltyLocks, ArrayOfRoyaltyProtocol, RoyaltyProtocol, CheckRoyaltiesResponse, CollectionCreatorResponse, NullableCollectionManager, CollectionManager, ArrayOfCollectionManager, NullableRoyaltyDefault, NullableRoyaltyProposal, RoyaltyProposal, RoyaltyDetailsResponse, Boolean, ArrayOfRoyaltyPeriod, RoyaltyPeriod, NullableRoyaltyPromotion, NullableRoyaltyProtocol, RoyaltySyncResponse, ArrayOfCoin, ConfigBoundsResponse, NullableRegisteredProtocol, RegisteredProtocol, ArrayOfRegisteredProtocol, RoyaltyAction, ArrayOfRoyaltyHistoryEntry, RoyaltyHistoryEntry, RoyaltiesInfoResponse, RoyaltyPaymentResponse, ArrayOfRoyaltyPaymentResponse } from "./RoyaltyRegistry.types";
 import { RoyaltyRegistryQueryClient } from "./RoyaltyRegistry.client";
 export const royaltyRegistryQueryKeys = {
   contract: ([{
@@ -18,10 +18,18 @@ export const royaltyRegistryQueryKeys = {
     method: "config",
     args
   }] as const),
+  configBounds: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "config_bounds",
+    args
+  }] as const),
   collectionRoyaltyDefault: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
     method: "collection_royalty_default",
     args
   }] as const),
+  collectionRoyaltyDetails: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "collection_royalty_details",
+    args
+  }] as const),
   collectionRoyaltyProtocol: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
     method: "collection_royalty_protocol",
     args
@@ -33,6 +41,122 @@ export const royaltyRegistryQueryKeys = {
   royaltyPayment: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
     method: "royalty_payment",
     args
+  }] as const),
+  royaltyInfo: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "royalty_info",
+    args
+  }] as const),
+  checkRoyalties: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "check_royalties",
+    args
+  }] as const),
+  collectionCreator: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "collection_creator",
+    args
+  }] as const),
+  collectionRoyaltySync: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "collection_royalty_sync",
+    args
+  }] as const),
+  royaltyPayments: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "royalty_payments",
+    args
+  }] as const),
+  allRoyaltyDefaults: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "all_royalty_defaults",
+    args
+  }] as const),
+  allRoyaltyProtocols: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "all_royalty_protocols",
+    args
+  }] as const),
+  royaltyProtocolsByProtocol: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "royalty_protocols_by_protocol",
+    args
+  }] as const),
+  royaltyDefaultsByRecipient: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "royalty_defaults_by_recipient",
+    args
+  }] as const),
+  royaltyProtocolsByRecipient: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "royalty_protocols_by_recipient",
+    args
+  }] as const),
+  collectionRoyaltyLocks: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "collection_royalty_locks",
+    args
+  }] as const),
+  allRoyaltyLocks: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "all_royalty_locks",
+    args
+  }] as const),
+  collectionRoyaltyFrozen: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "collection_royalty_frozen",
+    args
+  }] as const),
+  royaltyHistory: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "royalty_history",
+    args
+  }] as const),
+  collectionRoyaltyDefaultProposal: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "collection_royalty_default_proposal",
+    args
+  }] as const),
+  collectionRoyaltyProtocolProposal: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "collection_royalty_protocol_proposal",
+    args
+  }] as const),
+  royaltyBalance: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "royalty_balance",
+    args
+  }] as const),
+  royaltyBalances: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "royalty_balances",
+    args
+  }] as const),
+  collectionRoyaltyTotals: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "collection_royalty_totals",
+    args
+  }] as const),
+  recipientRoyaltyTotals: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "recipient_royalty_totals",
+    args
+  }] as const),
+  protocolRoyaltyTotals: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "protocol_royalty_totals",
+    args
+  }] as const),
+  collectionRoyaltyPeriods: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "collection_royalty_periods",
+    args
+  }] as const),
+  collectionRoyaltyPromotion: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "collection_royalty_promotion",
+    args
+  }] as const),
+  allRoyaltyDefaultPromotions: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "all_royalty_default_promotions",
+    args
+  }] as const),
+  allRoyaltyProtocolPromotions: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "all_royalty_protocol_promotions",
+    args
+  }] as const),
+  registeredProtocol: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "registered_protocol",
+    args
+  }] as const),
+  registeredProtocols: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "registered_protocols",
+    args
+  }] as const),
+  collectionManager: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "collection_manager",
+    args
+  }] as const),
+  collectionManagers: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...royaltyRegistryQueryKeys.address(contractAddress)[0],
+    method: "collection_managers",
+    args
   }] as const)
 };
 export const royaltyRegistryQueries = {
@@ -45,6 +169,15 @@ export const royaltyRegistryQueries = {
     ...options,
     enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
   }),
+  configBounds: <TData = ConfigBoundsResponse,>({
+    client,
+    options
+  }: RoyaltyRegistryConfigBoundsQuery<TData>): UseQueryOptions<ConfigBoundsResponse, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.configBounds(client?.contractAddress),
+    queryFn: () => client ? client.configBounds() : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
   collectionRoyaltyDefault: <TData = NullableRoyaltyDefault,>({
     client,
     args,
@@ -57,6 +190,19 @@ export const royaltyRegistryQueries = {
     ...options,
     enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
   }),
+  collectionRoyaltyDetails: <TData = RoyaltyDetailsResponse,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryCollectionRoyaltyDetailsQuery<TData>): UseQueryOptions<RoyaltyDetailsResponse, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.collectionRoyaltyDetails(client?.contractAddress, args),
+    queryFn: () => client ? client.collectionRoyaltyDetails({
+      collection: args.collection,
+      protocol: args.protocol
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
   collectionRoyaltyProtocol: <TData = NullableRoyaltyProtocol,>({
     client,
     args,
@@ -91,83 +237,985 @@ export const royaltyRegistryQueries = {
     queryKey: royaltyRegistryQueryKeys.royaltyPayment(client?.contractAddress, args),
     queryFn: () => client ? client.royaltyPayment({
       collection: args.collection,
+      denom: args.denom,
       protocol: args.protocol
     }) : Promise.reject(new Error("Invalid client")),
     ...options,
     enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
-  })
-};
-export interface RoyaltyRegistryReactQuery<TResponse, TData = TResponse> {
-  client: RoyaltyRegistryQueryClient | undefined;
-  options?: UseQueryOptions<TResponse, Error, TData>;
-}
-export interface RoyaltyRegistryRoyaltyPaymentQuery<TData> extends RoyaltyRegistryReactQuery<RoyaltyPaymentResponse, TData> {
-  args: {
-    collection: string;
-    protocol?: string;
-  };
-}
-export function useRoyaltyRegistryRoyaltyPaymentQuery<TData = RoyaltyPaymentResponse>({
-  client,
-  args,
-  options
-}: RoyaltyRegistryRoyaltyPaymentQuery<TData>) {
-  return useQuery<RoyaltyPaymentResponse, Error, TData>(royaltyRegistryQueryKeys.royaltyPayment(client?.contractAddress, args), () => client ? client.royaltyPayment({
-    collection: args.collection,
-    protocol: args.protocol
-  }) : Promise.reject(new Error("Invalid client")), { ...options,
+  }),
+  royaltyInfo: <TData = RoyaltiesInfoResponse,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryRoyaltyInfoQuery<TData>): UseQueryOptions<RoyaltiesInfoResponse, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.royaltyInfo(client?.contractAddress, args),
+    queryFn: () => client ? client.royaltyInfo({
+      collection: args.collection,
+      protocol: args.protocol,
+      salePrice: args.salePrice,
+      tokenId: args.tokenId
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
     enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
-  });
-}
-export interface RoyaltyRegistryRoyaltyProtocolByCollectionQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfRoyaltyProtocol, TData> {
-  args: {
-    collection: string;
-    queryOptions?: QueryOptionsForString;
-  };
-}
-export function useRoyaltyRegistryRoyaltyProtocolByCollectionQuery<TData = ArrayOfRoyaltyProtocol>({
-  client,
-  args,
-  options
-}: RoyaltyRegistryRoyaltyProtocolByCollectionQuery<TData>) {
-  return useQuery<ArrayOfRoyaltyProtocol, Error, TData>(royaltyRegistryQueryKeys.royaltyProtocolByCollection(client?.contractAddress, args), () => client ? client.royaltyProtocolByCollection({
-    collection: args.collection,
-    queryOptions: args.queryOptions
-  }) : Promise.reject(new Error("Invalid client")), { ...options,
+  }),
+  checkRoyalties: <TData = CheckRoyaltiesResponse,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryCheckRoyaltiesQuery<TData>): UseQueryOptions<CheckRoyaltiesResponse, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.checkRoyalties(client?.contractAddress, args),
+    queryFn: () => client ? client.checkRoyalties({
+      collection: args.collection,
+      protocol: args.protocol
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
     enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
-  });
-}
-export interface RoyaltyRegistryCollectionRoyaltyProtocolQuery<TData> extends RoyaltyRegistryReactQuery<NullableRoyaltyProtocol, TData> {
-  args: {
-    collection: string;
-    protocol: string;
-  };
-}
-export function useRoyaltyRegistryCollectionRoyaltyProtocolQuery<TData = NullableRoyaltyProtocol>({
-  client,
-  args,
-  options
-}: RoyaltyRegistryCollectionRoyaltyProtocolQuery<TData>) {
-  return useQuery<NullableRoyaltyProtocol, Error, TData>(royaltyRegistryQueryKeys.collectionRoyaltyProtocol(client?.contractAddress, args), () => client ? client.collectionRoyaltyProtocol({
-    collection: args.collection,
-    protocol: args.protocol
-  }) : Promise.reject(new Error("Invalid client")), { ...options,
+  }),
+  collectionCreator: <TData = CollectionCreatorResponse,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryCollectionCreatorQuery<TData>): UseQueryOptions<CollectionCreatorResponse, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.collectionCreator(client?.contractAddress, args),
+    queryFn: () => client ? client.collectionCreator({
+      collection: args.collection
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
     enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
-  });
-}
-export interface RoyaltyRegistryCollectionRoyaltyDefaultQuery<TData> extends RoyaltyRegistryReactQuery<NullableRoyaltyDefault, TData> {
-  args: {
-    collection: string;
-  };
-}
-export function useRoyaltyRegistryCollectionRoyaltyDefaultQuery<TData = NullableRoyaltyDefault>({
-  client,
-  args,
-  options
-}: RoyaltyRegistryCollectionRoyaltyDefaultQuery<TData>) {
-  return useQuery<NullableRoyaltyDefault, Error, TData>(royaltyRegistryQueryKeys.collectionRoyaltyDefault(client?.contractAddress, args), () => client ? client.collectionRoyaltyDefault({
-    collection: args.collection
-  }) : Promise.reject(new Error("Invalid client")), { ...options,
+  }),
+  collectionRoyaltySync: <TData = RoyaltySyncResponse,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryCollectionRoyaltySyncQuery<TData>): UseQueryOptions<RoyaltySyncResponse, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.collectionRoyaltySync(client?.contractAddress, args),
+    queryFn: () => client ? client.collectionRoyaltySync({
+      collection: args.collection
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  royaltyPayments: <TData = ArrayOfRoyaltyPaymentResponse,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryRoyaltyPaymentsQuery<TData>): UseQueryOptions<ArrayOfRoyaltyPaymentResponse, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.royaltyPayments(client?.contractAddress, args),
+    queryFn: () => client ? client.royaltyPayments({
+      collections: args.collections,
+      denom: args.denom,
+      protocol: args.protocol
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  allRoyaltyDefaults: <TData = ArrayOfRoyaltyDefault,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryAllRoyaltyDefaultsQuery<TData>): UseQueryOptions<ArrayOfRoyaltyDefault, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.allRoyaltyDefaults(client?.contractAddress, args),
+    queryFn: () => client ? client.allRoyaltyDefaults({
+      queryOptions: args.queryOptions
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  allRoyaltyProtocols: <TData = ArrayOfRoyaltyProtocol,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryAllRoyaltyProtocolsQuery<TData>): UseQueryOptions<ArrayOfRoyaltyProtocol, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.allRoyaltyProtocols(client?.contractAddress, args),
+    queryFn: () => client ? client.allRoyaltyProtocols({
+      queryOptions: args.queryOptions
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  royaltyProtocolsByProtocol: <TData = ArrayOfRoyaltyProtocol,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryRoyaltyProtocolsByProtocolQuery<TData>): UseQueryOptions<ArrayOfRoyaltyProtocol, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.royaltyProtocolsByProtocol(client?.contractAddress, args),
+    queryFn: () => client ? client.royaltyProtocolsByProtocol({
+      protocol: args.protocol,
+      queryOptions: args.queryOptions
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  royaltyDefaultsByRecipient: <TData = ArrayOfRoyaltyDefault,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryRoyaltyDefaultsByRecipientQuery<TData>): UseQueryOptions<ArrayOfRoyaltyDefault, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.royaltyDefaultsByRecipient(client?.contractAddress, args),
+    queryFn: () => client ? client.royaltyDefaultsByRecipient({
+      queryOptions: args.queryOptions,
+      recipient: args.recipient
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  royaltyProtocolsByRecipient: <TData = ArrayOfRoyaltyProtocol,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryRoyaltyProtocolsByRecipientQuery<TData>): UseQueryOptions<ArrayOfRoyaltyProtocol, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.royaltyProtocolsByRecipient(client?.contractAddress, args),
+    queryFn: () => client ? client.royaltyProtocolsByRecipient({
+      queryOptions: args.queryOptions,
+      recipient: args.recipient
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  collectionRoyaltyLocks: <TData = RoyaltyLocks,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryCollectionRoyaltyLocksQuery<TData>): UseQueryOptions<RoyaltyLocks, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.collectionRoyaltyLocks(client?.contractAddress, args),
+    queryFn: () => client ? client.collectionRoyaltyLocks({
+      collection: args.collection
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  allRoyaltyLocks: <TData = ArrayOfCollectionRoyaltyLocks,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryAllRoyaltyLocksQuery<TData>): UseQueryOptions<ArrayOfCollectionRoyaltyLocks, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.allRoyaltyLocks(client?.contractAddress, args),
+    queryFn: () => client ? client.allRoyaltyLocks({
+      queryOptions: args.queryOptions
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  collectionRoyaltyFrozen: <TData = Boolean,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryCollectionRoyaltyFrozenQuery<TData>): UseQueryOptions<Boolean, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.collectionRoyaltyFrozen(client?.contractAddress, args),
+    queryFn: () => client ? client.collectionRoyaltyFrozen({
+      collection: args.collection
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  royaltyHistory: <TData = ArrayOfRoyaltyHistoryEntry,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryRoyaltyHistoryQuery<TData>): UseQueryOptions<ArrayOfRoyaltyHistoryEntry, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.royaltyHistory(client?.contractAddress, args),
+    queryFn: () => client ? client.royaltyHistory({
+      collection: args.collection,
+      queryOptions: args.queryOptions
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  collectionRoyaltyDefaultProposal: <TData = NullableRoyaltyProposal,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryCollectionRoyaltyDefaultProposalQuery<TData>): UseQueryOptions<NullableRoyaltyProposal, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.collectionRoyaltyDefaultProposal(client?.contractAddress, args),
+    queryFn: () => client ? client.collectionRoyaltyDefaultProposal({
+      collection: args.collection
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  collectionRoyaltyProtocolProposal: <TData = NullableRoyaltyProposal,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryCollectionRoyaltyProtocolProposalQuery<TData>): UseQueryOptions<NullableRoyaltyProposal, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.collectionRoyaltyProtocolProposal(client?.contractAddress, args),
+    queryFn: () => client ? client.collectionRoyaltyProtocolProposal({
+      collection: args.collection,
+      protocol: args.protocol
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  royaltyBalance: <TData = Coin,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryRoyaltyBalanceQuery<TData>): UseQueryOptions<Coin, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.royaltyBalance(client?.contractAddress, args),
+    queryFn: () => client ? client.royaltyBalance({
+      denom: args.denom,
+      recipient: args.recipient
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  royaltyBalances: <TData = ArrayOfCoin,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryRoyaltyBalancesQuery<TData>): UseQueryOptions<ArrayOfCoin, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.royaltyBalances(client?.contractAddress, args),
+    queryFn: () => client ? client.royaltyBalances({
+      queryOptions: args.queryOptions,
+      recipient: args.recipient
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  collectionRoyaltyTotals: <TData = ArrayOfCoin,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryCollectionRoyaltyTotalsQuery<TData>): UseQueryOptions<ArrayOfCoin, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.collectionRoyaltyTotals(client?.contractAddress, args),
+    queryFn: () => client ? client.collectionRoyaltyTotals({
+      collection: args.collection,
+      queryOptions: args.queryOptions
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  recipientRoyaltyTotals: <TData = ArrayOfCoin,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryRecipientRoyaltyTotalsQuery<TData>): UseQueryOptions<ArrayOfCoin, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.recipientRoyaltyTotals(client?.contractAddress, args),
+    queryFn: () => client ? client.recipientRoyaltyTotals({
+      queryOptions: args.queryOptions,
+      recipient: args.recipient
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  protocolRoyaltyTotals: <TData = ArrayOfCoin,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryProtocolRoyaltyTotalsQuery<TData>): UseQueryOptions<ArrayOfCoin, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.protocolRoyaltyTotals(client?.contractAddress, args),
+    queryFn: () => client ? client.protocolRoyaltyTotals({
+      protocol: args.protocol,
+      queryOptions: args.queryOptions
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  collectionRoyaltyPeriods: <TData = ArrayOfRoyaltyPeriod,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryCollectionRoyaltyPeriodsQuery<TData>): UseQueryOptions<ArrayOfRoyaltyPeriod, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.collectionRoyaltyPeriods(client?.contractAddress, args),
+    queryFn: () => client ? client.collectionRoyaltyPeriods({
+      collection: args.collection,
+      queryOptions: args.queryOptions
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  collectionRoyaltyPromotion: <TData = NullableRoyaltyPromotion,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryCollectionRoyaltyPromotionQuery<TData>): UseQueryOptions<NullableRoyaltyPromotion, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.collectionRoyaltyPromotion(client?.contractAddress, args),
+    queryFn: () => client ? client.collectionRoyaltyPromotion({
+      collection: args.collection,
+      protocol: args.protocol
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  allRoyaltyDefaultPromotions: <TData = ArrayOfRoyaltyPromotion,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryAllRoyaltyDefaultPromotionsQuery<TData>): UseQueryOptions<ArrayOfRoyaltyPromotion, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.allRoyaltyDefaultPromotions(client?.contractAddress, args),
+    queryFn: () => client ? client.allRoyaltyDefaultPromotions({
+      queryOptions: args.queryOptions
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  allRoyaltyProtocolPromotions: <TData = ArrayOfRoyaltyPromotion,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryAllRoyaltyProtocolPromotionsQuery<TData>): UseQueryOptions<ArrayOfRoyaltyPromotion, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.allRoyaltyProtocolPromotions(client?.contractAddress, args),
+    queryFn: () => client ? client.allRoyaltyProtocolPromotions({
+      queryOptions: args.queryOptions
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  registeredProtocol: <TData = NullableRegisteredProtocol,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryRegisteredProtocolQuery<TData>): UseQueryOptions<NullableRegisteredProtocol, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.registeredProtocol(client?.contractAddress, args),
+    queryFn: () => client ? client.registeredProtocol({
+      protocol: args.protocol
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  registeredProtocols: <TData = ArrayOfRegisteredProtocol,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryRegisteredProtocolsQuery<TData>): UseQueryOptions<ArrayOfRegisteredProtocol, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.registeredProtocols(client?.contractAddress, args),
+    queryFn: () => client ? client.registeredProtocols({
+      queryOptions: args.queryOptions
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  collectionManager: <TData = NullableCollectionManager,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryCollectionManagerQuery<TData>): UseQueryOptions<NullableCollectionManager, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.collectionManager(client?.contractAddress, args),
+    queryFn: () => client ? client.collectionManager({
+      collection: args.collection,
+      manager: args.manager
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  }),
+  collectionManagers: <TData = ArrayOfCollectionManager,>({
+    client,
+    args,
+    options
+  }: RoyaltyRegistryCollectionManagersQuery<TData>): UseQueryOptions<ArrayOfCollectionManager, Error, TData> => ({
+    queryKey: royaltyRegistryQueryKeys.collectionManagers(client?.contractAddress, args),
+    queryFn: () => client ? client.collectionManagers({
+      collection: args.collection,
+      queryOptions: args.queryOptions
+    }) : Promise.reject(new Error("Invalid client")),
+    ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  })
+};
+export interface RoyaltyRegistryReactQuery<TResponse, TData = TResponse> {
+  client: RoyaltyRegistryQueryClient | undefined;
+  options?: UseQueryOptions<TResponse, Error, TData>;
+}
+export interface RoyaltyRegistryCollectionManagersQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfCollectionManager, TData> {
+  args: {
+    collection: string;
+    queryOptions?: QueryOptionsForString;
+  };
+}
+export function useRoyaltyRegistryCollectionManagersQuery<TData = ArrayOfCollectionManager>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionManagersQuery<TData>) {
+  return useQuery<ArrayOfCollectionManager, Error, TData>(royaltyRegistryQueryKeys.collectionManagers(client?.contractAddress, args), () => client ? client.collectionManagers({
+    collection: args.collection,
+    queryOptions: args.queryOptions
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCollectionManagerQuery<TData> extends RoyaltyRegistryReactQuery<NullableCollectionManager, TData> {
+  args: {
+    collection: string;
+    manager: string;
+  };
+}
+export function useRoyaltyRegistryCollectionManagerQuery<TData = NullableCollectionManager>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionManagerQuery<TData>) {
+  return useQuery<NullableCollectionManager, Error, TData>(royaltyRegistryQueryKeys.collectionManager(client?.contractAddress, args), () => client ? client.collectionManager({
+    collection: args.collection,
+    manager: args.manager
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryRegisteredProtocolsQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfRegisteredProtocol, TData> {
+  args: {
+    queryOptions?: QueryOptionsForString;
+  };
+}
+export function useRoyaltyRegistryRegisteredProtocolsQuery<TData = ArrayOfRegisteredProtocol>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryRegisteredProtocolsQuery<TData>) {
+  return useQuery<ArrayOfRegisteredProtocol, Error, TData>(royaltyRegistryQueryKeys.registeredProtocols(client?.contractAddress, args), () => client ? client.registeredProtocols({
+    queryOptions: args.queryOptions
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryRegisteredProtocolQuery<TData> extends RoyaltyRegistryReactQuery<NullableRegisteredProtocol, TData> {
+  args: {
+    protocol: string;
+  };
+}
+export function useRoyaltyRegistryRegisteredProtocolQuery<TData = NullableRegisteredProtocol>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryRegisteredProtocolQuery<TData>) {
+  return useQuery<NullableRegisteredProtocol, Error, TData>(royaltyRegistryQueryKeys.registeredProtocol(client?.contractAddress, args), () => client ? client.registeredProtocol({
+    protocol: args.protocol
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryAllRoyaltyProtocolPromotionsQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfRoyaltyPromotion, TData> {
+  args: {
+    queryOptions?: QueryOptionsForTupleOfStringAndString;
+  };
+}
+export function useRoyaltyRegistryAllRoyaltyProtocolPromotionsQuery<TData = ArrayOfRoyaltyPromotion>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryAllRoyaltyProtocolPromotionsQuery<TData>) {
+  return useQuery<ArrayOfRoyaltyPromotion, Error, TData>(royaltyRegistryQueryKeys.allRoyaltyProtocolPromotions(client?.contractAddress, args), () => client ? client.allRoyaltyProtocolPromotions({
+    queryOptions: args.queryOptions
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryAllRoyaltyDefaultPromotionsQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfRoyaltyPromotion, TData> {
+  args: {
+    queryOptions?: QueryOptionsForString;
+  };
+}
+export function useRoyaltyRegistryAllRoyaltyDefaultPromotionsQuery<TData = ArrayOfRoyaltyPromotion>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryAllRoyaltyDefaultPromotionsQuery<TData>) {
+  return useQuery<ArrayOfRoyaltyPromotion, Error, TData>(royaltyRegistryQueryKeys.allRoyaltyDefaultPromotions(client?.contractAddress, args), () => client ? client.allRoyaltyDefaultPromotions({
+    queryOptions: args.queryOptions
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCollectionRoyaltyPromotionQuery<TData> extends RoyaltyRegistryReactQuery<NullableRoyaltyPromotion, TData> {
+  args: {
+    collection: string;
+    protocol?: string;
+  };
+}
+export function useRoyaltyRegistryCollectionRoyaltyPromotionQuery<TData = NullableRoyaltyPromotion>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionRoyaltyPromotionQuery<TData>) {
+  return useQuery<NullableRoyaltyPromotion, Error, TData>(royaltyRegistryQueryKeys.collectionRoyaltyPromotion(client?.contractAddress, args), () => client ? client.collectionRoyaltyPromotion({
+    collection: args.collection,
+    protocol: args.protocol
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCollectionRoyaltyPeriodsQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfRoyaltyPeriod, TData> {
+  args: {
+    collection: string;
+    queryOptions?: QueryOptionsForUint64;
+  };
+}
+export function useRoyaltyRegistryCollectionRoyaltyPeriodsQuery<TData = ArrayOfRoyaltyPeriod>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionRoyaltyPeriodsQuery<TData>) {
+  return useQuery<ArrayOfRoyaltyPeriod, Error, TData>(royaltyRegistryQueryKeys.collectionRoyaltyPeriods(client?.contractAddress, args), () => client ? client.collectionRoyaltyPeriods({
+    collection: args.collection,
+    queryOptions: args.queryOptions
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryProtocolRoyaltyTotalsQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfCoin, TData> {
+  args: {
+    protocol: string;
+    queryOptions?: QueryOptionsForString;
+  };
+}
+export function useRoyaltyRegistryProtocolRoyaltyTotalsQuery<TData = ArrayOfCoin>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryProtocolRoyaltyTotalsQuery<TData>) {
+  return useQuery<ArrayOfCoin, Error, TData>(royaltyRegistryQueryKeys.protocolRoyaltyTotals(client?.contractAddress, args), () => client ? client.protocolRoyaltyTotals({
+    protocol: args.protocol,
+    queryOptions: args.queryOptions
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryRecipientRoyaltyTotalsQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfCoin, TData> {
+  args: {
+    queryOptions?: QueryOptionsForString;
+    recipient: string;
+  };
+}
+export function useRoyaltyRegistryRecipientRoyaltyTotalsQuery<TData = ArrayOfCoin>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryRecipientRoyaltyTotalsQuery<TData>) {
+  return useQuery<ArrayOfCoin, Error, TData>(royaltyRegistryQueryKeys.recipientRoyaltyTotals(client?.contractAddress, args), () => client ? client.recipientRoyaltyTotals({
+    queryOptions: args.queryOptions,
+    recipient: args.recipient
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCollectionRoyaltyTotalsQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfCoin, TData> {
+  args: {
+    collection: string;
+    queryOptions?: QueryOptionsForString;
+  };
+}
+export function useRoyaltyRegistryCollectionRoyaltyTotalsQuery<TData = ArrayOfCoin>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionRoyaltyTotalsQuery<TData>) {
+  return useQuery<ArrayOfCoin, Error, TData>(royaltyRegistryQueryKeys.collectionRoyaltyTotals(client?.contractAddress, args), () => client ? client.collectionRoyaltyTotals({
+    collection: args.collection,
+    queryOptions: args.queryOptions
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryRoyaltyBalancesQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfCoin, TData> {
+  args: {
+    queryOptions?: QueryOptionsForString;
+    recipient: string;
+  };
+}
+export function useRoyaltyRegistryRoyaltyBalancesQuery<TData = ArrayOfCoin>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryRoyaltyBalancesQuery<TData>) {
+  return useQuery<ArrayOfCoin, Error, TData>(royaltyRegistryQueryKeys.royaltyBalances(client?.contractAddress, args), () => client ? client.royaltyBalances({
+    queryOptions: args.queryOptions,
+    recipient: args.recipient
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryRoyaltyBalanceQuery<TData> extends RoyaltyRegistryReactQuery<Coin, TData> {
+  args: {
+    denom: string;
+    recipient: string;
+  };
+}
+export function useRoyaltyRegistryRoyaltyBalanceQuery<TData = Coin>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryRoyaltyBalanceQuery<TData>) {
+  return useQuery<Coin, Error, TData>(royaltyRegistryQueryKeys.royaltyBalance(client?.contractAddress, args), () => client ? client.royaltyBalance({
+    denom: args.denom,
+    recipient: args.recipient
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCollectionRoyaltyProtocolProposalQuery<TData> extends RoyaltyRegistryReactQuery<NullableRoyaltyProposal, TData> {
+  args: {
+    collection: string;
+    protocol: string;
+  };
+}
+export function useRoyaltyRegistryCollectionRoyaltyProtocolProposalQuery<TData = NullableRoyaltyProposal>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionRoyaltyProtocolProposalQuery<TData>) {
+  return useQuery<NullableRoyaltyProposal, Error, TData>(royaltyRegistryQueryKeys.collectionRoyaltyProtocolProposal(client?.contractAddress, args), () => client ? client.collectionRoyaltyProtocolProposal({
+    collection: args.collection,
+    protocol: args.protocol
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCollectionRoyaltyDefaultProposalQuery<TData> extends RoyaltyRegistryReactQuery<NullableRoyaltyProposal, TData> {
+  args: {
+    collection: string;
+  };
+}
+export function useRoyaltyRegistryCollectionRoyaltyDefaultProposalQuery<TData = NullableRoyaltyProposal>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionRoyaltyDefaultProposalQuery<TData>) {
+  return useQuery<NullableRoyaltyProposal, Error, TData>(royaltyRegistryQueryKeys.collectionRoyaltyDefaultProposal(client?.contractAddress, args), () => client ? client.collectionRoyaltyDefaultProposal({
+    collection: args.collection
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryRoyaltyHistoryQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfRoyaltyHistoryEntry, TData> {
+  args: {
+    collection: string;
+    queryOptions?: QueryOptionsForUint64;
+  };
+}
+export function useRoyaltyRegistryRoyaltyHistoryQuery<TData = ArrayOfRoyaltyHistoryEntry>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryRoyaltyHistoryQuery<TData>) {
+  return useQuery<ArrayOfRoyaltyHistoryEntry, Error, TData>(royaltyRegistryQueryKeys.royaltyHistory(client?.contractAddress, args), () => client ? client.royaltyHistory({
+    collection: args.collection,
+    queryOptions: args.queryOptions
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCollectionRoyaltyFrozenQuery<TData> extends RoyaltyRegistryReactQuery<Boolean, TData> {
+  args: {
+    collection: string;
+  };
+}
+export function useRoyaltyRegistryCollectionRoyaltyFrozenQuery<TData = Boolean>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionRoyaltyFrozenQuery<TData>) {
+  return useQuery<Boolean, Error, TData>(royaltyRegistryQueryKeys.collectionRoyaltyFrozen(client?.contractAddress, args), () => client ? client.collectionRoyaltyFrozen({
+    collection: args.collection
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryAllRoyaltyLocksQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfCollectionRoyaltyLocks, TData> {
+  args: {
+    queryOptions?: QueryOptionsForString;
+  };
+}
+export function useRoyaltyRegistryAllRoyaltyLocksQuery<TData = ArrayOfCollectionRoyaltyLocks>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryAllRoyaltyLocksQuery<TData>) {
+  return useQuery<ArrayOfCollectionRoyaltyLocks, Error, TData>(royaltyRegistryQueryKeys.allRoyaltyLocks(client?.contractAddress, args), () => client ? client.allRoyaltyLocks({
+    queryOptions: args.queryOptions
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCollectionRoyaltyLocksQuery<TData> extends RoyaltyRegistryReactQuery<RoyaltyLocks, TData> {
+  args: {
+    collection: string;
+  };
+}
+export function useRoyaltyRegistryCollectionRoyaltyLocksQuery<TData = RoyaltyLocks>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionRoyaltyLocksQuery<TData>) {
+  return useQuery<RoyaltyLocks, Error, TData>(royaltyRegistryQueryKeys.collectionRoyaltyLocks(client?.contractAddress, args), () => client ? client.collectionRoyaltyLocks({
+    collection: args.collection
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryRoyaltyProtocolsByRecipientQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfRoyaltyProtocol, TData> {
+  args: {
+    queryOptions?: QueryOptionsForTupleOfStringAndString;
+    recipient: string;
+  };
+}
+export function useRoyaltyRegistryRoyaltyProtocolsByRecipientQuery<TData = ArrayOfRoyaltyProtocol>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryRoyaltyProtocolsByRecipientQuery<TData>) {
+  return useQuery<ArrayOfRoyaltyProtocol, Error, TData>(royaltyRegistryQueryKeys.royaltyProtocolsByRecipient(client?.contractAddress, args), () => client ? client.royaltyProtocolsByRecipient({
+    queryOptions: args.queryOptions,
+    recipient: args.recipient
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryRoyaltyDefaultsByRecipientQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfRoyaltyDefault, TData> {
+  args: {
+    queryOptions?: QueryOptionsForString;
+    recipient: string;
+  };
+}
+export function useRoyaltyRegistryRoyaltyDefaultsByRecipientQuery<TData = ArrayOfRoyaltyDefault>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryRoyaltyDefaultsByRecipientQuery<TData>) {
+  return useQuery<ArrayOfRoyaltyDefault, Error, TData>(royaltyRegistryQueryKeys.royaltyDefaultsByRecipient(client?.contractAddress, args), () => client ? client.royaltyDefaultsByRecipient({
+    queryOptions: args.queryOptions,
+    recipient: args.recipient
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryRoyaltyProtocolsByProtocolQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfRoyaltyProtocol, TData> {
+  args: {
+    protocol: string;
+    queryOptions?: QueryOptionsForString;
+  };
+}
+export function useRoyaltyRegistryRoyaltyProtocolsByProtocolQuery<TData = ArrayOfRoyaltyProtocol>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryRoyaltyProtocolsByProtocolQuery<TData>) {
+  return useQuery<ArrayOfRoyaltyProtocol, Error, TData>(royaltyRegistryQueryKeys.royaltyProtocolsByProtocol(client?.contractAddress, args), () => client ? client.royaltyProtocolsByProtocol({
+    protocol: args.protocol,
+    queryOptions: args.queryOptions
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryAllRoyaltyProtocolsQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfRoyaltyProtocol, TData> {
+  args: {
+    queryOptions?: QueryOptionsForTupleOfStringAndString;
+  };
+}
+export function useRoyaltyRegistryAllRoyaltyProtocolsQuery<TData = ArrayOfRoyaltyProtocol>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryAllRoyaltyProtocolsQuery<TData>) {
+  return useQuery<ArrayOfRoyaltyProtocol, Error, TData>(royaltyRegistryQueryKeys.allRoyaltyProtocols(client?.contractAddress, args), () => client ? client.allRoyaltyProtocols({
+    queryOptions: args.queryOptions
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryAllRoyaltyDefaultsQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfRoyaltyDefault, TData> {
+  args: {
+    queryOptions?: QueryOptionsForString;
+  };
+}
+export function useRoyaltyRegistryAllRoyaltyDefaultsQuery<TData = ArrayOfRoyaltyDefault>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryAllRoyaltyDefaultsQuery<TData>) {
+  return useQuery<ArrayOfRoyaltyDefault, Error, TData>(royaltyRegistryQueryKeys.allRoyaltyDefaults(client?.contractAddress, args), () => client ? client.allRoyaltyDefaults({
+    queryOptions: args.queryOptions
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryRoyaltyPaymentsQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfRoyaltyPaymentResponse, TData> {
+  args: {
+    collections: string[];
+    denom?: string;
+    protocol?: string;
+  };
+}
+export function useRoyaltyRegistryRoyaltyPaymentsQuery<TData = ArrayOfRoyaltyPaymentResponse>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryRoyaltyPaymentsQuery<TData>) {
+  return useQuery<ArrayOfRoyaltyPaymentResponse, Error, TData>(royaltyRegistryQueryKeys.royaltyPayments(client?.contractAddress, args), () => client ? client.royaltyPayments({
+    collections: args.collections,
+    denom: args.denom,
+    protocol: args.protocol
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCollectionRoyaltySyncQuery<TData> extends RoyaltyRegistryReactQuery<RoyaltySyncResponse, TData> {
+  args: {
+    collection: string;
+  };
+}
+export function useRoyaltyRegistryCollectionRoyaltySyncQuery<TData = RoyaltySyncResponse>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionRoyaltySyncQuery<TData>) {
+  return useQuery<RoyaltySyncResponse, Error, TData>(royaltyRegistryQueryKeys.collectionRoyaltySync(client?.contractAddress, args), () => client ? client.collectionRoyaltySync({
+    collection: args.collection
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCollectionCreatorQuery<TData> extends RoyaltyRegistryReactQuery<CollectionCreatorResponse, TData> {
+  args: {
+    collection: string;
+  };
+}
+export function useRoyaltyRegistryCollectionCreatorQuery<TData = CollectionCreatorResponse>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionCreatorQuery<TData>) {
+  return useQuery<CollectionCreatorResponse, Error, TData>(royaltyRegistryQueryKeys.collectionCreator(client?.contractAddress, args), () => client ? client.collectionCreator({
+    collection: args.collection
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCheckRoyaltiesQuery<TData> extends RoyaltyRegistryReactQuery<CheckRoyaltiesResponse, TData> {
+  args: {
+    collection: string;
+    protocol?: string;
+  };
+}
+export function useRoyaltyRegistryCheckRoyaltiesQuery<TData = CheckRoyaltiesResponse>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCheckRoyaltiesQuery<TData>) {
+  return useQuery<CheckRoyaltiesResponse, Error, TData>(royaltyRegistryQueryKeys.checkRoyalties(client?.contractAddress, args), () => client ? client.checkRoyalties({
+    collection: args.collection,
+    protocol: args.protocol
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryRoyaltyInfoQuery<TData> extends RoyaltyRegistryReactQuery<RoyaltiesInfoResponse, TData> {
+  args: {
+    collection: string;
+    protocol?: string;
+    salePrice: Uint128;
+    tokenId: string;
+  };
+}
+export function useRoyaltyRegistryRoyaltyInfoQuery<TData = RoyaltiesInfoResponse>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryRoyaltyInfoQuery<TData>) {
+  return useQuery<RoyaltiesInfoResponse, Error, TData>(royaltyRegistryQueryKeys.royaltyInfo(client?.contractAddress, args), () => client ? client.royaltyInfo({
+    collection: args.collection,
+    protocol: args.protocol,
+    salePrice: args.salePrice,
+    tokenId: args.tokenId
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryRoyaltyPaymentQuery<TData> extends RoyaltyRegistryReactQuery<RoyaltyPaymentResponse, TData> {
+  args: {
+    collection: string;
+    denom?: string;
+    protocol?: string;
+  };
+}
+export function useRoyaltyRegistryRoyaltyPaymentQuery<TData = RoyaltyPaymentResponse>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryRoyaltyPaymentQuery<TData>) {
+  return useQuery<RoyaltyPaymentResponse, Error, TData>(royaltyRegistryQueryKeys.royaltyPayment(client?.contractAddress, args), () => client ? client.royaltyPayment({
+    collection: args.collection,
+    denom: args.denom,
+    protocol: args.protocol
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryRoyaltyProtocolByCollectionQuery<TData> extends RoyaltyRegistryReactQuery<ArrayOfRoyaltyProtocol, TData> {
+  args: {
+    collection: string;
+    queryOptions?: QueryOptionsForString;
+  };
+}
+export function useRoyaltyRegistryRoyaltyProtocolByCollectionQuery<TData = ArrayOfRoyaltyProtocol>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryRoyaltyProtocolByCollectionQuery<TData>) {
+  return useQuery<ArrayOfRoyaltyProtocol, Error, TData>(royaltyRegistryQueryKeys.royaltyProtocolByCollection(client?.contractAddress, args), () => client ? client.royaltyProtocolByCollection({
+    collection: args.collection,
+    queryOptions: args.queryOptions
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCollectionRoyaltyProtocolQuery<TData> extends RoyaltyRegistryReactQuery<NullableRoyaltyProtocol, TData> {
+  args: {
+    collection: string;
+    protocol: string;
+  };
+}
+export function useRoyaltyRegistryCollectionRoyaltyProtocolQuery<TData = NullableRoyaltyProtocol>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionRoyaltyProtocolQuery<TData>) {
+  return useQuery<NullableRoyaltyProtocol, Error, TData>(royaltyRegistryQueryKeys.collectionRoyaltyProtocol(client?.contractAddress, args), () => client ? client.collectionRoyaltyProtocol({
+    collection: args.collection,
+    protocol: args.protocol
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCollectionRoyaltyDetailsQuery<TData> extends RoyaltyRegistryReactQuery<RoyaltyDetailsResponse, TData> {
+  args: {
+    collection: string;
+    protocol?: string;
+  };
+}
+export function useRoyaltyRegistryCollectionRoyaltyDetailsQuery<TData = RoyaltyDetailsResponse>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionRoyaltyDetailsQuery<TData>) {
+  return useQuery<RoyaltyDetailsResponse, Error, TData>(royaltyRegistryQueryKeys.collectionRoyaltyDetails(client?.contractAddress, args), () => client ? client.collectionRoyaltyDetails({
+    collection: args.collection,
+    protocol: args.protocol
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryCollectionRoyaltyDefaultQuery<TData> extends RoyaltyRegistryReactQuery<NullableRoyaltyDefault, TData> {
+  args: {
+    collection: string;
+  };
+}
+export function useRoyaltyRegistryCollectionRoyaltyDefaultQuery<TData = NullableRoyaltyDefault>({
+  client,
+  args,
+  options
+}: RoyaltyRegistryCollectionRoyaltyDefaultQuery<TData>) {
+  return useQuery<NullableRoyaltyDefault, Error, TData>(royaltyRegistryQueryKeys.collectionRoyaltyDefault(client?.contractAddress, args), () => client ? client.collectionRoyaltyDefault({
+    collection: args.collection
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface RoyaltyRegistryConfigBoundsQuery<TData> extends RoyaltyRegistryReactQuery<ConfigBoundsResponse, TData> {}
+export function useRoyaltyRegistryConfigBoundsQuery<TData = ConfigBoundsResponse>({
+  client,
+  options
+}: RoyaltyRegistryConfigBoundsQuery<TData>) {
+  return useQuery<ConfigBoundsResponse, Error, TData>(royaltyRegistryQueryKeys.configBounds(client?.contractAddress), () => client ? client.configBounds() : Promise.reject(new Error("Invalid client")), { ...options,
     enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
   });
 }
@@ -9,13 +9,19 @@ export interface InstantiateMsg {
   config: Config;
 }
 export interface Config {
+  max_share: Decimal;
   max_share_delta: Decimal;
+  min_share: Decimal;
   update_wait_period: number;
 }
 export type ExecuteMsg = {
   initialize_collection_royalty: {
     collection: string;
   };
+} | {
+  sync_collection_royalty: {
+    collection: string;
+  };
 } | {
   set_collection_royalty_default: {
     collection: string;
@@ -27,6 +33,7 @@ export type ExecuteMsg = {
     collection: string;
     decrement?: boolean | null;
     recipient?: string | null;
+    share?: Decimal | null;
     share_delta?: Decimal | null;
   };
 } | {
@@ -42,15 +49,205 @@ export type ExecuteMsg = {
     decrement?: boolean | null;
     protocol: string;
     recipient?: string | null;
+    share?: Decimal | null;
+    share_delta?: Decimal | null;
+  };
+} | {
+  propose_collection_royalty_default: {
+    collection: string;
+    decrement?: boolean | null;
+    recipient?: string | null;
+    share?: Decimal | null;
+    share_delta?: Decimal | null;
+  };
+} | {
+  apply_collection_royalty_default: {
+    collection: string;
+  };
+} | {
+  cancel_collection_royalty_default: {
+    collection: string;
+  };
+} | {
+  propose_collection_royalty_protocol: {
+    collection: string;
+    decrement?: boolean | null;
+    protocol: string;
+    recipient?: string | null;
+    share?: Decimal | null;
+    share_delta?: Decimal | null;
+  };
+} | {
+  apply_collection_royalty_protocol: {
+    collection: string;
+    protocol: string;
+  };
+} | {
+  cancel_collection_royalty_protocol: {
+    collection: string;
+    protocol: string;
+  };
+} | {
+  set_collection_royalty_promotion: {
+    collection: string;
+    end: Timestamp;
+    protocol?: string | null;
+    share: Decimal;
+    start: Timestamp;
+  };
+} | {
+  remove_collection_royalty_promotion: {
+    collection: string;
+    protocol?: string | null;
+  };
+} | {
+  lock_collection_royalty: {
+    collection: string;
+    default?: RoyaltyLock | null;
+    protocols?: RoyaltyLock | null;
+  };
+} | {
+  set_collection_royalty_update_params: {
+    collection: string;
+    update_params: UpdateParams;
+  };
+} | {
+  set_collection_royalty_metadata: {
+    collection: string;
+    metadata?: RoyaltyMetadata | null;
+    protocol?: string | null;
+  };
+} | {
+  set_collection_royalty_denom_share: {
+    collection: string;
+    denom: string;
+    protocol?: string | null;
+    share?: Decimal | null;
+  };
+} | {
+  batch_collection_royalty: {
+    operations: RoyaltyOperation[];
+  };
+} | {
+  distribute_sale: {
+    collection: string;
+    escrow_royalty?: boolean | null;
+    protocol?: string | null;
+    protocol_fee?: ProtocolFee | null;
+    seller: string;
+  };
+} | {
+  record_royalty_payment: {
+    amount: Coin;
+    collection: string;
+    recipient: string;
+  };
+} | {
+  claim_royalties: {
+    denoms?: string[] | null;
+  };
+} | {
+  claim_collection_royalty: {
+    collection: string;
+    recipient?: string | null;
+  };
+} | {
+  grant_collection_manager: {
+    collection: string;
+    expires?: Expiration | null;
+    manager: string;
+  };
+} | {
+  revoke_collection_manager: {
+    collection: string;
+    manager: string;
+  };
+} | {
+  rebuild_royalty_default_indices: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  rebuild_royalty_protocol_indices: {
+    limit?: number | null;
+    start_after?: [string, string] | null;
+  };
+};
+export type Timestamp = Uint64;
+export type Uint64 = string;
+export type RoyaltyLock = "increases_only" | "full";
+export type RoyaltyRole = "artist" | "dao" | "treasury" | "charity" | "other";
+export type RoyaltyOperation = {
+  set_default: {
+    collection: string;
+    recipient: string;
+    share: Decimal;
+  };
+} | {
+  update_default: {
+    collection: string;
+    decrement?: boolean | null;
+    recipient?: string | null;
+    share?: Decimal | null;
+    share_delta?: Decimal | null;
+  };
+} | {
+  set_protocol: {
+    collection: string;
+    protocol: string;
+    recipient: string;
+    share: Decimal;
+  };
+} | {
+  update_protocol: {
+    collection: string;
+    decrement?: boolean | null;
+    protocol: string;
+    recipient?: string | null;
+    share?: Decimal | null;
     share_delta?: Decimal | null;
   };
 };
+export type Uint128 = string;
+export type Expiration = {
+  at_height: number;
+} | {
+  at_time: Timestamp;
+} | {
+  never: {};
+};
+export interface UpdateParams {
+  max_share_delta?: Decimal | null;
+  update_wait_period?: number | null;
+}
+export interface RoyaltyMetadata {
+  label?: string | null;
+  role?: RoyaltyRole | null;
+  url?: string | null;
+}
+export interface ProtocolFee {
+  fair_burn: string;
+  fee_percent: Decimal;
+  recipient?: string | null;
+}
+export interface Coin {
+  amount: Uint128;
+  denom: string;
+  [k: string]: unknown;
+}
 export type QueryMsg = {
   config: {};
+} | {
+  config_bounds: {};
 } | {
   collection_royalty_default: {
     collection: string;
   };
+} | {
+  collection_royalty_details: {
+    collection: string;
+    protocol?: string | null;
+  };
 } | {
   collection_royalty_protocol: {
     collection: string;
@@ -63,8 +260,145 @@ export type QueryMsg = {
   };
 } | {
   royalty_payment: {
+    collection: string;
+    denom?: string | null;
+    protocol?: string | null;
+  };
+} | {
+  royalty_info: {
     collection: string;
     protocol?: string | null;
+    sale_price: Uint128;
+    token_id: string;
+  };
+} | {
+  check_royalties: {
+    collection: string;
+    protocol?: string | null;
+  };
+} | {
+  collection_creator: {
+    collection: string;
+  };
+} | {
+  collection_royalty_sync: {
+    collection: string;
+  };
+} | {
+  royalty_payments: {
+    collections: string[];
+    denom?: string | null;
+    protocol?: string | null;
+  };
+} | {
+  all_royalty_defaults: {
+    query_options?: QueryOptionsForString | null;
+  };
+} | {
+  all_royalty_protocols: {
+    query_options?: QueryOptionsForTupleOfStringAndString | null;
+  };
+} | {
+  royalty_protocols_by_protocol: {
+    protocol: string;
+    query_options?: QueryOptionsForString | null;
+  };
+} | {
+  royalty_defaults_by_recipient: {
+    query_options?: QueryOptionsForString | null;
+    recipient: string;
+  };
+} | {
+  royalty_protocols_by_recipient: {
+    query_options?: QueryOptionsForTupleOfStringAndString | null;
+    recipient: string;
+  };
+} | {
+  collection_royalty_locks: {
+    collection: string;
+  };
+} | {
+  all_royalty_locks: {
+    query_options?: QueryOptionsForString | null;
+  };
+} | {
+  collection_royalty_frozen: {
+    collection: string;
+  };
+} | {
+  royalty_history: {
+    collection: string;
+    query_options?: QueryOptionsForUint64 | null;
+  };
+} | {
+  collection_royalty_default_proposal: {
+    collection: string;
+  };
+} | {
+  collection_royalty_protocol_proposal: {
+    collection: string;
+    protocol: string;
+  };
+} | {
+  royalty_balance: {
+    denom: string;
+    recipient: string;
+  };
+} | {
+  royalty_balances: {
+    query_options?: QueryOptionsForString | null;
+    recipient: string;
+  };
+} | {
+  collection_royalty_totals: {
+    collection: string;
+    query_options?: QueryOptionsForString | null;
+  };
+} | {
+  recipient_royalty_totals: {
+    query_options?: QueryOptionsForString | null;
+    recipient: string;
+  };
+} | {
+  protocol_royalty_totals: {
+    protocol: string;
+    query_options?: QueryOptionsForString | null;
+  };
+} | {
+  collection_royalty_periods: {
+    collection: string;
+    query_options?: QueryOptionsForUint64 | null;
+  };
+} | {
+  collection_royalty_promotion: {
+    collection: string;
+    protocol?: string | null;
+  };
+} | {
+  all_royalty_default_promotions: {
+    query_options?: QueryOptionsForString | null;
+  };
+} | {
+  all_royalty_protocol_promotions: {
+    query_options?: QueryOptionsForTupleOfStringAndString | null;
+  };
+} | {
+  registered_protocol: {
+    protocol: string;
+  };
+} | {
+  registered_protocols: {
+    query_options?: QueryOptionsForString | null;
+  };
+} | {
+  collection_manager: {
+    collection: string;
+    manager: string;
+  };
+} | {
+  collection_managers: {
+    collection: string;
+    query_options?: QueryOptionsForString | null;
   };
 };
 export type QueryBoundForString = {
@@ -72,33 +406,155 @@ export type QueryBoundForString = {
 } | {
   exclusive: string;
 };
+export type QueryBoundForTupleOfStringAndString = {
+  inclusive: [string, string];
+} | {
+  exclusive: [string, string];
+};
+export type QueryBoundForUint64 = {
+  inclusive: number;
+} | {
+  exclusive: number;
+};
 export interface QueryOptionsForString {
   descending?: boolean | null;
   limit?: number | null;
   max?: QueryBoundForString | null;
   min?: QueryBoundForString | null;
 }
-export type NullableRoyaltyDefault = RoyaltyDefault | null;
+export interface QueryOptionsForTupleOfStringAndString {
+  descending?: boolean | null;
+  limit?: number | null;
+  max?: QueryBoundForTupleOfStringAndString | null;
+  min?: QueryBoundForTupleOfStringAndString | null;
+}
+export interface QueryOptionsForUint64 {
+  descending?: boolean | null;
+  limit?: number | null;
+  max?: QueryBoundForUint64 | null;
+  min?: QueryBoundForUint64 | null;
+}
 export type Addr = string;
-export type Timestamp = Uint64;
-export type Uint64 = string;
+export type ArrayOfRoyaltyPromotion = RoyaltyPromotion[];
+export interface RoyaltyPromotion {
+  collection: Addr;
+  end: Timestamp;
+  protocol?: Addr | null;
+  share: Decimal;
+  start: Timestamp;
+}
+export type ArrayOfRoyaltyDefault = RoyaltyDefault[];
 export interface RoyaltyDefault {
   collection: Addr;
   royalty_entry: RoyaltyEntry;
+  update_params?: UpdateParams | null;
 }
 export interface RoyaltyEntry {
+  denom_shares?: DenomShare[];
   recipient: Addr;
   share: Decimal;
   updated?: Timestamp | null;
 }
-export type NullableRoyaltyProtocol = RoyaltyProtocol | null;
+export interface DenomShare {
+  denom: string;
+  share: Decimal;
+}
+export type ArrayOfCollectionRoyaltyLocks = CollectionRoyaltyLocks[];
+export interface CollectionRoyaltyLocks {
+  collection: Addr;
+  royalty_locks: RoyaltyLocks;
+}
+export interface RoyaltyLocks {
+  default?: RoyaltyLock | null;
+  protocols?: RoyaltyLock | null;
+}
+export type ArrayOfRoyaltyProtocol = RoyaltyProtocol[];
 export interface RoyaltyProtocol {
   collection: Addr;
   protocol: Addr;
   royalty_entry: RoyaltyEntry;
 }
+export interface CheckRoyaltiesResponse {
+  royalty_payments: boolean;
+}
+export interface CollectionCreatorResponse {
+  changed?: boolean | null;
+  current_creator?: Addr | null;
+  recorded_creator?: Addr | null;
+}
+export type NullableCollectionManager = CollectionManager | null;
+export interface CollectionManager {
+  collection: Addr;
+  expires: Expiration;
+  manager: Addr;
+}
+export type ArrayOfCollectionManager = CollectionManager[];
+export type NullableRoyaltyDefault = RoyaltyDefault | null;
+export type NullableRoyaltyProposal = RoyaltyProposal | null;
+export interface RoyaltyProposal {
+  activation: Timestamp;
+  collection: Addr;
+  proposer: Addr;
+  protocol?: Addr | null;
+  royalty_entry: RoyaltyEntry;
+}
+export interface RoyaltyDetailsResponse {
+  default_metadata?: RoyaltyMetadata | null;
+  protocol_metadata?: RoyaltyMetadata | null;
+  royalty_default?: RoyaltyDefault | null;
+  royalty_protocol?: RoyaltyProtocol | null;
+}
+export type Boolean = boolean;
+export type ArrayOfRoyaltyPeriod = RoyaltyPeriod[];
+export interface RoyaltyPeriod {
+  amounts: Coin[];
+  collection: Addr;
+  period_start: Timestamp;
+}
+export type NullableRoyaltyPromotion = RoyaltyPromotion | null;
+export type NullableRoyaltyProtocol = RoyaltyProtocol | null;
+export interface RoyaltySyncResponse {
+  collection_royalty?: RoyaltyEntry | null;
+  in_sync: boolean;
+  royalty_default?: RoyaltyDefault | null;
+}
+export type ArrayOfCoin = Coin[];
+export interface ConfigBoundsResponse {
+  max_max_share_delta: Decimal;
+  max_update_wait_period: number;
+  min_max_share_delta: Decimal;
+  min_update_wait_period: number;
+}
+export type NullableRegisteredProtocol = RegisteredProtocol | null;
+export interface RegisteredProtocol {
+  active: boolean;
+  max_share: Decimal;
+  name: string;
+  protocol: Addr;
+  url: string;
+}
+export type ArrayOfRegisteredProtocol = RegisteredProtocol[];
+export type RoyaltyAction = ("initialize" | "set" | "update") | "sync" | "governance_set" | "governance_remove" | "claim";
+export type ArrayOfRoyaltyHistoryEntry = RoyaltyHistoryEntry[];
+export interface RoyaltyHistoryEntry {
+  action: RoyaltyAction;
+  actor: Addr;
+  collection: Addr;
+  current?: RoyaltyEntry | null;
+  id: number;
+  previous?: RoyaltyEntry | null;
+  protocol?: Addr | null;
+  timestamp: Timestamp;
+}
+export interface RoyaltiesInfoResponse {
+  address: string;
+  royalty_amount: Uint128;
+}
 export interface RoyaltyPaymentResponse {
+  protocol_max_share?: Decimal | null;
   royalty_default?: RoyaltyDefault | null;
+  royalty_locks?: RoyaltyLocks;
+  royalty_promotions?: RoyaltyPromotion[];
   royalty_protocol?: RoyaltyProtocol | null;
 }
-export type ArrayOfRoyaltyProtocol = RoyaltyProtocol[];
\ No newline at end of file
+export type ArrayOfRoyaltyPaymentResponse = RoyaltyPaymentResponse[];
\ No newline at end of file